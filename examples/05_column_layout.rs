@@ -94,8 +94,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         for i in 0..5 {
             let (mut row, pos) = column_area!(column, 5)?;
 
-            let label1 = label_new!(20).add_text(&format!("Row {} Left", i))?;
-            let label2 = label_new!(20).add_text(&format!("Row {} Right", i))?;
+            let label1 = label_new!(20).add_text(format!("Row {} Left", i))?;
+            let label2 = label_new!(20).add_text(format!("Row {} Right", i))?;
 
             row.add_child(label1, (0, 1))?;
             row.add_child(label2, (30, 1))?;
@@ -86,19 +86,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     println!("  ESC C (page length) count: {}", esc_c_count);
 
-    println!("\nExpected for 2 pages (EPSON LQ-2090II with ESC C 50):");
+    println!("\nExpected for 2 pages (EPSON LQ-2090II with ESC C 51):");
     println!("  ESC @ should be: 1 (initial only, not between pages)");
     println!("  ESC C should be: 1 (set page length once)");
     println!("  FF should be: 2 (after each page)");
     println!("  SI should be: 1 (initial only)");
-    println!("  CR should be: 100 (50 lines × 2 pages, no extra CR)");
-    println!("  LF should be: 100 (50 lines × 2 pages)");
+    println!("  CR should be: 102 (51 lines × 2 pages, no extra CR)");
+    println!("  LF should be: 102 (51 lines × 2 pages)");
 
     // Check page structure
     println!("\nPage structure analysis:");
     let page = &document.pages()[0];
-    let first_line = &page.cells()[0];
-    let last_line = &page.cells()[50];
+    let first_line = page.row(0).unwrap();
+    let last_line = page.row(50).unwrap();
 
     println!("  First line (0): {} chars",
         first_line.iter().filter(|c| c.character() != ' ').count());
@@ -107,8 +107,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Show where content is
     println!("\nContent positions on first page:");
-    for y in 0..51 {
-        let line = &page.cells()[y as usize];
+    for (y, line) in page.rows().enumerate() {
         let content_count = line.iter().filter(|c| c.character() != ' ').count();
         if content_count > 0 {
             let first_char_x = line.iter().position(|c| c.character() != ' ').unwrap();
@@ -121,5 +120,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    println!("\nText preview:");
+    print!("{}", document.to_plain_text());
+
     Ok(())
 }
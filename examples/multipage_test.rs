@@ -16,12 +16,12 @@ use std::process::Command;
 
 const PRINTER_NAME: &str = "EPSON_LQ_2090II";
 const PAGE_WIDTH: u16 = 80;
-const PAGE_HEIGHT: u16 = 50;  // EPSON LQ-2090II page height (line 50 reserved)
+const PAGE_HEIGHT: u16 = 51;  // EPSON LQ-2090II page height
 
 /// Send ESC/P bytes to printer via CUPS
 fn send_to_printer(escp_bytes: &[u8], job_name: &str) -> Result<(), Box<dyn std::error::Error>> {
     // Write to temporary file
-    let temp_path = format!("/tmp/escp_multipage_test.prn");
+    let temp_path = "/tmp/escp_multipage_test.prn".to_string();
     let mut file = File::create(&temp_path)?;
     file.write_all(escp_bytes)?;
     file.flush()?;
@@ -54,17 +54,17 @@ fn create_page(page_number: u16) -> Result<Page, Box<dyn std::error::Error>> {
     let mut root = rect_new!(PAGE_WIDTH, PAGE_HEIGHT);
 
     // Page number at the top (line 0)
-    let top_label = label_new!(60).add_text(&format!("Page {}", page_number))?;
+    let top_label = label_new!(60).add_text(format!("Page {}", page_number))?;
     root.add_child(top_label, (10, 0))?;
 
     // Content in the middle
-    let title = label_new!(60).add_text(&format!("EPSON LQ-2090II Multi-Page Test - Page {}", page_number))?;
+    let title = label_new!(60).add_text(format!("EPSON LQ-2090II Multi-Page Test - Page {}", page_number))?;
     root.add_child(title, (10, 5))?;
 
     let description = label_new!(70).add_text("This is a test of multi-page ESC/P printing.")?;
     root.add_child(description, (5, 10))?;
 
-    let line1 = label_new!(70).add_text(&format!("Current page: {}/2", page_number))?;
+    let line1 = label_new!(70).add_text(format!("Current page: {}/2", page_number))?;
     root.add_child(line1, (5, 15))?;
 
     let line2 = label_new!(70).add_text("Each page has page numbers at top and bottom.")?;
@@ -73,7 +73,7 @@ fn create_page(page_number: u16) -> Result<Page, Box<dyn std::error::Error>> {
     let line3 = label_new!(70).add_text("Printer resets after each form feed.")?;
     root.add_child(line3, (5, 20))?;
 
-    let line4 = label_new!(70).add_text(&format!("Testing 50-line page height"))?;
+    let line4 = label_new!(70).add_text("Testing 50-line page height".to_string())?;
     root.add_child(line4, (5, 22))?;
 
     // Add some additional content unique to each page
@@ -90,7 +90,7 @@ fn create_page(page_number: u16) -> Result<Page, Box<dyn std::error::Error>> {
     }
 
     // Page number at the bottom (last line)
-    let bottom_label = label_new!(60).add_text(&format!("End of Page {}", page_number))?;
+    let bottom_label = label_new!(60).add_text(format!("End of Page {}", page_number))?;
     root.add_child(bottom_label, (10, PAGE_HEIGHT - 1))?;
 
     // Render to page
@@ -124,7 +124,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Page dimensions: {}×{} (columns×lines)", PAGE_WIDTH, PAGE_HEIGHT);
     println!("  Total ESC/P output: {} bytes", escp_bytes.len());
     println!("  Average per page: {} bytes", escp_bytes.len() / 2);
-    println!("  Page length configured: ESC C 50 (50 lines per page)");
+    println!("  Page length configured: ESC C 51 (51 lines per page)");
 
     // Send to printer
     println!("\nSending to printer...");
@@ -137,9 +137,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\nDocument initialization:");
     println!("  1. ESC @ (0x1B 0x40) - Reset printer");
     println!("  2. SI (0x0F) - Enable condensed mode");
-    println!("  3. ESC C 50 (0x1B 0x43 0x32) - Set 50-line pages");
+    println!("  3. ESC C 51 (0x1B 0x43 0x33) - Set 51-line pages");
     println!("\nEach page sequence:");
-    println!("  1. Render 50 lines of content (160 chars each)");
+    println!("  1. Render 51 lines of content (160 chars each)");
     println!("     Each line ends with CR + LF");
     println!("  2. FF (0x0C) - Form feed to next page");
     println!("\nNo reset commands between pages - printer maintains state");
@@ -11,43 +11,23 @@
 //!   cargo run --example printer_test -- all
 
 use escp_layout::widget::{label_new, rect_new, row_area, row_new, column_area, column_new};
+use escp_layout::transport::{LprTransport, Transport};
 use escp_layout::{Page, Document};
 use std::env;
-use std::fs::File;
-use std::io::Write;
-use std::process::Command;
 
 const PRINTER_NAME: &str = "EPSON_LQ_2090II";
 
 /// Send ESC/P bytes to printer via CUPS
 fn send_to_printer(escp_bytes: &[u8], job_name: &str) -> Result<(), Box<dyn std::error::Error>> {
-    // Write to temporary file
-    let temp_path = format!("/tmp/escp_test_{}.prn", job_name);
-    let mut file = File::create(&temp_path)?;
-    file.write_all(escp_bytes)?;
-    file.flush()?;
-    drop(file);
-
     println!("Sending {} bytes to printer {} (job: {})", escp_bytes.len(), PRINTER_NAME, job_name);
 
-    // Send to printer using lpr
-    let output = Command::new("lpr")
-        .arg("-P")
-        .arg(PRINTER_NAME)
-        .arg("-o")
-        .arg("raw")
-        .arg("-T")
-        .arg(job_name)
-        .arg(&temp_path)
-        .output()?;
-
-    if output.status.success() {
-        println!("✓ Job '{}' sent successfully!", job_name);
-        Ok(())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(format!("Failed to print: {}", error).into())
-    }
+    let mut printer = LprTransport::lpr()
+        .with_printer(PRINTER_NAME)
+        .with_job_name(job_name);
+    printer.send(escp_bytes)?;
+
+    println!("✓ Job '{}' sent successfully!", job_name);
+    Ok(())
 }
 
 /// Test 1: Basic Label
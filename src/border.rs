@@ -0,0 +1,204 @@
+//! BorderStyle type selecting the glyphs used to draw lines and boxes.
+
+/// Line style for borders drawn with [`PageBuilder::draw_box`],
+/// [`PageBuilder::draw_hline`] and [`PageBuilder::draw_vline`].
+///
+/// [`BorderStyle::Single`] and [`BorderStyle::Double`] use Unicode
+/// box-drawing characters, which print as continuous lines with the PC437,
+/// PC850 and PC858 character tables. When the document's character table
+/// lacks them they print as the ASCII glyphs of [`BorderStyle::Ascii`].
+///
+/// [`PageBuilder::draw_box`]: crate::PageBuilder::draw_box
+/// [`PageBuilder::draw_hline`]: crate::PageBuilder::draw_hline
+/// [`PageBuilder::draw_vline`]: crate::PageBuilder::draw_vline
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::BorderStyle;
+///
+/// assert_eq!(BorderStyle::Single.top_left(), '┌');
+/// assert_eq!(BorderStyle::Double.horizontal(), '═');
+/// assert_eq!(BorderStyle::Ascii.top_left(), '+');
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BorderStyle {
+    /// ASCII lines (`+`, `-`, `|`), printable with every character table
+    Ascii,
+    /// Single box-drawing lines (`┌ ─ ┐ │`)
+    #[default]
+    Single,
+    /// Double box-drawing lines (`╔ ═ ╗ ║`)
+    Double,
+}
+
+impl BorderStyle {
+    /// Returns the glyph for horizontal lines
+    #[inline]
+    pub const fn horizontal(self) -> char {
+        match self {
+            BorderStyle::Ascii => '-',
+            BorderStyle::Single => '─',
+            BorderStyle::Double => '═',
+        }
+    }
+
+    /// Returns the glyph for vertical lines
+    #[inline]
+    pub const fn vertical(self) -> char {
+        match self {
+            BorderStyle::Ascii => '|',
+            BorderStyle::Single => '│',
+            BorderStyle::Double => '║',
+        }
+    }
+
+    /// Returns the glyph for the top-left corner
+    #[inline]
+    pub const fn top_left(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┌',
+            BorderStyle::Double => '╔',
+        }
+    }
+
+    /// Returns the glyph for the top-right corner
+    #[inline]
+    pub const fn top_right(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┐',
+            BorderStyle::Double => '╗',
+        }
+    }
+
+    /// Returns the glyph for the bottom-left corner
+    #[inline]
+    pub const fn bottom_left(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '└',
+            BorderStyle::Double => '╚',
+        }
+    }
+
+    /// Returns the glyph for the bottom-right corner
+    #[inline]
+    pub const fn bottom_right(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┘',
+            BorderStyle::Double => '╝',
+        }
+    }
+
+    /// Returns the glyph joining a vertical line to a horizontal line on its
+    /// right (`├`)
+    #[inline]
+    pub const fn left_tee(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '├',
+            BorderStyle::Double => '╠',
+        }
+    }
+
+    /// Returns the glyph joining a vertical line to a horizontal line on its
+    /// left (`┤`)
+    #[inline]
+    pub const fn right_tee(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┤',
+            BorderStyle::Double => '╣',
+        }
+    }
+
+    /// Returns the glyph joining a horizontal line to a vertical line below
+    /// it (`┬`)
+    #[inline]
+    pub const fn top_tee(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┬',
+            BorderStyle::Double => '╦',
+        }
+    }
+
+    /// Returns the glyph joining a horizontal line to a vertical line above
+    /// it (`┴`)
+    #[inline]
+    pub const fn bottom_tee(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┴',
+            BorderStyle::Double => '╩',
+        }
+    }
+
+    /// Returns the glyph where a horizontal and a vertical line cross
+    #[inline]
+    pub const fn cross(self) -> char {
+        match self {
+            BorderStyle::Ascii => '+',
+            BorderStyle::Single => '┼',
+            BorderStyle::Double => '╬',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_border_style_default() {
+        assert_eq!(BorderStyle::default(), BorderStyle::Single);
+    }
+
+    #[test]
+    fn test_border_style_glyphs() {
+        let single = BorderStyle::Single;
+        assert_eq!(
+            [
+                single.top_left(),
+                single.top_right(),
+                single.bottom_left(),
+                single.bottom_right()
+            ],
+            ['┌', '┐', '└', '┘']
+        );
+        assert_eq!(
+            [
+                single.left_tee(),
+                single.right_tee(),
+                single.top_tee(),
+                single.bottom_tee()
+            ],
+            ['├', '┤', '┬', '┴']
+        );
+
+        let double = BorderStyle::Double;
+        assert_eq!(
+            [
+                double.top_left(),
+                double.top_right(),
+                double.bottom_left(),
+                double.bottom_right()
+            ],
+            ['╔', '╗', '╚', '╝']
+        );
+        assert_eq!(double.cross(), '╬');
+        assert_eq!(double.vertical(), '║');
+    }
+
+    #[test]
+    fn test_border_style_ascii() {
+        let ascii = BorderStyle::Ascii;
+        assert_eq!(ascii.horizontal(), '-');
+        assert_eq!(ascii.vertical(), '|');
+        assert_eq!(ascii.cross(), '+');
+        assert!(ascii.top_left().is_ascii());
+    }
+}
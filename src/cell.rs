@@ -2,10 +2,14 @@
 
 /// Style flags for text formatting using bit packing.
 ///
-/// Represents bold and underline styles that can be combined.
-/// Uses bit manipulation for compact representation (1 byte).
+/// Represents bold, underline, italic, double-strike, superscript, subscript,
+/// strikethrough, double-width and double-height styles that can be combined.
+/// Uses bit manipulation for compact representation (2 bytes).
+///
+/// Superscript and subscript are mutually exclusive: enabling one clears
+/// the other.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct StyleFlags(u8);
+pub struct StyleFlags(u16);
 
 impl StyleFlags {
     /// No styling applied
@@ -17,6 +21,27 @@ impl StyleFlags {
     /// Underline text style
     pub const UNDERLINE: StyleFlags = StyleFlags(1 << 1);
 
+    /// Italic text style
+    pub const ITALIC: StyleFlags = StyleFlags(1 << 2);
+
+    /// Double-strike text style
+    pub const DOUBLE_STRIKE: StyleFlags = StyleFlags(1 << 3);
+
+    /// Superscript text style
+    pub const SUPERSCRIPT: StyleFlags = StyleFlags(1 << 4);
+
+    /// Subscript text style
+    pub const SUBSCRIPT: StyleFlags = StyleFlags(1 << 5);
+
+    /// Strikethrough (score-through) text style
+    pub const STRIKETHROUGH: StyleFlags = StyleFlags(1 << 6);
+
+    /// Double-width text style (each glyph occupies two columns)
+    pub const DOUBLE_WIDTH: StyleFlags = StyleFlags(1 << 7);
+
+    /// Double-height text style (each glyph occupies two rows)
+    pub const DOUBLE_HEIGHT: StyleFlags = StyleFlags(1 << 8);
+
     /// Returns true if bold style is active
     #[inline]
     pub fn bold(self) -> bool {
@@ -29,6 +54,101 @@ impl StyleFlags {
         self.0 & Self::UNDERLINE.0 != 0
     }
 
+    /// Returns true if italic style is active
+    #[inline]
+    pub fn italic(self) -> bool {
+        self.0 & Self::ITALIC.0 != 0
+    }
+
+    /// Returns true if double-strike style is active
+    #[inline]
+    pub fn double_strike(self) -> bool {
+        self.0 & Self::DOUBLE_STRIKE.0 != 0
+    }
+
+    /// Returns true if superscript style is active
+    #[inline]
+    pub fn superscript(self) -> bool {
+        self.0 & Self::SUPERSCRIPT.0 != 0
+    }
+
+    /// Returns true if subscript style is active
+    #[inline]
+    pub fn subscript(self) -> bool {
+        self.0 & Self::SUBSCRIPT.0 != 0
+    }
+
+    /// Returns true if strikethrough style is active
+    #[inline]
+    pub fn strikethrough(self) -> bool {
+        self.0 & Self::STRIKETHROUGH.0 != 0
+    }
+
+    /// Returns true if double-width style is active
+    #[inline]
+    pub fn double_width(self) -> bool {
+        self.0 & Self::DOUBLE_WIDTH.0 != 0
+    }
+
+    /// Returns true if double-height style is active
+    #[inline]
+    pub fn double_height(self) -> bool {
+        self.0 & Self::DOUBLE_HEIGHT.0 != 0
+    }
+
+    /// Returns the number of columns a glyph with this style occupies (1 or 2)
+    #[inline]
+    pub fn glyph_width(self) -> u16 {
+        if self.double_width() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Returns the number of rows a glyph with this style occupies (1 or 2)
+    #[inline]
+    pub fn glyph_height(self) -> u16 {
+        if self.double_height() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Every defined flag
+    #[cfg(feature = "serde")]
+    const ALL: u16 = (1 << 9) - 1;
+
+    /// Returns the raw bit mask
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub(crate) const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the flags for a raw bit mask, or None if it has undefined
+    /// bits or both superscript and subscript set
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_bits(bits: u16) -> Option<Self> {
+        let style = StyleFlags(bits);
+        if bits & !Self::ALL != 0 || (style.superscript() && style.subscript()) {
+            None
+        } else {
+            Some(style)
+        }
+    }
+
+    /// Returns a new StyleFlags with the flags in `flag` enabled or disabled
+    #[inline]
+    pub(crate) fn set(self, flag: StyleFlags, on: bool) -> Self {
+        if on {
+            StyleFlags(self.0 | flag.0)
+        } else {
+            StyleFlags(self.0 & !flag.0)
+        }
+    }
+
     /// Returns a new StyleFlags with bold enabled
     #[inline]
     pub fn with_bold(self) -> Self {
@@ -40,31 +160,100 @@ impl StyleFlags {
     pub fn with_underline(self) -> Self {
         StyleFlags(self.0 | Self::UNDERLINE.0)
     }
+
+    /// Returns a new StyleFlags with italic enabled
+    #[inline]
+    pub fn with_italic(self) -> Self {
+        StyleFlags(self.0 | Self::ITALIC.0)
+    }
+
+    /// Returns a new StyleFlags with double-strike enabled
+    #[inline]
+    pub fn with_double_strike(self) -> Self {
+        StyleFlags(self.0 | Self::DOUBLE_STRIKE.0)
+    }
+
+    /// Returns a new StyleFlags with superscript enabled (clears subscript)
+    #[inline]
+    pub fn with_superscript(self) -> Self {
+        StyleFlags((self.0 & !Self::SUBSCRIPT.0) | Self::SUPERSCRIPT.0)
+    }
+
+    /// Returns a new StyleFlags with subscript enabled (clears superscript)
+    #[inline]
+    pub fn with_subscript(self) -> Self {
+        StyleFlags((self.0 & !Self::SUPERSCRIPT.0) | Self::SUBSCRIPT.0)
+    }
+
+    /// Returns a new StyleFlags with strikethrough enabled
+    #[inline]
+    pub fn with_strikethrough(self) -> Self {
+        StyleFlags(self.0 | Self::STRIKETHROUGH.0)
+    }
+
+    /// Returns a new StyleFlags with double-width enabled
+    #[inline]
+    pub fn with_double_width(self) -> Self {
+        StyleFlags(self.0 | Self::DOUBLE_WIDTH.0)
+    }
+
+    /// Returns a new StyleFlags with double-height enabled
+    #[inline]
+    pub fn with_double_height(self) -> Self {
+        StyleFlags(self.0 | Self::DOUBLE_HEIGHT.0)
+    }
 }
 
+/// Marker character for a cell covered by a double-width glyph to its left
+const WIDE_TAIL: char = '\u{0}';
+
+/// Marker character for a cell covered by a double-height glyph above it
+const TALL_TAIL: char = '\u{1}';
+
 /// Represents a single character cell in the page grid.
 ///
-/// Each cell contains one ASCII character and associated style flags.
-/// Non-ASCII characters are automatically converted to '?' during construction.
+/// Each cell contains one Unicode character and associated style flags.
+/// Control characters are converted to '?' during construction; other
+/// characters are kept and mapped to printer bytes by the document's
+/// character table when rendering.
+///
+/// A double-width or double-height glyph occupies more than one cell: the
+/// glyph itself sits in its top-left cell and the other cells it covers are
+/// marked as covered (see [`Cell::is_covered`]).
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Cell {
-    /// ASCII character (values 32-126, or 63 for '?')
-    pub(crate) character: u8,
-    /// Style flags (bold, underline)
+    /// Printable character, or a covered-cell marker
+    pub(crate) character: char,
+    /// Style flags (bold, underline, italic, ...)
     pub(crate) style: StyleFlags,
 }
 
 impl Cell {
     /// Empty cell containing a space character with no styling
     pub const EMPTY: Cell = Cell {
-        character: b' ',
+        character: ' ',
+        style: StyleFlags::NONE,
+    };
+
+    /// Cell covered by the right half of a double-width glyph to its left
+    pub(crate) fn wide_tail(style: StyleFlags) -> Cell {
+        Cell {
+            character: WIDE_TAIL,
+            style,
+        }
+    }
+
+    /// Cell covered by the lower half of a double-height glyph above it
+    pub(crate) const TALL_TAIL: Cell = Cell {
+        character: TALL_TAIL,
         style: StyleFlags::NONE,
     };
 
     /// Creates a new cell with the specified character and style.
     ///
-    /// Non-ASCII characters (char code > 127) are replaced with '?' (0x3F).
-    /// Control characters (char code < 32) are replaced with '?' (0x3F).
+    /// Control characters (C0, DEL and C1) are replaced with '?'. Other
+    /// characters are kept; characters the document's character table
+    /// cannot print are substituted at render time.
     ///
     /// # Examples
     ///
@@ -74,25 +263,48 @@ impl Cell {
     /// let cell = Cell::new('A', StyleFlags::BOLD);
     /// assert_eq!(cell.character(), 'A');
     ///
-    /// // Non-ASCII converted to '?'
+    /// // Non-ASCII characters are kept
     /// let cell = Cell::new('é', StyleFlags::NONE);
+    /// assert_eq!(cell.character(), 'é');
+    ///
+    /// // Control characters converted to '?'
+    /// let cell = Cell::new('\n', StyleFlags::NONE);
     /// assert_eq!(cell.character(), '?');
     /// ```
     pub fn new(ch: char, style: StyleFlags) -> Self {
-        let character = if ch.is_ascii() && (ch as u8) >= 32 && (ch as u8) <= 126 {
-            ch as u8
-        } else {
-            // Non-ASCII or control character → '?'
-            b'?'
-        };
+        let character = if ch.is_control() { '?' } else { ch };
 
         Cell { character, style }
     }
 
     /// Returns the character as a char
+    ///
+    /// Covered cells report a space.
     #[inline]
     pub fn character(&self) -> char {
-        self.character as char
+        match self.character {
+            WIDE_TAIL | TALL_TAIL => ' ',
+            character => character,
+        }
+    }
+
+    /// Returns true if this cell is covered by a double-width or
+    /// double-height glyph written in a neighbouring cell
+    #[inline]
+    pub fn is_covered(&self) -> bool {
+        self.is_wide_tail() || self.is_tall_tail()
+    }
+
+    /// Returns true if this cell is the right half of a double-width glyph
+    #[inline]
+    pub(crate) fn is_wide_tail(&self) -> bool {
+        self.character == WIDE_TAIL
+    }
+
+    /// Returns true if this cell is the lower half of a double-height glyph
+    #[inline]
+    pub(crate) fn is_tall_tail(&self) -> bool {
+        self.character == TALL_TAIL
     }
 
     /// Returns the style flags
@@ -148,6 +360,71 @@ mod tests {
         assert!(style.underline());
     }
 
+    #[test]
+    fn test_style_flags_extended_constants() {
+        assert_eq!(StyleFlags::ITALIC.0, 4);
+        assert_eq!(StyleFlags::DOUBLE_STRIKE.0, 8);
+        assert_eq!(StyleFlags::SUPERSCRIPT.0, 16);
+        assert_eq!(StyleFlags::SUBSCRIPT.0, 32);
+        assert_eq!(StyleFlags::STRIKETHROUGH.0, 64);
+    }
+
+    #[test]
+    fn test_style_flags_extended_accessors() {
+        let style = StyleFlags::NONE
+            .with_italic()
+            .with_double_strike()
+            .with_strikethrough();
+        assert!(style.italic());
+        assert!(style.double_strike());
+        assert!(style.strikethrough());
+        assert!(!style.bold());
+        assert!(!style.superscript());
+        assert!(!style.subscript());
+    }
+
+    #[test]
+    fn test_style_flags_script_exclusive() {
+        let style = StyleFlags::BOLD.with_superscript();
+        assert!(style.superscript());
+        assert!(!style.subscript());
+
+        let style = style.with_subscript();
+        assert!(style.subscript());
+        assert!(!style.superscript());
+        assert!(style.bold());
+    }
+
+    #[test]
+    fn test_style_flags_double_size() {
+        assert_eq!(StyleFlags::DOUBLE_WIDTH.0, 128);
+        assert_eq!(StyleFlags::DOUBLE_HEIGHT.0, 256);
+
+        let style = StyleFlags::BOLD.with_double_width();
+        assert!(style.double_width());
+        assert!(!style.double_height());
+        assert_eq!(style.glyph_width(), 2);
+        assert_eq!(style.glyph_height(), 1);
+
+        let style = style.with_double_height();
+        assert!(style.double_height());
+        assert_eq!(style.glyph_height(), 2);
+    }
+
+    #[test]
+    fn test_cell_covered() {
+        assert!(!Cell::EMPTY.is_covered());
+
+        let tail = Cell::wide_tail(StyleFlags::DOUBLE_WIDTH);
+        assert!(tail.is_covered());
+        assert!(tail.is_wide_tail());
+        assert_eq!(tail.character(), ' ');
+
+        assert!(Cell::TALL_TAIL.is_covered());
+        assert!(Cell::TALL_TAIL.is_tall_tail());
+        assert_eq!(Cell::TALL_TAIL.character(), ' ');
+    }
+
     #[test]
     fn test_cell_empty() {
         assert_eq!(Cell::EMPTY.character(), ' ');
@@ -164,7 +441,10 @@ mod tests {
     #[test]
     fn test_cell_new_non_ascii() {
         let cell = Cell::new('é', StyleFlags::NONE);
-        assert_eq!(cell.character(), '?');
+        assert_eq!(cell.character(), 'é');
+
+        let cell = Cell::new('€', StyleFlags::NONE);
+        assert_eq!(cell.character(), '€');
     }
 
     #[test]
@@ -174,6 +454,12 @@ mod tests {
 
         let cell = Cell::new('\t', StyleFlags::NONE);
         assert_eq!(cell.character(), '?');
+
+        let cell = Cell::new('\u{7f}', StyleFlags::NONE);
+        assert_eq!(cell.character(), '?');
+
+        let cell = Cell::new('\u{85}', StyleFlags::NONE);
+        assert_eq!(cell.character(), '?');
     }
 
     #[test]
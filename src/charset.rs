@@ -0,0 +1,579 @@
+//! Character tables and international character sets for non-ASCII text.
+//!
+//! Cells hold Unicode characters; at render time each character is mapped to
+//! the printer byte of the document's active character table and
+//! international character set. Characters with no byte in the active tables
+//! print as the document's fallback character, except box-drawing
+//! characters, which first fall back to their ASCII look-alikes.
+
+/// Printer character table selected for bytes 0x80-0xFF.
+///
+/// Selected once per document with `ESC ( t` (assigning the table to slot 1)
+/// followed by `ESC t 1`. The default, [`CharacterTable::Ascii`], emits no
+/// command and maps only 7-bit ASCII.
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{CharacterTable, Document, Page, StyleFlags};
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.write_str(0, 0, "Müller", StyleFlags::NONE);
+///
+/// let mut builder = Document::builder();
+/// builder.character_table(CharacterTable::Pc850);
+/// builder.add_page(page_builder.build());
+/// let bytes = builder.build().render();
+///
+/// // 'ü' is 0x81 in PC850
+/// assert!(bytes.windows(6).any(|w| w == b"M\x81ller"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep")
+)]
+pub enum CharacterTable {
+    /// 7-bit ASCII only (no table selection)
+    #[default]
+    Ascii,
+    /// PC437 (US)
+    Pc437,
+    /// PC850 (Multilingual)
+    Pc850,
+    /// PC858 (Multilingual with euro sign)
+    Pc858,
+    /// ISO-8859-15 (Latin-9)
+    Iso8859_15,
+}
+
+impl CharacterTable {
+    /// Returns the characters of bytes 0x80-0xFF, or None for ASCII
+    fn upper_half(self) -> Option<&'static [char; 128]> {
+        match self {
+            CharacterTable::Ascii => None,
+            CharacterTable::Pc437 => Some(&PC437_UPPER),
+            CharacterTable::Pc850 => Some(&PC850_UPPER),
+            CharacterTable::Pc858 => Some(&PC858_UPPER),
+            CharacterTable::Iso8859_15 => Some(&ISO_8859_15_UPPER),
+        }
+    }
+
+    /// Returns the table with the given `ESC ( t` (d2, d3) identifier
+    pub(crate) fn from_table_id(d2: u8, d3: u8) -> Option<Self> {
+        [
+            CharacterTable::Pc437,
+            CharacterTable::Pc850,
+            CharacterTable::Pc858,
+            CharacterTable::Iso8859_15,
+        ]
+        .into_iter()
+        .find(|table| table.table_id() == Some((d2, d3)))
+    }
+
+    /// Returns the (d2, d3) table identifier used by `ESC ( t`
+    pub(crate) fn table_id(self) -> Option<(u8, u8)> {
+        match self {
+            CharacterTable::Ascii => None,
+            CharacterTable::Pc437 => Some((1, 0)),
+            CharacterTable::Pc850 => Some((3, 0)),
+            CharacterTable::Pc858 => Some((13, 32)),
+            CharacterTable::Iso8859_15 => Some((29, 16)),
+        }
+    }
+}
+
+/// International character set selected with `ESC R n`.
+///
+/// Each set replaces twelve ASCII positions (0x23, 0x24, 0x40, 0x5B-0x5E,
+/// 0x60, 0x7B-0x7E) with national characters. The default,
+/// [`InternationalCharset::Usa`], is plain ASCII and emits no command.
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{Document, InternationalCharset, Page, StyleFlags};
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.write_str(0, 0, "Straße", StyleFlags::NONE);
+///
+/// let mut builder = Document::builder();
+/// builder.international_charset(InternationalCharset::Germany);
+/// builder.add_page(page_builder.build());
+/// let bytes = builder.build().render();
+///
+/// // 'ß' is 0x7E in the German set
+/// assert!(bytes.windows(6).any(|w| w == b"Stra\x7Ee"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep")
+)]
+pub enum InternationalCharset {
+    /// USA (plain ASCII)
+    #[default]
+    Usa,
+    /// France
+    France,
+    /// Germany
+    Germany,
+    /// United Kingdom
+    UnitedKingdom,
+    /// Denmark I
+    DenmarkI,
+    /// Sweden
+    Sweden,
+    /// Italy
+    Italy,
+    /// Spain I
+    SpainI,
+    /// Japan (English)
+    Japan,
+    /// Norway
+    Norway,
+    /// Denmark II
+    DenmarkII,
+    /// Spain II
+    SpainII,
+    /// Latin America
+    LatinAmerica,
+}
+
+impl InternationalCharset {
+    /// Returns the `n` parameter of `ESC R n`
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            InternationalCharset::Usa => 0,
+            InternationalCharset::France => 1,
+            InternationalCharset::Germany => 2,
+            InternationalCharset::UnitedKingdom => 3,
+            InternationalCharset::DenmarkI => 4,
+            InternationalCharset::Sweden => 5,
+            InternationalCharset::Italy => 6,
+            InternationalCharset::SpainI => 7,
+            InternationalCharset::Japan => 8,
+            InternationalCharset::Norway => 9,
+            InternationalCharset::DenmarkII => 10,
+            InternationalCharset::SpainII => 11,
+            InternationalCharset::LatinAmerica => 12,
+        }
+    }
+
+    /// Returns the character set selected by `ESC R n`
+    pub(crate) fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => InternationalCharset::Usa,
+            1 => InternationalCharset::France,
+            2 => InternationalCharset::Germany,
+            3 => InternationalCharset::UnitedKingdom,
+            4 => InternationalCharset::DenmarkI,
+            5 => InternationalCharset::Sweden,
+            6 => InternationalCharset::Italy,
+            7 => InternationalCharset::SpainI,
+            8 => InternationalCharset::Japan,
+            9 => InternationalCharset::Norway,
+            10 => InternationalCharset::DenmarkII,
+            11 => InternationalCharset::SpainII,
+            12 => InternationalCharset::LatinAmerica,
+            _ => return None,
+        })
+    }
+
+    /// Returns the characters printed at the twelve replaced positions
+    fn replacements(self) -> &'static [char; 12] {
+        match self {
+            InternationalCharset::Usa => {
+                &['#', '$', '@', '[', '\\', ']', '^', '`', '{', '|', '}', '~']
+            }
+            InternationalCharset::France => {
+                &['#', '$', 'à', '°', 'ç', '§', '^', '`', 'é', 'ù', 'è', '¨']
+            }
+            InternationalCharset::Germany => {
+                &['#', '$', '§', 'Ä', 'Ö', 'Ü', '^', '`', 'ä', 'ö', 'ü', 'ß']
+            }
+            InternationalCharset::UnitedKingdom => {
+                &['£', '$', '@', '[', '\\', ']', '^', '`', '{', '|', '}', '~']
+            }
+            InternationalCharset::DenmarkI => {
+                &['#', '$', '@', 'Æ', 'Ø', 'Å', '^', '`', 'æ', 'ø', 'å', '~']
+            }
+            InternationalCharset::Sweden => {
+                &['#', '¤', 'É', 'Ä', 'Ö', 'Å', 'Ü', 'é', 'ä', 'ö', 'å', 'ü']
+            }
+            InternationalCharset::Italy => {
+                &['#', '$', '@', '°', '\\', 'é', '^', 'ù', 'à', 'ò', 'è', 'ì']
+            }
+            InternationalCharset::SpainI => {
+                &['₧', '$', '@', '¡', 'Ñ', '¿', '^', '`', '¨', 'ñ', '}', '~']
+            }
+            InternationalCharset::Japan => {
+                &['#', '$', '@', '[', '¥', ']', '^', '`', '{', '|', '}', '~']
+            }
+            InternationalCharset::Norway => {
+                &['#', '¤', 'É', 'Æ', 'Ø', 'Å', 'Ü', 'é', 'æ', 'ø', 'å', 'ü']
+            }
+            InternationalCharset::DenmarkII => {
+                &['#', '$', 'É', 'Æ', 'Ø', 'Å', 'Ü', 'é', 'æ', 'ø', 'å', 'ü']
+            }
+            InternationalCharset::SpainII => {
+                &['#', '$', 'á', '¡', 'Ñ', '¿', 'é', '`', 'í', 'ñ', 'ó', 'ú']
+            }
+            InternationalCharset::LatinAmerica => {
+                &['#', '$', 'á', '¡', 'Ñ', '¿', 'é', 'ü', 'í', 'ñ', 'ó', 'ú']
+            }
+        }
+    }
+}
+
+/// ASCII positions replaced by international character sets
+const INTERNATIONAL_POSITIONS: [u8; 12] = [
+    0x23, 0x24, 0x40, 0x5B, 0x5C, 0x5D, 0x5E, 0x60, 0x7B, 0x7C, 0x7D, 0x7E,
+];
+
+/// Maps Unicode characters to printer bytes for one table/charset selection.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Encoder {
+    table: CharacterTable,
+    international: InternationalCharset,
+    /// Byte printed for unmappable characters
+    fallback: u8,
+}
+
+impl Default for Encoder {
+    /// ASCII table, USA character set and '?' fallback (the ESC @ state)
+    fn default() -> Self {
+        Encoder::new(CharacterTable::Ascii, InternationalCharset::Usa, '?')
+    }
+}
+
+impl Encoder {
+    /// Creates an encoder; an unmappable fallback character becomes '?'.
+    pub(crate) fn new(
+        table: CharacterTable,
+        international: InternationalCharset,
+        fallback: char,
+    ) -> Self {
+        let mut encoder = Encoder {
+            table,
+            international,
+            fallback: b'?',
+        };
+        if let Some(byte) = encoder.encode_exact(fallback) {
+            encoder.fallback = byte;
+        }
+        encoder
+    }
+
+    /// Returns the printer byte for `ch`, or the fallback byte.
+    ///
+    /// Box-drawing characters missing from the active tables print as their
+    /// ASCII look-alike (see [`box_drawing_fallback`]).
+    pub(crate) fn encode(&self, ch: char) -> u8 {
+        self.encode_exact(ch)
+            .or_else(|| box_drawing_fallback(ch).and_then(|ascii| self.encode_exact(ascii)))
+            .unwrap_or(self.fallback)
+    }
+
+    /// Returns the printer byte for `ch` if the active tables contain it.
+    pub(crate) fn encode_exact(&self, ch: char) -> Option<u8> {
+        if let Some(i) = self
+            .international
+            .replacements()
+            .iter()
+            .position(|&c| c == ch)
+        {
+            return Some(INTERNATIONAL_POSITIONS[i]);
+        }
+
+        if ch.is_ascii() {
+            let byte = ch as u8;
+            // Control characters and positions taken over by the
+            // international set do not print this character
+            if byte < 0x20 || byte == 0x7F || INTERNATIONAL_POSITIONS.contains(&byte) {
+                return None;
+            }
+            return Some(byte);
+        }
+
+        self.table
+            .upper_half()?
+            .iter()
+            .position(|&c| c == ch)
+            .map(|i| 0x80 + i as u8)
+    }
+}
+
+impl Encoder {
+    /// Returns the character the printer prints for `byte`, if any.
+    ///
+    /// This is the inverse of [`Encoder::encode_exact`]: control bytes and
+    /// bytes unassigned in the active table yield None.
+    pub(crate) fn decode(&self, byte: u8) -> Option<char> {
+        if let Some(i) = INTERNATIONAL_POSITIONS.iter().position(|&b| b == byte) {
+            return Some(self.international.replacements()[i]);
+        }
+
+        match byte {
+            0x20..=0x7E => Some(byte as char),
+            0x80..=0xFF => {
+                let ch = self.table.upper_half()?[(byte - 0x80) as usize];
+                (ch != '\0').then_some(ch)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the ASCII look-alike of a box-drawing character (U+2500-U+257F).
+///
+/// Horizontal lines become '-' ('=' for double lines), vertical lines '|',
+/// and corners, tees and crosses '+'.
+pub(crate) fn box_drawing_fallback(ch: char) -> Option<char> {
+    let ascii = match ch {
+        '═' => '=',
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => {
+            '-'
+        }
+        '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿' => {
+            '|'
+        }
+        '╱' => '/',
+        '╲' => '\\',
+        '╳' => 'X',
+        '\u{2500}'..='\u{257F}' => '+',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Upper half (0x80-0xFF) of PC437 (US)
+const PC437_UPPER: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// Upper half (0x80-0xFF) of PC850 (Multilingual)
+const PC850_UPPER: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©',
+    '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '¤', 'ð', 'Ð', 'Ê', 'Ë', 'È', 'ı', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì',
+    '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´', '\u{ad}',
+    '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{a0}',
+];
+
+/// Upper half (0x80-0xFF) of PC858 (PC850 with the euro sign at 0xD5)
+const PC858_UPPER: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©',
+    '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '¤', 'ð', 'Ð', 'Ê', 'Ë', 'È', '€', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì',
+    '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´', '\u{ad}',
+    '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{a0}',
+];
+
+/// Upper half (0x80-0xFF) of ISO-8859-15 (0x80-0x9F unassigned)
+const ISO_8859_15_UPPER: [char; 128] = [
+    '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}',
+    '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}',
+    '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}', '\u{0}',
+    '\u{0}', '\u{0}', '\u{a0}', '¡', '¢', '£', '€', '¥', 'Š', '§', 'š', '©', 'ª', '«', '¬',
+    '\u{ad}', '®', '¯', '°', '±', '²', '³', 'Ž', 'µ', '¶', '·', 'ž', '¹', 'º', '»', 'Œ', 'œ', 'Ÿ',
+    '¿', 'À', 'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ',
+    'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä',
+    'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷',
+    'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoder(table: CharacterTable, international: InternationalCharset) -> Encoder {
+        Encoder::new(table, international, '?')
+    }
+
+    #[test]
+    fn test_ascii_passthrough() {
+        let encoder = encoder(CharacterTable::Ascii, InternationalCharset::Usa);
+        assert_eq!(encoder.encode('A'), b'A');
+        assert_eq!(encoder.encode('~'), b'~');
+        assert_eq!(encoder.encode('#'), b'#');
+    }
+
+    #[test]
+    fn test_ascii_table_non_ascii_uses_fallback() {
+        let encoder = encoder(CharacterTable::Ascii, InternationalCharset::Usa);
+        assert_eq!(encoder.encode('é'), b'?');
+        assert_eq!(encoder.encode_exact('é'), None);
+    }
+
+    #[test]
+    fn test_code_pages() {
+        let pc437 = encoder(CharacterTable::Pc437, InternationalCharset::Usa);
+        assert_eq!(pc437.encode('é'), 0x82);
+        assert_eq!(pc437.encode('ñ'), 0xA4);
+        assert_eq!(pc437.encode('£'), 0x9C);
+        assert_eq!(pc437.encode('─'), 0xC4);
+        assert_eq!(pc437.encode('€'), b'?');
+
+        let pc850 = encoder(CharacterTable::Pc850, InternationalCharset::Usa);
+        assert_eq!(pc850.encode('ü'), 0x81);
+        assert_eq!(pc850.encode('Ã'), 0xC7);
+        assert_eq!(pc850.encode('€'), b'?');
+
+        let pc858 = encoder(CharacterTable::Pc858, InternationalCharset::Usa);
+        assert_eq!(pc858.encode('€'), 0xD5);
+
+        let latin9 = encoder(CharacterTable::Iso8859_15, InternationalCharset::Usa);
+        assert_eq!(latin9.encode('€'), 0xA4);
+        assert_eq!(latin9.encode('é'), 0xE9);
+        assert_eq!(latin9.encode('Š'), 0xA6);
+    }
+
+    #[test]
+    fn test_international_charsets() {
+        let germany = encoder(CharacterTable::Ascii, InternationalCharset::Germany);
+        assert_eq!(germany.encode('ä'), 0x7B);
+        assert_eq!(germany.encode('ß'), 0x7E);
+        assert_eq!(germany.encode('§'), 0x40);
+        // '@' is no longer printable under the German set
+        assert_eq!(germany.encode_exact('@'), None);
+        // Unchanged positions keep their ASCII meaning
+        assert_eq!(germany.encode('#'), 0x23);
+
+        let uk = encoder(CharacterTable::Ascii, InternationalCharset::UnitedKingdom);
+        assert_eq!(uk.encode('£'), 0x23);
+        assert_eq!(uk.encode_exact('#'), None);
+    }
+
+    #[test]
+    fn test_international_charset_over_code_page() {
+        // The international set takes precedence over the upper half
+        let encoder = encoder(CharacterTable::Pc850, InternationalCharset::France);
+        assert_eq!(encoder.encode('é'), 0x7B);
+        assert_eq!(encoder.encode('É'), 0x90);
+    }
+
+    #[test]
+    fn test_custom_fallback() {
+        let encoder = Encoder::new(CharacterTable::Ascii, InternationalCharset::Usa, '*');
+        assert_eq!(encoder.encode('€'), b'*');
+
+        // An unmappable fallback itself becomes '?'
+        let encoder = Encoder::new(CharacterTable::Ascii, InternationalCharset::Usa, '¤');
+        assert_eq!(encoder.encode('€'), b'?');
+    }
+
+    #[test]
+    fn test_box_drawing_native() {
+        let pc437 = encoder(CharacterTable::Pc437, InternationalCharset::Usa);
+        assert_eq!(pc437.encode('─'), 0xC4);
+        assert_eq!(pc437.encode('│'), 0xB3);
+        assert_eq!(pc437.encode('┌'), 0xDA);
+        assert_eq!(pc437.encode('╔'), 0xC9);
+        assert_eq!(pc437.encode('═'), 0xCD);
+
+        let pc850 = encoder(CharacterTable::Pc850, InternationalCharset::Usa);
+        assert_eq!(pc850.encode('┐'), 0xBF);
+        assert_eq!(pc850.encode('╝'), 0xBC);
+    }
+
+    #[test]
+    fn test_box_drawing_ascii_fallback() {
+        let latin9 = encoder(CharacterTable::Iso8859_15, InternationalCharset::Usa);
+        assert_eq!(latin9.encode('─'), b'-');
+        assert_eq!(latin9.encode('═'), b'=');
+        assert_eq!(latin9.encode('│'), b'|');
+        assert_eq!(latin9.encode('║'), b'|');
+        assert_eq!(latin9.encode('┌'), b'+');
+        assert_eq!(latin9.encode('╬'), b'+');
+        assert_eq!(latin9.encode('╭'), b'+');
+
+        // PC850 lacks the mixed single/double corners
+        let pc850 = encoder(CharacterTable::Pc850, InternationalCharset::Usa);
+        assert_eq!(pc850.encode('╒'), b'+');
+    }
+
+    #[test]
+    fn test_box_drawing_fallback_displaced_by_international_set() {
+        // '|' is 'ö' in the German set, so the vertical line has no glyph
+        let encoder = encoder(CharacterTable::Ascii, InternationalCharset::Germany);
+        assert_eq!(encoder.encode('│'), b'?');
+        assert_eq!(encoder.encode('─'), b'-');
+    }
+
+    #[test]
+    fn test_control_characters_unmappable() {
+        let encoder = encoder(CharacterTable::Pc437, InternationalCharset::Usa);
+        assert_eq!(encoder.encode_exact('\n'), None);
+        assert_eq!(encoder.encode_exact('\u{7f}'), None);
+        assert_eq!(encoder.encode_exact('\u{85}'), None);
+    }
+
+    #[test]
+    fn test_decode_inverts_encode() {
+        for table in [
+            CharacterTable::Pc437,
+            CharacterTable::Pc850,
+            CharacterTable::Pc858,
+            CharacterTable::Iso8859_15,
+        ] {
+            let encoder = encoder(table, InternationalCharset::Sweden);
+            for byte in 0x20..=0xFF {
+                // Characters printable at two positions encode to either
+                if let Some(ch) = encoder.decode(byte) {
+                    let encoded = encoder.encode_exact(ch).unwrap();
+                    assert_eq!(encoder.decode(encoded), Some(ch), "{table:?} {byte:#x}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode() {
+        let encoder = encoder(
+            CharacterTable::Iso8859_15,
+            InternationalCharset::UnitedKingdom,
+        );
+        assert_eq!(encoder.decode(b'A'), Some('A'));
+        assert_eq!(encoder.decode(0x23), Some('£'));
+        assert_eq!(encoder.decode(0xA4), Some('€'));
+        assert_eq!(encoder.decode(0x85), None);
+        assert_eq!(encoder.decode(0x0A), None);
+        assert_eq!(encoder.decode(0x7F), None);
+    }
+
+    #[test]
+    fn test_from_codes() {
+        assert_eq!(
+            CharacterTable::from_table_id(13, 32),
+            Some(CharacterTable::Pc858)
+        );
+        assert_eq!(CharacterTable::from_table_id(0, 0), None);
+        assert_eq!(
+            InternationalCharset::from_code(2),
+            Some(InternationalCharset::Germany)
+        );
+        assert_eq!(InternationalCharset::from_code(64), None);
+    }
+
+    #[test]
+    fn test_table_ids() {
+        assert_eq!(CharacterTable::Ascii.table_id(), None);
+        assert_eq!(CharacterTable::Pc437.table_id(), Some((1, 0)));
+        assert_eq!(CharacterTable::Pc850.table_id(), Some((3, 0)));
+        assert_eq!(CharacterTable::Pc858.table_id(), Some((13, 32)));
+        assert_eq!(CharacterTable::Iso8859_15.table_id(), Some((29, 16)));
+    }
+}
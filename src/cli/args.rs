@@ -0,0 +1,153 @@
+//! Minimal command-line argument parsing.
+
+use super::UsageError;
+use std::collections::BTreeMap;
+
+/// Option accepted by a subcommand
+pub(crate) struct Opt {
+    /// Long name, without the leading `--`
+    pub long: &'static str,
+    /// Optional one-letter alias, without the leading `-`
+    pub short: Option<char>,
+    /// Whether the option takes a value
+    pub takes_value: bool,
+}
+
+/// Parsed arguments of a subcommand
+#[derive(Debug)]
+pub(crate) struct Args {
+    positionals: Vec<String>,
+    /// Given options by long name; switches map to an empty string
+    options: BTreeMap<&'static str, String>,
+}
+
+impl Args {
+    /// Parses `args` against the options a subcommand accepts.
+    ///
+    /// Accepts `--name value`, `--name=value` and `-n value`. A lone `-` is
+    /// a positional argument (stdin or stdout) and `--` ends the options.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        opts: &[Opt],
+        positionals: &[&str],
+    ) -> Result<Args, UsageError> {
+        let mut parsed = Args {
+            positionals: Vec::new(),
+            options: BTreeMap::new(),
+        };
+        let mut args = args.into_iter();
+        let mut options_ended = false;
+
+        while let Some(arg) = args.next() {
+            if options_ended || arg == "-" || !arg.starts_with('-') {
+                parsed.positionals.push(arg);
+                continue;
+            }
+            if arg == "--" {
+                options_ended = true;
+                continue;
+            }
+
+            let (name, inline_value) = match arg.strip_prefix("--") {
+                Some(long) => match long.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (long, None),
+                },
+                None => (&arg[1..], None),
+            };
+            let opt = opts
+                .iter()
+                .find(|opt| {
+                    if arg.starts_with("--") {
+                        opt.long == name
+                    } else {
+                        name.chars().eq(opt.short)
+                    }
+                })
+                .ok_or_else(|| UsageError(format!("unknown option {}", arg)))?;
+
+            let value = match (opt.takes_value, inline_value) {
+                (true, Some(value)) => value,
+                (true, None) => args
+                    .next()
+                    .ok_or_else(|| UsageError(format!("--{} needs a value", opt.long)))?,
+                (false, Some(_)) => {
+                    return Err(UsageError(format!("--{} takes no value", opt.long)));
+                }
+                (false, None) => String::new(),
+            };
+            if parsed.options.insert(opt.long, value).is_some() {
+                return Err(UsageError(format!("--{} given more than once", opt.long)));
+            }
+        }
+
+        if parsed.positionals.len() != positionals.len() {
+            return Err(UsageError(format!(
+                "expected {} argument(s): {}",
+                positionals.len(),
+                positionals.join(" ")
+            )));
+        }
+        Ok(parsed)
+    }
+
+    /// Returns the positional argument at `index`
+    pub fn positional(&self, index: usize) -> &str {
+        &self.positionals[index]
+    }
+
+    /// Returns the value of an option, if given
+    pub fn value(&self, long: &str) -> Option<&str> {
+        self.options.get(long).map(String::as_str)
+    }
+
+    /// Returns true if a switch was given
+    pub fn is_set(&self, long: &str) -> bool {
+        self.options.contains_key(long)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPTS: &[Opt] = &[
+        Opt {
+            long: "output",
+            short: Some('o'),
+            takes_value: true,
+        },
+        Opt {
+            long: "html",
+            short: None,
+            takes_value: false,
+        },
+    ];
+
+    fn parse(args: &[&str], positionals: &[&str]) -> Result<Args, UsageError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()), OPTS, positionals)
+    }
+
+    #[test]
+    fn test_options_and_positionals() {
+        let args = parse(&["in.prn", "-o", "out.html", "--html"], &["INPUT"]).unwrap();
+        assert_eq!(args.positional(0), "in.prn");
+        assert_eq!(args.value("output"), Some("out.html"));
+        assert!(args.is_set("html"));
+
+        let args = parse(&["--output=-", "--", "-x"], &["INPUT"]).unwrap();
+        assert_eq!(args.positional(0), "-x");
+        assert_eq!(args.value("output"), Some("-"));
+        assert!(!args.is_set("html"));
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        assert!(parse(&["a", "--verbose"], &["INPUT"]).is_err());
+        assert!(parse(&["a", "--output"], &["INPUT"]).is_err());
+        assert!(parse(&["a", "--html=yes"], &["INPUT"]).is_err());
+        assert!(parse(&["a", "-o", "x", "-o", "y"], &["INPUT"]).is_err());
+        assert!(parse(&["a", "b"], &["INPUT"]).is_err());
+        assert!(parse(&[], &["INPUT"]).is_err());
+    }
+}
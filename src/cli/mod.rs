@@ -0,0 +1,323 @@
+//! Subcommands of the `escp-layout` tool.
+
+mod args;
+
+use args::{Args, Opt};
+use escp_layout::transport::{DeviceTransport, LprTransport, TcpTransport, Transport};
+use escp_layout::widget::{Template, TemplateValue, WidgetDescription};
+use escp_layout::{Decoder, Document, Page, PageGeometry};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Invalid command line
+#[derive(Debug)]
+pub(crate) struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for UsageError {}
+
+const DATA: Opt = Opt {
+    long: "data",
+    short: Some('d'),
+    takes_value: true,
+};
+
+const OUTPUT: Opt = Opt {
+    long: "output",
+    short: Some('o'),
+    takes_value: true,
+};
+
+const HTML: Opt = Opt {
+    long: "html",
+    short: None,
+    takes_value: false,
+};
+
+const JOB_NAME: Opt = Opt {
+    long: "job-name",
+    short: Some('j'),
+    takes_value: true,
+};
+
+const TIMEOUT: Opt = Opt {
+    long: "timeout",
+    short: None,
+    takes_value: true,
+};
+
+/// `render <LAYOUT> [--data FILE] [--output FILE]`
+pub(crate) fn render(args: impl Iterator<Item = String>) -> Result<ExitCode> {
+    let args = Args::parse(args, &[DATA, OUTPUT], &["LAYOUT"])?;
+    let document = load_layout(args.positional(0), args.value("data"))?;
+
+    let output = args.value("output").unwrap_or("-");
+    if output == "-" && io::stdout().is_terminal() {
+        return Err("refusing to write ESC/P bytes to a terminal; use --output".into());
+    }
+    write_output(output, &document.render())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `preview <INPUT> [--data FILE] [--html] [--output FILE]`
+pub(crate) fn preview(args: impl Iterator<Item = String>) -> Result<ExitCode> {
+    let args = Args::parse(args, &[DATA, HTML, OUTPUT], &["INPUT"])?;
+    let input = args.positional(0);
+    let document = if is_layout(input) {
+        load_layout(input, args.value("data"))?
+    } else if args.is_set("data") {
+        return Err(UsageError("--data needs a layout input".to_string()).into());
+    } else {
+        decode_file(input)?
+    };
+
+    let output = args.value("output").unwrap_or("-");
+    let text = if args.is_set("html") {
+        document.to_html()
+    } else if output == "-" && io::stdout().is_terminal() {
+        document.to_ansi()
+    } else {
+        document.to_plain_text()
+    };
+    write_output(output, text.as_bytes())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `decode <FILE> [--output FILE]`
+pub(crate) fn decode(args: impl Iterator<Item = String>) -> Result<ExitCode> {
+    let args = Args::parse(args, &[OUTPUT], &["FILE"])?;
+    let document = decode_file(args.positional(0))?;
+    let output = args.value("output").unwrap_or("-");
+    write_output(output, document.to_plain_text().as_bytes())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `diff <BEFORE> <AFTER>`
+pub(crate) fn diff(args: impl Iterator<Item = String>) -> Result<ExitCode> {
+    let args = Args::parse(args, &[], &["BEFORE", "AFTER"])?;
+    let (before, after) = (args.positional(0), args.positional(1));
+    if before == "-" && after == "-" {
+        return Err(UsageError("only one input can be stdin".to_string()).into());
+    }
+
+    let before = load(before)?;
+    let after = load(after)?;
+    let diff = before.diff(&after);
+    if diff.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+    write_output("-", diff.side_by_side().as_bytes())?;
+    Ok(ExitCode::from(1))
+}
+
+/// `send <FILE> <DESTINATION> [--job-name NAME] [--timeout SECONDS]`
+pub(crate) fn send(args: impl Iterator<Item = String>) -> Result<ExitCode> {
+    let args = Args::parse(args, &[JOB_NAME, TIMEOUT], &["FILE", "DESTINATION"])?;
+    let bytes = read_input(args.positional(0))?;
+    let mut transport = transport(&args)?;
+    transport.send(&bytes)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Returns the transport for the destination of `send`.
+///
+/// `--job-name` only applies to the spooler and `--timeout` only to a
+/// network address; either one with another destination is a usage error.
+fn transport(args: &Args) -> Result<Box<dyn Transport>> {
+    let destination = args.positional(1);
+    let spooler = [("lpr:", LprTransport::lpr()), ("lp:", LprTransport::lp())]
+        .into_iter()
+        .find_map(|(prefix, spooler)| Some((destination.strip_prefix(prefix)?, spooler)));
+    let network = spooler.is_none() && is_network_address(destination);
+
+    if spooler.is_none() && args.is_set("job-name") {
+        return Err(UsageError("--job-name needs an lpr: or lp: destination".to_string()).into());
+    }
+    if !network && args.is_set("timeout") {
+        return Err(UsageError("--timeout needs a HOST:PORT destination".to_string()).into());
+    }
+
+    if let Some((printer, mut spooler)) = spooler {
+        if !printer.is_empty() {
+            spooler = spooler.with_printer(printer);
+        }
+        if let Some(job_name) = args.value("job-name") {
+            spooler = spooler.with_job_name(job_name);
+        }
+        Ok(Box::new(spooler))
+    } else if network {
+        let mut tcp = TcpTransport::new(destination);
+        if let Some(timeout) = args.value("timeout") {
+            // Sockets reject a zero timeout, and huge ones overflow Duration
+            let timeout = timeout
+                .parse()
+                .ok()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                .filter(|duration| !duration.is_zero())
+                .ok_or_else(|| UsageError(format!("invalid timeout {:?}", timeout)))?;
+            tcp = tcp
+                .with_connect_timeout(timeout)
+                .with_write_timeout(timeout);
+        }
+        Ok(Box::new(tcp))
+    } else {
+        Ok(Box::new(DeviceTransport::new(destination)))
+    }
+}
+
+/// Loads a layout or ESC/P file, depending on its extension.
+///
+/// Stdin (`-`) has no extension and is always read as ESC/P bytes.
+fn load(path: &str) -> Result<Document> {
+    if is_layout(path) {
+        load_layout(path, None)
+    } else {
+        decode_file(path)
+    }
+}
+
+/// Returns true if `path` names a JSON or TOML layout
+fn is_layout(path: &str) -> bool {
+    matches!(extension(path).as_deref(), Some("json" | "toml"))
+}
+
+fn extension(path: &str) -> Option<String> {
+    let extension = Path::new(path).extension()?.to_str()?;
+    Some(extension.to_ascii_lowercase())
+}
+
+/// Returns true if `destination` is `HOST:PORT` rather than a path
+fn is_network_address(destination: &str) -> bool {
+    !destination.contains('/')
+        && !destination.contains('\\')
+        && destination
+            .rsplit_once(':')
+            .is_some_and(|(host, port)| !host.is_empty() && port.parse::<u16>().is_ok())
+}
+
+/// Renders a layout, filled from the records in `data` if given
+fn load_layout(path: &str, data: Option<&str>) -> Result<Document> {
+    let description: WidgetDescription = parse_file(path)?;
+
+    let mut builder = Document::builder();
+    match data {
+        Some(data) => {
+            let template = Template::new(description).map_err(|e| format!("{}: {}", path, e))?;
+            let records = match parse_file(data)? {
+                TemplateValue::List(records) => records,
+                record => vec![record],
+            };
+            for (index, record) in records.iter().enumerate() {
+                let page = template
+                    .render(record)
+                    .map_err(|e| format!("{}: record {}: {}", data, index, e))?;
+                builder.add_page(page);
+            }
+        }
+        None => {
+            let layout = description
+                .build()
+                .map_err(|e| format!("{}: {}", path, e))?;
+            let mut page_builder = Page::builder();
+            page_builder
+                .render_layout(&layout)
+                .map_err(|e| format!("{}: {}", path, e))?;
+            builder.add_page(page_builder.build());
+        }
+    }
+    Ok(builder.build())
+}
+
+/// Decodes an ESC/P file, warning about unknown sequences.
+///
+/// Pages are at least as wide as the default page grid, so files compare
+/// equal to layouts and to each other regardless of their widest line.
+fn decode_file(path: &str) -> Result<Document> {
+    let bytes = read_input(path)?;
+    let mut decoded = Decoder::new().decode(&bytes);
+    let default_columns = PageGeometry::default().columns();
+    let columns = decoded
+        .document()
+        .pages()
+        .first()
+        .map(|page| page.geometry().columns());
+    if columns.is_some_and(|columns| columns < default_columns) {
+        decoded = Decoder::new().with_columns(default_columns).decode(&bytes);
+    }
+    for sequence in decoded.unknown_sequences() {
+        eprintln!("escp-layout: warning: {}: {}", path, sequence);
+    }
+    Ok(decoded.into_document())
+}
+
+/// Parses a JSON file, or a TOML file if the name ends in `.toml`
+fn parse_file<T: serde_dep::de::DeserializeOwned>(path: &str) -> Result<T> {
+    let text = String::from_utf8(read_input(path)?).map_err(|_| format!("{}: not UTF-8", path))?;
+    let value = if extension(path).as_deref() == Some("toml") {
+        toml::from_str(&text).map_err(|e| format!("{}: {}", path, e))?
+    } else {
+        serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))?
+    };
+    Ok(value)
+}
+
+/// Reads a file, or stdin for `-`
+fn read_input(path: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let result = if path == "-" {
+        io::stdin().read_to_end(&mut bytes)
+    } else {
+        File::open(path).and_then(|mut file| file.read_to_end(&mut bytes))
+    };
+    result.map_err(|error| format!("{}: {}", path, error))?;
+    Ok(bytes)
+}
+
+/// Writes a file, or stdout for `-`
+fn write_output(path: &str, bytes: &[u8]) -> Result<()> {
+    let result = if path == "-" {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(bytes).and_then(|()| stdout.flush())
+    } else {
+        std::fs::write(path, bytes)
+    };
+    result.map_err(|error| format!("{}: {}", path, error))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_network_address() {
+        assert!(is_network_address("printer.local:9100"));
+        assert!(is_network_address("192.168.1.20:9100"));
+        assert!(is_network_address("[::1]:9100"));
+        assert!(!is_network_address("/dev/usb/lp0"));
+        assert!(!is_network_address("out.prn"));
+        assert!(!is_network_address("./weird:9100"));
+        assert!(!is_network_address(":9100"));
+        assert!(!is_network_address("host:port"));
+    }
+
+    #[test]
+    fn test_is_layout() {
+        assert!(is_layout("invoice.json"));
+        assert!(is_layout("INVOICE.TOML"));
+        assert!(!is_layout("invoice.prn"));
+        assert!(!is_layout("-"));
+    }
+}
@@ -0,0 +1,523 @@
+//! Cell-by-cell comparison of pages and documents.
+
+use crate::cell::Cell;
+use crate::document::Document;
+use crate::geometry::PageGeometry;
+use crate::page::Page;
+use crate::preview::line_text;
+use std::fmt;
+use std::ops::Range;
+
+/// A cell whose character or style differs between two pages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CellChange {
+    x: u16,
+    y: u16,
+    before: Cell,
+    after: Cell,
+}
+
+impl CellChange {
+    /// Returns the column of the changed cell
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the row of the changed cell
+    pub fn y(&self) -> u16 {
+        self.y
+    }
+
+    /// Returns the cell on the first page ([`Cell::EMPTY`] if outside its grid)
+    pub fn before(&self) -> Cell {
+        self.before
+    }
+
+    /// Returns the cell on the second page ([`Cell::EMPTY`] if outside its grid)
+    pub fn after(&self) -> Cell {
+        self.after
+    }
+
+    /// Returns true if the character differs.
+    ///
+    /// A cell becoming covered by (or freed from) a double-size glyph counts
+    /// as a text change.
+    pub fn text_changed(&self) -> bool {
+        self.before.character != self.after.character
+    }
+
+    /// Returns true if the style flags differ
+    pub fn style_changed(&self) -> bool {
+        self.before.style != self.after.style
+    }
+}
+
+/// Differences between two pages, cell by cell.
+///
+/// Created with [`Page::diff`]. Pages of different sizes are compared over
+/// the union of both grids, with cells outside a grid read as
+/// [`Cell::EMPTY`].
+///
+/// The [`Display`](fmt::Display) implementation shows the changed rows side
+/// by side, as returned by [`PageDiff::side_by_side`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PageDiff {
+    before_geometry: PageGeometry,
+    after_geometry: PageGeometry,
+    /// Changed cells in row-major order
+    changes: Vec<CellChange>,
+    /// Text of every changed row: (row, before, after)
+    lines: Vec<(u16, String, String)>,
+}
+
+impl PageDiff {
+    /// Compares `before` with `after`
+    pub(crate) fn new(before: &Page, after: &Page) -> Self {
+        let columns = before.geometry().columns().max(after.geometry().columns());
+        let rows = before.geometry().rows().max(after.geometry().rows());
+        let cell = |page: &Page, x, y| page.get_cell(x, y).unwrap_or(Cell::EMPTY);
+
+        let mut changes = Vec::new();
+        let mut lines = Vec::new();
+        for y in 0..rows {
+            let first_change = changes.len();
+            for x in 0..columns {
+                let (old, new) = (cell(before, x, y), cell(after, x, y));
+                if old != new {
+                    changes.push(CellChange {
+                        x,
+                        y,
+                        before: old,
+                        after: new,
+                    });
+                }
+            }
+            if changes.len() > first_change {
+                lines.push((
+                    y,
+                    line_text(before.row(y).unwrap_or(&[]), false),
+                    line_text(after.row(y).unwrap_or(&[]), false),
+                ));
+            }
+        }
+
+        PageDiff {
+            before_geometry: before.geometry(),
+            after_geometry: after.geometry(),
+            changes,
+            lines,
+        }
+    }
+
+    /// Returns true if both pages have the same geometry and cells
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && !self.geometry_changed()
+    }
+
+    /// Returns true if the pages have different grid dimensions
+    pub fn geometry_changed(&self) -> bool {
+        self.before_geometry != self.after_geometry
+    }
+
+    /// Returns the geometry of the first page
+    pub fn before_geometry(&self) -> PageGeometry {
+        self.before_geometry
+    }
+
+    /// Returns the geometry of the second page
+    pub fn after_geometry(&self) -> PageGeometry {
+        self.after_geometry
+    }
+
+    /// Returns every changed cell in row-major order
+    pub fn changes(&self) -> &[CellChange] {
+        &self.changes
+    }
+
+    /// Returns the changed cells whose character differs
+    pub fn text_changes(&self) -> impl Iterator<Item = &CellChange> {
+        self.changes.iter().filter(|change| change.text_changed())
+    }
+
+    /// Returns the changed cells whose style differs
+    pub fn style_changes(&self) -> impl Iterator<Item = &CellChange> {
+        self.changes.iter().filter(|change| change.style_changed())
+    }
+
+    /// Returns the rows containing at least one changed cell, top to bottom
+    pub fn changed_rows(&self) -> Vec<u16> {
+        self.lines.iter().map(|(y, _, _)| *y).collect()
+    }
+
+    /// Returns the columns containing at least one changed cell, left to right
+    pub fn changed_columns(&self) -> Vec<u16> {
+        let mut columns: Vec<u16> = self.changes.iter().map(|change| change.x).collect();
+        columns.sort_unstable();
+        columns.dedup();
+        columns
+    }
+
+    /// Returns a human-readable rendering of the changed rows.
+    ///
+    /// Each changed row is shown with its number, the first page's text on
+    /// the left and the second page's on the right, followed by a line of
+    /// `^` markers under the changed columns (style-only changes are marked
+    /// too). A geometry change is reported on a line of its own. Returns an
+    /// empty string if the pages are identical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Page, PageGeometry, StyleFlags};
+    ///
+    /// let page = |text: &str, style: StyleFlags| {
+    ///     let mut builder = Page::builder_with_geometry(PageGeometry::new(20, 4));
+    ///     builder.write_str(0, 1, text, style);
+    ///     builder.build()
+    /// };
+    /// let before = page("Total: 42", StyleFlags::NONE);
+    /// let after = page("Total: 43", StyleFlags::NONE);
+    ///
+    /// assert_eq!(
+    ///     before.diff(&after).side_by_side(),
+    ///     "   1 | Total: 42 | Total: 43\n     |         ^ |         ^\n"
+    /// );
+    /// ```
+    pub fn side_by_side(&self) -> String {
+        let mut text = String::new();
+        if self.geometry_changed() {
+            text.push_str(&format!(
+                "geometry: {}x{} | {}x{}\n",
+                self.before_geometry.columns(),
+                self.before_geometry.rows(),
+                self.after_geometry.columns(),
+                self.after_geometry.rows(),
+            ));
+        }
+
+        let markers: Vec<String> = self
+            .lines
+            .iter()
+            .map(|(y, _, _)| {
+                let mut marker = String::new();
+                for change in self.changes.iter().filter(|change| change.y == *y) {
+                    let x = change.x as usize;
+                    marker.push_str(&" ".repeat(x - marker.len()));
+                    marker.push('^');
+                }
+                marker
+            })
+            .collect();
+        let width = self
+            .lines
+            .iter()
+            .zip(&markers)
+            .map(|((_, before, _), marker)| before.chars().count().max(marker.len()))
+            .max()
+            .unwrap_or(0);
+
+        for ((y, before, after), marker) in self.lines.iter().zip(&markers) {
+            let row = format!("{:>4} | {:<width$} | {}", y, before, after);
+            text.push_str(row.trim_end());
+            text.push('\n');
+            let row = format!("     | {:<width$} | {}", marker, marker);
+            text.push_str(row.trim_end());
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl fmt::Display for PageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.side_by_side())
+    }
+}
+
+/// Differences between two documents, page by page.
+///
+/// Created with [`Document::diff`]. Pages are paired by index; pages past
+/// the end of the shorter document are reported as added or removed.
+///
+/// The [`Display`](fmt::Display) implementation shows every difference, as
+/// returned by [`DocumentDiff::side_by_side`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentDiff {
+    /// One description per differing document setting
+    settings: Vec<String>,
+    /// Diffs of the pages both documents have, by index
+    pages: Vec<PageDiff>,
+    before_pages: usize,
+    after_pages: usize,
+}
+
+impl DocumentDiff {
+    /// Compares `before` with `after`
+    pub(crate) fn new(before: &Document, after: &Document) -> Self {
+        let mut settings = Vec::new();
+        let mut compare = |name: &str, old: String, new: String| {
+            if old != new {
+                settings.push(format!("{}: {} | {}", name, old, new));
+            }
+        };
+        compare(
+            "pitch",
+            format!("{:?}", before.pitch()),
+            format!("{:?}", after.pitch()),
+        );
+        compare(
+            "line spacing",
+            format!("{:?}", before.line_spacing()),
+            format!("{:?}", after.line_spacing()),
+        );
+        compare(
+            "character table",
+            format!("{:?}", before.character_table()),
+            format!("{:?}", after.character_table()),
+        );
+        compare(
+            "international charset",
+            format!("{:?}", before.international_charset()),
+            format!("{:?}", after.international_charset()),
+        );
+        compare(
+            "fallback char",
+            format!("{:?}", before.fallback_char()),
+            format!("{:?}", after.fallback_char()),
+        );
+
+        DocumentDiff {
+            settings,
+            pages: before
+                .pages()
+                .iter()
+                .zip(after.pages())
+                .map(|(old, new)| PageDiff::new(old, new))
+                .collect(),
+            before_pages: before.page_count(),
+            after_pages: after.page_count(),
+        }
+    }
+
+    /// Returns true if both documents have the same settings and pages
+    pub fn is_empty(&self) -> bool {
+        !self.settings_changed()
+            && self.before_pages == self.after_pages
+            && self.pages.iter().all(PageDiff::is_empty)
+    }
+
+    /// Returns true if the documents differ in pitch, line spacing,
+    /// character table, international character set or fallback character
+    pub fn settings_changed(&self) -> bool {
+        !self.settings.is_empty()
+    }
+
+    /// Returns the diffs of the pages both documents have, indexed like
+    /// their pages (including pages without differences)
+    pub fn page_diffs(&self) -> &[PageDiff] {
+        &self.pages
+    }
+
+    /// Returns the indices of pages only the second document has
+    pub fn added_pages(&self) -> Range<usize> {
+        self.pages.len()..self.after_pages
+    }
+
+    /// Returns the indices of pages only the first document has
+    pub fn removed_pages(&self) -> Range<usize> {
+        self.pages.len()..self.before_pages
+    }
+
+    /// Returns a human-readable rendering of all differences.
+    ///
+    /// Setting changes come first, then each differing page under a
+    /// `page N:` heading (1-based) rendered like
+    /// [`PageDiff::side_by_side`], then added or removed pages. Returns an
+    /// empty string if the documents are identical.
+    pub fn side_by_side(&self) -> String {
+        let mut text = String::new();
+        for setting in &self.settings {
+            text.push_str(setting);
+            text.push('\n');
+        }
+        for (index, page) in self.pages.iter().enumerate() {
+            if !page.is_empty() {
+                text.push_str(&format!("page {}:\n", index + 1));
+                text.push_str(&page.side_by_side());
+            }
+        }
+        for index in self.removed_pages() {
+            text.push_str(&format!("page {}: removed\n", index + 1));
+        }
+        for index in self.added_pages() {
+            text.push_str(&format!("page {}: added\n", index + 1));
+        }
+        text
+    }
+}
+
+impl fmt::Display for DocumentDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.side_by_side())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::StyleFlags;
+
+    fn page(build: impl FnOnce(&mut crate::page::PageBuilder)) -> Page {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(20, 6));
+        build(&mut builder);
+        builder.build()
+    }
+
+    fn document(pages: Vec<Page>) -> Document {
+        let mut builder = Document::builder();
+        for page in pages {
+            builder.add_page(page);
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_identical_pages() {
+        let a = page(|p| {
+            p.write_str(0, 0, "Same", StyleFlags::BOLD);
+        });
+        let diff = a.diff(&a.clone());
+        assert!(diff.is_empty());
+        assert!(diff.changes().is_empty());
+        assert_eq!(diff.side_by_side(), "");
+    }
+
+    #[test]
+    fn test_text_and_style_changes() {
+        let before = page(|p| {
+            p.write_str(2, 1, "abc", StyleFlags::NONE);
+            p.write_str(0, 4, "x", StyleFlags::NONE);
+        });
+        let after = page(|p| {
+            p.write_str(2, 1, "abd", StyleFlags::NONE);
+            p.write_str(0, 4, "x", StyleFlags::BOLD);
+        });
+        let diff = before.diff(&after);
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.changed_rows(), [1, 4]);
+        assert_eq!(diff.changed_columns(), [0, 4]);
+
+        let text: Vec<_> = diff.text_changes().map(|c| (c.x(), c.y())).collect();
+        assert_eq!(text, [(4, 1)]);
+        let style: Vec<_> = diff.style_changes().collect();
+        assert_eq!(style.len(), 1);
+        assert_eq!(style[0].before().style(), StyleFlags::NONE);
+        assert_eq!(style[0].after().style(), StyleFlags::BOLD);
+        assert!(!style[0].text_changed());
+    }
+
+    #[test]
+    fn test_side_by_side() {
+        let before = page(|p| {
+            p.write_str(0, 0, "Hello World", StyleFlags::NONE);
+            p.write_str(0, 3, "ok", StyleFlags::NONE);
+        });
+        let after = page(|p| {
+            p.write_str(0, 0, "Hello Wor1d", StyleFlags::NONE);
+            p.write_str(0, 3, "ok", StyleFlags::UNDERLINE);
+            p.write_str(0, 5, "new", StyleFlags::NONE);
+        });
+        assert_eq!(
+            before.diff(&after).to_string(),
+            "   0 | Hello World | Hello Wor1d\n\
+             \x20    |          ^  |          ^\n\
+             \x20  3 | ok          | ok\n\
+             \x20    | ^^          | ^^\n\
+             \x20  5 |             | new\n\
+             \x20    | ^^^         | ^^^\n"
+        );
+    }
+
+    #[test]
+    fn test_covered_cells_count_as_text() {
+        let before = page(|p| {
+            p.write_str(0, 0, "W", StyleFlags::NONE);
+        });
+        let after = page(|p| {
+            p.write_str(0, 0, "W", StyleFlags::DOUBLE_WIDTH);
+        });
+        let diff = before.diff(&after);
+        assert_eq!(diff.changes().len(), 2);
+        assert_eq!(diff.text_changes().count(), 1);
+        assert!(diff.changes()[1].after().is_covered());
+    }
+
+    #[test]
+    fn test_geometry_change() {
+        let before = page(|_| {});
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(24, 6));
+        builder.write_str(22, 0, "zz", StyleFlags::NONE);
+        let after = builder.build();
+
+        let diff = before.diff(&after);
+        assert!(diff.geometry_changed());
+        assert_eq!(diff.changed_columns(), [22, 23]);
+        assert_eq!(diff.changes()[0].before(), Cell::EMPTY);
+        assert!(diff.side_by_side().starts_with("geometry: 20x6 | 24x6\n"));
+
+        let blank = Page::builder_with_geometry(PageGeometry::new(20, 8)).build();
+        let diff = before.diff(&blank);
+        assert!(diff.changes().is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_document_pages_added_and_removed() {
+        let one = page(|p| {
+            p.write_str(0, 0, "one", StyleFlags::NONE);
+        });
+        let two = page(|p| {
+            p.write_str(0, 0, "two", StyleFlags::NONE);
+        });
+        let short = document(vec![one.clone()]);
+        let long = document(vec![one.clone(), two.clone(), two]);
+
+        let diff = short.diff(&long);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.page_diffs().len(), 1);
+        assert!(diff.page_diffs()[0].is_empty());
+        assert_eq!(diff.added_pages(), 1..3);
+        assert!(diff.removed_pages().is_empty());
+        assert_eq!(diff.side_by_side(), "page 2: added\npage 3: added\n");
+
+        let diff = long.diff(&short);
+        assert_eq!(diff.removed_pages(), 1..3);
+        assert!(diff.added_pages().is_empty());
+        assert!(short.diff(&short.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_document_settings_and_pages() {
+        let before = document(vec![page(|p| {
+            p.write_str(0, 2, "abc", StyleFlags::NONE);
+        })]);
+        let mut builder = Document::builder();
+        builder
+            .pitch(crate::geometry::Pitch::Cpi10)
+            .add_page(page(|p| {
+                p.write_str(0, 2, "abC", StyleFlags::NONE);
+            }));
+        let after = builder.build();
+
+        let diff = before.diff(&after);
+        assert!(diff.settings_changed());
+        assert_eq!(
+            diff.side_by_side(),
+            format!(
+                "pitch: {:?} | Cpi10\npage 1:\n   2 | abc | abC\n     |   ^ |   ^\n",
+                before.pitch()
+            )
+        );
+    }
+}
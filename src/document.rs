@@ -1,13 +1,29 @@
 //! Document and DocumentBuilder types for multi-page documents.
 
+use crate::charset::{CharacterTable, InternationalCharset};
+use crate::diff::DocumentDiff;
+use crate::escp::constants::{MAX_FORM_LENGTH, MAX_PRINT_WIDTH};
+use crate::escp::RenderOptions;
+use crate::geometry::{LineSpacing, Pitch};
 use crate::page::Page;
+use std::io::{self, Write};
 
 /// Represents a complete multi-page document.
 ///
 /// Documents are immutable after construction and can be rendered to ESC/P bytes.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize),
+    serde(crate = "serde_dep")
+)]
 pub struct Document {
     pages: Vec<Page>,
+    pitch: Pitch,
+    line_spacing: LineSpacing,
+    character_table: CharacterTable,
+    international_charset: InternationalCharset,
+    fallback_char: char,
 }
 
 impl Document {
@@ -34,6 +50,31 @@ impl Document {
         self.pages.len()
     }
 
+    /// Returns the character pitch the document is printed at.
+    pub fn pitch(&self) -> Pitch {
+        self.pitch
+    }
+
+    /// Returns the line spacing the document is printed at.
+    pub fn line_spacing(&self) -> LineSpacing {
+        self.line_spacing
+    }
+
+    /// Returns the character table used for non-ASCII characters.
+    pub fn character_table(&self) -> CharacterTable {
+        self.character_table
+    }
+
+    /// Returns the international character set selected with ESC R.
+    pub fn international_charset(&self) -> InternationalCharset {
+        self.international_charset
+    }
+
+    /// Returns the character printed in place of unmappable characters.
+    pub fn fallback_char(&self) -> char {
+        self.fallback_char
+    }
+
     /// Renders the document to an ESC/P byte stream.
     ///
     /// The output includes initialization codes, page content, and form-feeds.
@@ -55,19 +96,368 @@ impl Document {
     /// assert!(!bytes.is_empty());
     /// ```
     pub fn render(&self) -> Vec<u8> {
-        crate::escp::render_document(self)
+        crate::escp::render_document(self, RenderOptions::default())
+    }
+
+    /// Renders the document to an ESC/P byte stream using the given options.
+    ///
+    /// With default options the output is identical to [`Document::render`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, RenderOptions, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "Hello", StyleFlags::NONE);
+    ///
+    /// let mut doc_builder = Document::builder();
+    /// doc_builder.add_page(page_builder.build());
+    /// let document = doc_builder.build();
+    ///
+    /// let bytes = document.render_with(RenderOptions::new().with_compact(true));
+    /// assert!(bytes.ends_with(b"Hello\r\n\x0C"));
+    /// ```
+    pub fn render_with(&self, options: RenderOptions) -> Vec<u8> {
+        crate::escp::render_document(self, options)
+    }
+
+    /// Writes the ESC/P byte stream to `writer`, one page at a time.
+    ///
+    /// The bytes are identical to [`Document::render`], but only one page of
+    /// output is held in memory, so jobs can be piped straight to a file,
+    /// socket or printer device.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "Hello", StyleFlags::NONE);
+    ///
+    /// let mut doc_builder = Document::builder();
+    /// doc_builder.add_page(page_builder.build());
+    /// let document = doc_builder.build();
+    ///
+    /// let mut output = Vec::new();
+    /// document.render_to(&mut output)?;
+    /// assert_eq!(output, document.render());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn render_to<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.render_to_with(writer, RenderOptions::default())
+    }
+
+    /// Writes the ESC/P byte stream to `writer` using the given options, one
+    /// page at a time.
+    ///
+    /// The bytes are identical to [`Document::render_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `writer`.
+    pub fn render_to_with<W: Write>(
+        &self,
+        mut writer: W,
+        options: RenderOptions,
+    ) -> io::Result<()> {
+        crate::escp::write_document(self, options, &mut writer)
+    }
+
+    /// Compares this document with `other`, page by page and cell by cell.
+    ///
+    /// See [`DocumentDiff`] for what is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "Page 1", StyleFlags::NONE);
+    /// let page = page_builder.build();
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.add_page(page.clone());
+    /// let before = builder.build();
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.add_page(page.clone()).add_page(page);
+    /// let after = builder.build();
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.added_pages(), 1..2);
+    /// assert_eq!(diff.to_string(), "page 2: added\n");
+    /// ```
+    pub fn diff(&self, other: &Document) -> DocumentDiff {
+        DocumentDiff::new(self, other)
+    }
+
+    /// Returns the document as plain text, page by page.
+    ///
+    /// Each page is rendered like [`Page::to_plain_text`]; every page after
+    /// the first is preceded by a `--- page N ---` marker line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut doc_builder = Document::builder();
+    /// for text in ["First", "Second"] {
+    ///     let mut page_builder = Page::builder();
+    ///     page_builder.write_str(0, 0, text, StyleFlags::NONE);
+    ///     doc_builder.add_page(page_builder.build());
+    /// }
+    /// let document = doc_builder.build();
+    ///
+    /// assert_eq!(document.to_plain_text(), "First\n--- page 2 ---\nSecond\n");
+    /// ```
+    pub fn to_plain_text(&self) -> String {
+        crate::preview::document_text(self, false)
+    }
+
+    /// Returns the document as text for a terminal, page by page.
+    ///
+    /// Each page is rendered like [`Page::to_ansi`], with the same page-break
+    /// markers as [`Document::to_plain_text`].
+    pub fn to_ansi(&self) -> String {
+        crate::preview::document_text(self, true)
+    }
+
+    /// Returns a self-contained HTML print preview of the document.
+    ///
+    /// Each page is a monospace `<pre>` block sized to the paper, with one
+    /// element per style run (`<b>`, `<i>`, `<u>`, `<s>`). The font size and
+    /// line height follow the document's pitch and line spacing, so the
+    /// preview matches the printer grid. The output is deterministic and
+    /// needs no external resources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "Total", StyleFlags::BOLD);
+    ///
+    /// let mut doc_builder = Document::builder();
+    /// doc_builder.add_page(page_builder.build());
+    /// let html = doc_builder.build().to_html();
+    ///
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// assert!(html.contains("<b>Total</b>"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        crate::preview::document_html(self)
+    }
+
+    /// Returns an SVG print preview of each page.
+    ///
+    /// Every image is sized to the paper covered by the page grid, with one
+    /// `<text>` element per visible style run placed at its cell
+    /// coordinates (in 1/360-inch user units). The output is deterministic
+    /// and needs no external resources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "Total", StyleFlags::BOLD);
+    ///
+    /// let mut doc_builder = Document::builder();
+    /// doc_builder.add_page(page_builder.build());
+    /// let svgs = doc_builder.build().to_svg();
+    ///
+    /// assert_eq!(svgs.len(), 1);
+    /// assert!(svgs[0].contains("font-weight=\"bold\">Total</text>"));
+    /// ```
+    pub fn to_svg(&self) -> Vec<String> {
+        crate::preview::document_svg(self)
+    }
+
+    /// Returns the document as a PDF file, one PDF page per [`Page`].
+    ///
+    /// Text is set in the standard Courier fonts (bold, oblique and bold
+    /// oblique for styled runs), scaled so glyphs sit on the grid given by
+    /// the document's pitch and line spacing. Underline and strikethrough
+    /// are drawn as rules. Each PDF page is sized to the paper covered by
+    /// its page grid.
+    ///
+    /// The PDF has no timestamps or IDs, so the bytes are deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "INVOICE", StyleFlags::BOLD);
+    ///
+    /// let mut doc_builder = Document::builder();
+    /// doc_builder.add_page(page_builder.build());
+    /// let document = doc_builder.build();
+    ///
+    /// let pdf = document.to_pdf();
+    /// assert!(pdf.starts_with(b"%PDF-1.4"));
+    /// assert_eq!(pdf, document.to_pdf());
+    /// ```
+    pub fn to_pdf(&self) -> Vec<u8> {
+        crate::preview::document_pdf(self)
     }
 }
 
 /// Builder for constructing Documents with multiple pages.
 pub struct DocumentBuilder {
     pages: Vec<Page>,
+    pitch: Pitch,
+    line_spacing: LineSpacing,
+    character_table: CharacterTable,
+    international_charset: InternationalCharset,
+    fallback_char: char,
 }
 
 impl DocumentBuilder {
     /// Creates a new DocumentBuilder with no pages.
     fn new() -> Self {
-        DocumentBuilder { pages: Vec::new() }
+        DocumentBuilder {
+            pages: Vec::new(),
+            pitch: Pitch::default(),
+            line_spacing: LineSpacing::default(),
+            character_table: CharacterTable::default(),
+            international_charset: InternationalCharset::default(),
+            fallback_char: '?',
+        }
+    }
+
+    /// Sets the character pitch for the whole document.
+    ///
+    /// Defaults to condensed pica (17.14 cpi). Page geometry is not changed;
+    /// use [`PageGeometry::for_pitch`](crate::PageGeometry::for_pitch) to size
+    /// pages for the chosen pitch. Columns beyond the 13.6-inch print width
+    /// at this pitch are cut when the document is built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, PageGeometry, Pitch};
+    ///
+    /// let geometry = PageGeometry::for_pitch(8 * PageGeometry::INCH, Pitch::Cpi10, 66);
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.pitch(Pitch::Cpi10);
+    /// builder.add_page(Page::builder_with_geometry(geometry).build());
+    /// let document = builder.build();
+    /// assert_eq!(document.pitch(), Pitch::Cpi10);
+    /// ```
+    pub fn pitch(&mut self, pitch: Pitch) -> &mut Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Sets the line spacing for the whole document.
+    ///
+    /// Defaults to 1/6 inch. The page-length command is computed from each
+    /// page's row count at this spacing; use
+    /// [`PageGeometry::for_form`](crate::PageGeometry::for_form) to derive the
+    /// row count from the form length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, LineSpacing, Page, PageGeometry, Pitch};
+    ///
+    /// let geometry = PageGeometry::for_form(
+    ///     3360,
+    ///     11 * PageGeometry::INCH,
+    ///     Pitch::Condensed17,
+    ///     LineSpacing::EighthInch,
+    /// );
+    /// assert_eq!(geometry.rows(), 88);
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.line_spacing(LineSpacing::EighthInch);
+    /// builder.add_page(Page::builder_with_geometry(geometry).build());
+    /// let document = builder.build();
+    /// assert_eq!(document.line_spacing(), LineSpacing::EighthInch);
+    /// ```
+    pub fn line_spacing(&mut self, line_spacing: LineSpacing) -> &mut Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    /// Sets the character table used to print non-ASCII characters.
+    ///
+    /// Defaults to [`CharacterTable::Ascii`], which selects no table and
+    /// prints every non-ASCII character as the fallback character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{CharacterTable, Document};
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.character_table(CharacterTable::Pc858);
+    /// assert_eq!(builder.build().character_table(), CharacterTable::Pc858);
+    /// ```
+    pub fn character_table(&mut self, character_table: CharacterTable) -> &mut Self {
+        self.character_table = character_table;
+        self
+    }
+
+    /// Sets the international character set for the whole document.
+    ///
+    /// Defaults to [`InternationalCharset::Usa`]. National characters in the
+    /// set take precedence over the character table; the ASCII characters
+    /// they displace print as the fallback character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, InternationalCharset};
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.international_charset(InternationalCharset::UnitedKingdom);
+    /// let document = builder.build();
+    /// assert_eq!(document.international_charset(), InternationalCharset::UnitedKingdom);
+    /// ```
+    pub fn international_charset(
+        &mut self,
+        international_charset: InternationalCharset,
+    ) -> &mut Self {
+        self.international_charset = international_charset;
+        self
+    }
+
+    /// Sets the character printed in place of characters that neither the
+    /// character table nor the international set can print.
+    ///
+    /// Defaults to '?'. A fallback that is itself unmappable prints as '?'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, Page, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "€5", StyleFlags::NONE);
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.fallback_char('*');
+    /// builder.add_page(page_builder.build());
+    /// let bytes = builder.build().render();
+    /// assert!(bytes.windows(2).any(|w| w == b"*5"));
+    /// ```
+    pub fn fallback_char(&mut self, fallback_char: char) -> &mut Self {
+        self.fallback_char = fallback_char;
+        self
     }
 
     /// Adds a page to the document.
@@ -91,6 +481,12 @@ impl DocumentBuilder {
 
     /// Consumes the builder and returns an immutable Document.
     ///
+    /// The printer's longest form is 22 inches and its widest line 13.6
+    /// inches. Pages with more rows than fit in 22 inches at the document's
+    /// line spacing, or more columns than fit in 13.6 inches at its pitch,
+    /// are cut to the rows and columns that fit, so every page prints on
+    /// exactly one form and no line runs past the right margin.
+    ///
     /// # Examples
     ///
     /// ```
@@ -103,13 +499,33 @@ impl DocumentBuilder {
     /// let document = builder.build();
     /// ```
     pub fn build(self) -> Document {
-        Document { pages: self.pages }
+        let max_columns = self.pitch.columns(MAX_PRINT_WIDTH);
+        // Zero line spacing prints every row on the same line
+        let max_rows = match self.line_spacing.rows(MAX_FORM_LENGTH) {
+            0 => u16::MAX,
+            rows => rows,
+        };
+        let pages = self
+            .pages
+            .into_iter()
+            .map(|page| page.truncate(max_columns, max_rows))
+            .collect();
+        Document {
+            pages,
+            pitch: self.pitch,
+            line_spacing: self.line_spacing,
+            character_table: self.character_table,
+            international_charset: self.international_charset,
+            fallback_char: self.fallback_char,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cell::StyleFlags;
+    use crate::geometry::PageGeometry;
 
     #[test]
     fn test_document_builder_new() {
@@ -162,6 +578,88 @@ mod tests {
         assert_eq!(document.page_count(), 3);
     }
 
+    #[test]
+    fn test_document_pitch() {
+        let document = Document::builder().build();
+        assert_eq!(document.pitch(), Pitch::Condensed17);
+
+        let mut builder = Document::builder();
+        builder.pitch(Pitch::Cpi12);
+        assert_eq!(builder.build().pitch(), Pitch::Cpi12);
+    }
+
+    #[test]
+    fn test_document_line_spacing() {
+        let document = Document::builder().build();
+        assert_eq!(document.line_spacing(), LineSpacing::SixthInch);
+
+        let mut builder = Document::builder();
+        builder.line_spacing(LineSpacing::Units360(40));
+        assert_eq!(builder.build().line_spacing(), LineSpacing::Units360(40));
+    }
+
+    #[test]
+    fn test_document_charset() {
+        let document = Document::builder().build();
+        assert_eq!(document.character_table(), CharacterTable::Ascii);
+        assert_eq!(document.international_charset(), InternationalCharset::Usa);
+        assert_eq!(document.fallback_char(), '?');
+
+        let mut builder = Document::builder();
+        builder
+            .character_table(CharacterTable::Iso8859_15)
+            .international_charset(InternationalCharset::Germany)
+            .fallback_char('_');
+        let document = builder.build();
+        assert_eq!(document.character_table(), CharacterTable::Iso8859_15);
+        assert_eq!(
+            document.international_charset(),
+            InternationalCharset::Germany
+        );
+        assert_eq!(document.fallback_char(), '_');
+    }
+
+    #[test]
+    fn test_document_cuts_pages_to_22_inches() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(80, 200));
+        page_builder.write_str(0, 131, "last", StyleFlags::NONE);
+        page_builder.write_str(0, 132, "cut", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // 132 rows at 1/6" = 22 inches
+        let mut builder = Document::builder();
+        builder.add_page(page.clone());
+        let document = builder.build();
+        assert_eq!(document.pages()[0].geometry().rows(), 132);
+        assert_eq!(
+            document.pages()[0].to_plain_text().lines().last(),
+            Some("last")
+        );
+
+        // 176 rows at 1/8" = 22 inches
+        let mut builder = Document::builder();
+        builder.line_spacing(LineSpacing::EighthInch).add_page(page);
+        assert_eq!(builder.build().pages()[0].geometry().rows(), 176);
+    }
+
+    #[test]
+    fn test_document_cuts_pages_to_print_width() {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(130, 0, "0123456789", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // 13.6 inches hold 136 columns at pica; 160 at condensed pica
+        let mut builder = Document::builder();
+        builder.pitch(Pitch::Cpi10).add_page(page.clone());
+        let document = builder.build();
+        assert_eq!(document.pages()[0].geometry(), PageGeometry::new(136, 51));
+        assert_eq!(document.pages()[0].to_plain_text().trim(), "012345");
+
+        let mut builder = Document::builder();
+        builder.add_page(page.clone());
+        assert_eq!(builder.build().pages()[0], page);
+    }
+
     #[test]
     fn test_document_empty() {
         let document = Document::builder().build();
@@ -3,21 +3,102 @@
 /// ESC @ - Printer reset and initialization
 pub const ESC_RESET: &[u8] = &[0x1B, 0x40];
 
-/// SI (Shift In) - Condensed mode (12 CPI)
+/// SI (Shift In) - Condensed mode (17.14 CPI from pica, 20 CPI from elite)
 pub const SI_CONDENSED: &[u8] = &[0x0F];
 
+/// ESC P - Select 10 CPI (pica)
+pub const ESC_PICA: &[u8] = &[0x1B, 0x50];
+
+/// ESC M - Select 12 CPI (elite)
+pub const ESC_ELITE: &[u8] = &[0x1B, 0x4D];
+
+/// ESC g - Select 15 CPI
+pub const ESC_15_CPI: &[u8] = &[0x1B, 0x67];
+
+/// ESC M SI - Select 20 CPI (condensed elite)
+pub const ESC_ELITE_CONDENSED: &[u8] = &[0x1B, 0x4D, 0x0F];
+
 /// ESC E - Bold on
 pub const ESC_BOLD_ON: &[u8] = &[0x1B, 0x45];
 
 /// ESC F - Bold off
 pub const ESC_BOLD_OFF: &[u8] = &[0x1B, 0x46];
 
+/// ESC 0 - Select 1/8-inch line spacing
+pub const ESC_LINE_SPACING_EIGHTH: &[u8] = &[0x1B, 0x30];
+
+/// ESC 3 n - Set n/180-inch line spacing, followed by n
+pub const ESC_LINE_SPACING_180: &[u8] = &[0x1B, 0x33];
+
+/// ESC + n - Set n/360-inch line spacing, followed by n
+pub const ESC_LINE_SPACING_360: &[u8] = &[0x1B, 0x2B];
+
+/// ESC ( t 3 0 1 d2 d3 - Assign character table to slot 1, followed by d2 d3
+pub const ESC_ASSIGN_TABLE_SLOT_1: &[u8] = &[0x1B, 0x28, 0x74, 0x03, 0x00, 0x01];
+
+/// ESC t 1 - Select character table in slot 1
+pub const ESC_SELECT_TABLE_SLOT_1: &[u8] = &[0x1B, 0x74, 0x01];
+
+/// ESC R n - Select international character set, followed by n
+pub const ESC_INTERNATIONAL_CHARSET: &[u8] = &[0x1B, 0x52];
+
 /// ESC - 1 - Underline on
 pub const ESC_UNDERLINE_ON: &[u8] = &[0x1B, 0x2D, 0x01];
 
 /// ESC - 0 - Underline off
 pub const ESC_UNDERLINE_OFF: &[u8] = &[0x1B, 0x2D, 0x00];
 
+/// ESC 4 - Italic on
+pub const ESC_ITALIC_ON: &[u8] = &[0x1B, 0x34];
+
+/// ESC 5 - Italic off
+pub const ESC_ITALIC_OFF: &[u8] = &[0x1B, 0x35];
+
+/// ESC G - Double-strike on
+pub const ESC_DOUBLE_STRIKE_ON: &[u8] = &[0x1B, 0x47];
+
+/// ESC H - Double-strike off
+pub const ESC_DOUBLE_STRIKE_OFF: &[u8] = &[0x1B, 0x48];
+
+/// ESC S 0 - Superscript on
+pub const ESC_SUPERSCRIPT_ON: &[u8] = &[0x1B, 0x53, 0x00];
+
+/// ESC S 1 - Subscript on
+pub const ESC_SUBSCRIPT_ON: &[u8] = &[0x1B, 0x53, 0x01];
+
+/// ESC T - Superscript/subscript off
+pub const ESC_SCRIPT_OFF: &[u8] = &[0x1B, 0x54];
+
+/// ESC ( - 3 0 1 2 1 - Strikethrough (single continuous score) on
+pub const ESC_STRIKETHROUGH_ON: &[u8] = &[0x1B, 0x28, 0x2D, 0x03, 0x00, 0x01, 0x02, 0x01];
+
+/// ESC ( - 3 0 1 2 0 - Strikethrough off
+pub const ESC_STRIKETHROUGH_OFF: &[u8] = &[0x1B, 0x28, 0x2D, 0x03, 0x00, 0x01, 0x02, 0x00];
+
+/// ESC W 1 - Double-width on
+pub const ESC_DOUBLE_WIDTH_ON: &[u8] = &[0x1B, 0x57, 0x01];
+
+/// ESC W 0 - Double-width off
+pub const ESC_DOUBLE_WIDTH_OFF: &[u8] = &[0x1B, 0x57, 0x00];
+
+/// ESC w 1 - Double-height on
+pub const ESC_DOUBLE_HEIGHT_ON: &[u8] = &[0x1B, 0x77, 0x01];
+
+/// ESC w 0 - Double-height off
+pub const ESC_DOUBLE_HEIGHT_OFF: &[u8] = &[0x1B, 0x77, 0x00];
+
+/// ESC J n - Advance paper n/180 inch, followed by n
+pub const ESC_FEED_180: &[u8] = &[0x1B, 0x4A];
+
+/// ESC ( U 1 0 10 - Set the unit for ESC $ and ESC \ to 10/3600 (1/360) inch
+pub const ESC_UNIT_360: &[u8] = &[0x1B, 0x28, 0x55, 0x01, 0x00, 0x0A];
+
+/// ESC $ nL nH - Set absolute horizontal print position, followed by nL nH
+pub const ESC_ABSOLUTE_POSITION: &[u8] = &[0x1B, 0x24];
+
+/// ESC \ nL nH - Set relative horizontal print position, followed by nL nH
+pub const ESC_RELATIVE_POSITION: &[u8] = &[0x1B, 0x5C];
+
 /// CR - Carriage return
 pub const CR: u8 = 0x0D;
 
@@ -27,6 +108,23 @@ pub const LF: u8 = 0x0A;
 /// FF - Form feed (page separator)
 pub const FF: u8 = 0x0C;
 
-/// ESC C n - Set page length to n lines (1-127)
-/// For EPSON LQ-2090II: 50 lines per page
-pub const ESC_PAGE_LENGTH_50: &[u8] = &[0x1B, 0x43, 50];
+/// ESC C n - Set page length to n lines (1-127), followed by the line count byte
+pub const ESC_PAGE_LENGTH: &[u8] = &[0x1B, 0x43];
+
+/// ESC C 0 n - Set page length to n inches (1-22), followed by the inch count byte
+pub const ESC_PAGE_LENGTH_INCHES: &[u8] = &[0x1B, 0x43, 0x00];
+
+/// Maximum line count accepted by ESC C n
+pub const MAX_PAGE_LENGTH_LINES: u16 = 127;
+
+/// Maximum inch count accepted by ESC C 0 n
+pub const MAX_PAGE_LENGTH_INCHES: u32 = 22;
+
+/// Units per inch used for line spacing and page length calculations
+pub const UNITS_PER_INCH: u32 = 360;
+
+/// Widest printable line of the LQ-2090II (13.6 inches), in 1/360 inch
+pub const MAX_PRINT_WIDTH: u32 = 13 * UNITS_PER_INCH + 216;
+
+/// Longest form the LQ-2090II accepts (22 inches), in 1/360 inch
+pub const MAX_FORM_LENGTH: u32 = MAX_PAGE_LENGTH_INCHES * UNITS_PER_INCH;
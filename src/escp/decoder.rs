@@ -0,0 +1,379 @@
+//! ESC/P decoder parsing byte streams back into documents.
+
+use super::constants::{MAX_FORM_LENGTH, MAX_PRINT_WIDTH};
+use super::interpreter::{interpret, PrintedGlyph, UnknownSequence};
+use crate::{Document, Page, PageGeometry, StyleFlags};
+
+/// Parses ESC/P byte streams back into a [`Document`] of cell grids.
+///
+/// The decoder understands every command the renderer emits (reset, pitch,
+/// line spacing, page length, styles, character tables, horizontal
+/// positioning and paper feeds) as well as common legacy commands such as
+/// `ESC !`, HT, BS and SO. Anything else is skipped and reported as an
+/// [`UnknownSequence`].
+///
+/// The number of rows per page follows from the page length (`ESC C`) and
+/// the number of columns from the widest printed line; both can be fixed
+/// with [`with_rows`](Self::with_rows) and
+/// [`with_columns`](Self::with_columns), e.g. for compact output whose rows
+/// omit trailing blanks. Inferred pages never exceed the printer's physical
+/// limits of 13.6 inches by 22 inches: glyphs positioned beyond them are
+/// reported as unknown sequences instead.
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{Decoder, Document, Page, StyleFlags};
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.write_str(0, 0, "INVOICE", StyleFlags::BOLD);
+///
+/// let mut builder = Document::builder();
+/// builder.add_page(page_builder.build());
+/// let document = builder.build();
+///
+/// let decoded = Decoder::new().decode(&document.render());
+/// assert!(decoded.unknown_sequences().is_empty());
+/// assert_eq!(decoded.document(), &document);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Decoder {
+    columns: Option<u16>,
+    rows: Option<u16>,
+}
+
+impl Decoder {
+    /// Creates a decoder inferring the page geometry from the byte stream
+    pub const fn new() -> Self {
+        Decoder {
+            columns: None,
+            rows: None,
+        }
+    }
+
+    /// Returns a decoder producing pages with the given number of columns
+    pub const fn with_columns(self, columns: u16) -> Self {
+        Decoder {
+            columns: Some(columns),
+            ..self
+        }
+    }
+
+    /// Returns a decoder producing pages with the given number of rows,
+    /// regardless of the page length set in the byte stream
+    pub const fn with_rows(self, rows: u16) -> Self {
+        Decoder {
+            rows: Some(rows),
+            ..self
+        }
+    }
+
+    /// Decodes an ESC/P byte stream.
+    ///
+    /// Decoding never fails: unknown or truncated sequences are skipped and
+    /// reported in [`Decoded::unknown_sequences`]. Each FF ends a page; data
+    /// after the last FF forms a final page if it prints anything.
+    pub fn decode(&self, bytes: &[u8]) -> Decoded {
+        let interpretation = interpret(bytes);
+
+        // Glyphs the printer could not have put on paper are reported
+        // instead of growing the grid beyond the printer's limits
+        let mut unknown_sequences = interpretation.unknown_sequences;
+        unknown_sequences.extend(
+            interpretation
+                .pages
+                .iter()
+                .flat_map(|page| &page.glyphs)
+                .filter(|glyph| !on_paper(glyph))
+                .map(|glyph| UnknownSequence::new(glyph.offset, &bytes[glyph.offset..][..1])),
+        );
+        unknown_sequences.sort_by_key(UnknownSequence::offset);
+
+        let columns = self.columns.unwrap_or_else(|| {
+            interpretation
+                .pages
+                .iter()
+                .flat_map(|page| &page.glyphs)
+                .filter(|glyph| on_paper(glyph))
+                .filter_map(|glyph| {
+                    let (x, _) = cell_position(glyph)?;
+                    Some(x.saturating_add(glyph.style.glyph_width()))
+                })
+                .max()
+                .unwrap_or(PageGeometry::default().columns())
+        });
+
+        let mut builder = Document::builder();
+        builder
+            .pitch(interpretation.pitch)
+            .line_spacing(interpretation.line_spacing)
+            .character_table(interpretation.character_table)
+            .international_charset(interpretation.international_charset);
+
+        for page in &interpretation.pages {
+            let rows = self.rows.unwrap_or_else(|| {
+                page.page_length
+                    .map_or(PageGeometry::default().rows(), |length| {
+                        let max_rows = MAX_FORM_LENGTH
+                            .checked_div(page.line_spacing)
+                            .unwrap_or(0)
+                            .try_into()
+                            .unwrap_or(u16::MAX);
+                        length.rows(page.line_spacing).min(max_rows)
+                    })
+            });
+            let mut page_builder = Page::builder_with_geometry(PageGeometry::new(columns, rows));
+            for glyph in page.glyphs.iter().filter(|glyph| on_paper(glyph)) {
+                // Plain spaces put no ink on the paper
+                if glyph.character == ' ' && glyph.style == StyleFlags::NONE {
+                    continue;
+                }
+                if let Some((x, y)) = cell_position(glyph) {
+                    page_builder.write_at(x, y, glyph.character, glyph.style);
+                }
+            }
+            builder.add_page(page_builder.build());
+        }
+
+        Decoded {
+            document: builder.build(),
+            unknown_sequences,
+        }
+    }
+}
+
+/// Returns true if the glyph lies within the printable width (13.6 inches)
+/// and the longest form (22 inches) of the printer.
+fn on_paper(glyph: &PrintedGlyph) -> bool {
+    let right =
+        glyph.position as u64 + (glyph.char_width * glyph.style.glyph_width() as u32) as u64;
+    let bottom =
+        glyph.vertical as u64 + (glyph.line_spacing * glyph.style.glyph_height() as u32) as u64;
+    right <= MAX_PRINT_WIDTH as u64 && bottom <= MAX_FORM_LENGTH as u64
+}
+
+/// Returns the cell a glyph was printed in, at the pitch and line spacing
+/// in effect when it was printed.
+fn cell_position(glyph: &PrintedGlyph) -> Option<(u16, u16)> {
+    let x = u16::try_from(glyph.position / glyph.char_width).ok()?;
+    let row = glyph.vertical.checked_div(glyph.line_spacing).unwrap_or(0);
+    let y = u16::try_from(row).ok()?;
+    Some((x, y))
+}
+
+/// Result of decoding an ESC/P byte stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    document: Document,
+    unknown_sequences: Vec<UnknownSequence>,
+}
+
+impl Decoded {
+    /// Returns the decoded document
+    pub fn document(&self) -> &Document {
+        &self.document
+    }
+
+    /// Consumes the result and returns the decoded document
+    pub fn into_document(self) -> Document {
+        self.document
+    }
+
+    /// Returns the sequences the decoder skipped, in stream order
+    pub fn unknown_sequences(&self) -> &[UnknownSequence] {
+        &self.unknown_sequences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::escp::{HorizontalSkip, RenderOptions};
+    use crate::{Cell, CharacterTable, InternationalCharset, LineSpacing, Pitch};
+
+    fn single_page(build: impl FnOnce(&mut crate::PageBuilder)) -> Document {
+        let mut page_builder = Page::builder();
+        build(&mut page_builder);
+        let mut builder = Document::builder();
+        builder.add_page(page_builder.build());
+        builder.build()
+    }
+
+    #[test]
+    fn test_decode_empty_stream() {
+        let decoded = Decoder::new().decode(&[]);
+        assert_eq!(decoded.document().page_count(), 0);
+        assert!(decoded.unknown_sequences().is_empty());
+    }
+
+    #[test]
+    fn test_round_trip_styles() {
+        let document = single_page(|page| {
+            page.write_str(0, 0, "Plain", StyleFlags::NONE);
+            page.write_str(10, 1, "Bold", StyleFlags::BOLD);
+            page.write_str(0, 2, "Under lined", StyleFlags::UNDERLINE);
+            page.write_str(20, 3, "Italic", StyleFlags::ITALIC.with_double_strike());
+            page.write_str(0, 4, "Struck", StyleFlags::STRIKETHROUGH);
+            page.write_str(0, 5, "x2", StyleFlags::SUPERSCRIPT);
+            page.write_str(5, 5, "H2O", StyleFlags::SUBSCRIPT);
+            page.write_str(0, 6, "WIDE", StyleFlags::DOUBLE_WIDTH);
+            page.write_str(0, 8, "TALL", StyleFlags::DOUBLE_HEIGHT.with_bold());
+        });
+
+        let decoded = Decoder::new().decode(&document.render());
+        assert!(decoded.unknown_sequences().is_empty());
+        assert_eq!(decoded.document(), &document);
+    }
+
+    #[test]
+    fn test_round_trip_settings() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(96, 88));
+        page_builder.write_str(0, 0, "Grüße € 1/2", StyleFlags::NONE);
+        page_builder.write_str(0, 87, "last row", StyleFlags::NONE);
+
+        let mut builder = Document::builder();
+        builder
+            .pitch(Pitch::Cpi12)
+            .line_spacing(LineSpacing::EighthInch)
+            .character_table(CharacterTable::Pc858)
+            .international_charset(InternationalCharset::Germany)
+            .add_page(page_builder.build());
+        let document = builder.build();
+
+        let decoded = Decoder::new().decode(&document.render());
+        assert!(decoded.unknown_sequences().is_empty());
+        assert_eq!(decoded.document(), &document);
+    }
+
+    #[test]
+    fn test_round_trip_compact_with_columns_hint() {
+        let document = single_page(|page| {
+            page.write_str(0, 0, "Left", StyleFlags::NONE);
+            page.write_str(100, 0, "Right", StyleFlags::BOLD);
+            page.write_str(40, 30, "Middle", StyleFlags::NONE);
+        });
+        let options = RenderOptions::new()
+            .with_compact(true)
+            .with_horizontal_skip(HorizontalSkip::Relative);
+
+        let decoded = Decoder::new()
+            .with_columns(160)
+            .decode(&document.render_with(options));
+        assert!(decoded.unknown_sequences().is_empty());
+        assert_eq!(decoded.document(), &document);
+    }
+
+    #[test]
+    fn test_columns_inferred_from_widest_line() {
+        let decoded = Decoder::new().decode(b"\x1b@AB\r\nABCD\r\n\x0c");
+        let page = &decoded.document().pages()[0];
+        assert_eq!(page.geometry().columns(), 4);
+        assert_eq!(page.geometry().rows(), PageGeometry::default().rows());
+    }
+
+    #[test]
+    fn test_rows_hint_overrides_page_length() {
+        let decoded = Decoder::new()
+            .with_rows(10)
+            .decode(b"\x1b@\x1bC\x42text\x0c");
+        assert_eq!(decoded.document().pages()[0].geometry().rows(), 10);
+    }
+
+    #[test]
+    fn test_trailing_data_forms_last_page() {
+        let decoded = Decoder::new().decode(b"one\x0ctwo");
+        assert_eq!(decoded.document().page_count(), 2);
+        assert_eq!(
+            decoded.document().pages()[1]
+                .get_cell(0, 0)
+                .unwrap()
+                .character(),
+            't'
+        );
+    }
+
+    #[test]
+    fn test_legacy_commands() {
+        // ESC ! bold + double width, HT, SO one-line double width, BS
+        let decoded = Decoder::new()
+            .with_columns(40)
+            .decode(b"\x1b!\x28A\x1b!\x00\tB\x0eC\r\nDE\x08F\x0c");
+        let page = &decoded.document().pages()[0];
+        assert!(decoded.unknown_sequences().is_empty());
+
+        let a = page.get_cell(0, 0).unwrap();
+        assert_eq!(a.character(), 'A');
+        assert_eq!(a.style(), StyleFlags::BOLD.with_double_width());
+        assert_eq!(page.get_cell(8, 0).unwrap().character(), 'B');
+        assert!(page.get_cell(9, 0).unwrap().style().double_width());
+        // SO is cancelled by the line feed
+        assert_eq!(page.get_cell(0, 1).unwrap().style(), StyleFlags::NONE);
+        // F overprints E after the backspace
+        assert_eq!(page.get_cell(1, 1).unwrap().character(), 'F');
+    }
+
+    #[test]
+    fn test_unknown_sequences_reported() {
+        let decoded = Decoder::new()
+            .decode(b"\x1bl\x05A\x1b~B\x1b*\x00\x02\x00\xff\xffC\x1b(Z\x01\x00\x00D\x1b(");
+        let offsets: Vec<usize> = decoded
+            .unknown_sequences()
+            .iter()
+            .map(UnknownSequence::offset)
+            .collect();
+        assert_eq!(offsets, [0, 4, 7, 15, 22]);
+        assert_eq!(decoded.unknown_sequences()[0].bytes(), b"\x1bl\x05");
+        assert_eq!(
+            decoded.unknown_sequences()[2].bytes(),
+            b"\x1b*\x00\x02\x00\xff\xff"
+        );
+        assert_eq!(decoded.unknown_sequences()[4].bytes(), b"\x1b(");
+
+        let page = &decoded.document().pages()[0];
+        let text: String = page.row(0).unwrap().iter().map(Cell::character).collect();
+        assert_eq!(text, "ABCD");
+    }
+
+    #[test]
+    fn test_relative_motion_units() {
+        // Default ESC \ unit is 1/180 inch: 36 units = 0.2 inch = 2 columns at 10 cpi
+        let decoded = Decoder::new()
+            .with_columns(10)
+            .decode(b"\x1b@\x1bPA\x1b\\\x24\x00B\x0c");
+        let page = &decoded.document().pages()[0];
+        assert_eq!(page.get_cell(3, 0).unwrap().character(), 'B');
+    }
+
+    #[test]
+    fn test_geometry_capped_at_printer_limits() {
+        // 1/360" lines, a 22-inch form, condensed and a far-right position
+        let decoded = Decoder::new().decode(b"\x1b+\x01\x1bC\x00\x16\x0f\x1b$\xff\xffX\x0c");
+        let geometry = decoded.document().pages()[0].geometry();
+        assert!(geometry.columns() as u32 * 21 <= MAX_PRINT_WIDTH);
+        assert_eq!(geometry.rows() as u32, MAX_FORM_LENGTH);
+
+        let glyph = decoded.unknown_sequences().last().unwrap();
+        assert_eq!(glyph.offset(), 12);
+        assert_eq!(glyph.bytes(), b"X");
+
+        // 127 lines set at 1/6", then printed at 1/3": 42 inches
+        let decoded = Decoder::new().decode(b"\x1bC\x7f\x1b+\x78X\x0c");
+        let geometry = decoded.document().pages()[0].geometry();
+        assert_eq!(geometry.rows() as u32, MAX_FORM_LENGTH / 120);
+    }
+
+    #[test]
+    fn test_glyphs_below_longest_form_reported() {
+        // 23 inches of feed at 180 units per ESC J
+        let mut bytes = b"\x1b@A".to_vec();
+        for _ in 0..46 {
+            bytes.extend_from_slice(b"\x1bJ\xb4");
+        }
+        bytes.extend_from_slice(b"B\x0c");
+        let decoded = Decoder::new().decode(&bytes);
+
+        assert_eq!(decoded.unknown_sequences().len(), 1);
+        assert_eq!(decoded.unknown_sequences()[0].bytes(), b"B");
+        assert_eq!(decoded.document().pages()[0].geometry().rows(), 51);
+    }
+}
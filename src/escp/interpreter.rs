@@ -0,0 +1,631 @@
+//! ESC/P interpreter replaying byte streams on a model of the printer.
+//!
+//! Shared by the [`Decoder`](super::Decoder), which maps printed glyphs back
+//! to cells, and the [`VirtualPrinter`](super::VirtualPrinter), which
+//! rasterizes them at their physical positions.
+
+use super::constants::*;
+use super::renderer::PageLength;
+use crate::charset::Encoder;
+use crate::{CharacterTable, InternationalCharset, LineSpacing, Pitch, StyleFlags};
+use std::fmt;
+
+/// Byte sequence the decoder or virtual printer could not interpret.
+///
+/// Covers unknown commands, commands with no equivalent in the cell grid
+/// (margins, graphics, typeface selection), invalid parameters, truncated
+/// sequences at the end of the stream and bytes with no character in the
+/// active character table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownSequence {
+    offset: usize,
+    bytes: Vec<u8>,
+}
+
+impl UnknownSequence {
+    /// Creates the record of `bytes` found at `offset`
+    pub(crate) fn new(offset: usize, bytes: &[u8]) -> Self {
+        UnknownSequence {
+            offset,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    /// Returns the byte offset of the sequence in the stream
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the bytes of the sequence
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Display for UnknownSequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown sequence at byte {}:", self.offset)?;
+        for byte in &self.bytes {
+            write!(f, " {:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// ESC byte introducing commands
+const ESC: u8 = 0x1B;
+
+/// Default tab stops are every 8 characters
+const TAB_WIDTH: u32 = 8;
+
+/// Glyph placed on the paper.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PrintedGlyph {
+    /// Offset of the printed byte in the stream
+    pub(crate) offset: usize,
+    /// Horizontal position from the left margin in 1/360 inch
+    pub(crate) position: u32,
+    /// Top of the print head from top-of-form in 1/360 inch
+    pub(crate) vertical: u32,
+    /// Column width of the pitch in effect, in 1/360 inch
+    pub(crate) char_width: u32,
+    /// Line spacing in effect, in 1/360 inch
+    pub(crate) line_spacing: u32,
+    pub(crate) character: char,
+    /// Style in effect, including SO double width
+    pub(crate) style: StyleFlags,
+}
+
+/// Page ejected by a form feed (or left at the end of the stream).
+#[derive(Clone, Debug)]
+pub(crate) struct PrintedPage {
+    /// Page length set with ESC C, if any
+    pub(crate) page_length: Option<PageLength>,
+    /// Page length in 1/360 inch, at the line spacing in effect for ESC C
+    pub(crate) form_length: Option<u32>,
+    /// Line spacing in effect at the form feed, in 1/360 inch
+    pub(crate) line_spacing: u32,
+    pub(crate) glyphs: Vec<PrintedGlyph>,
+}
+
+/// Result of replaying a byte stream.
+#[derive(Clone, Debug)]
+pub(crate) struct Interpretation {
+    pub(crate) pages: Vec<PrintedPage>,
+    pub(crate) unknown_sequences: Vec<UnknownSequence>,
+    /// Settings in effect at the end of the stream
+    pub(crate) pitch: Pitch,
+    pub(crate) line_spacing: LineSpacing,
+    /// Table selected with ESC ( t and ESC t (ASCII if none)
+    pub(crate) character_table: CharacterTable,
+    pub(crate) international_charset: InternationalCharset,
+}
+
+/// Replays an ESC/P byte stream on a model of the printer.
+///
+/// Unknown or truncated sequences are skipped and reported. Each FF ejects
+/// a page; data after the last FF forms a final page if it prints anything.
+pub(crate) fn interpret(bytes: &[u8]) -> Interpretation {
+    let mut interpreter = Interpreter::new(bytes);
+    interpreter.run();
+    interpreter.finish()
+}
+
+/// Pitch selected by ESC P, ESC M or ESC g (before SI)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BasePitch {
+    Pica,
+    Elite,
+    Cpi15,
+}
+
+/// Printer state replayed from a byte stream.
+struct Interpreter<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    unknown: Vec<UnknownSequence>,
+    pages: Vec<PrintedPage>,
+    glyphs: Vec<PrintedGlyph>,
+    /// Horizontal position from the left margin in 1/360 inch
+    position: u32,
+    /// Vertical position from top-of-form in 1/360 inch
+    vertical: u32,
+    base_pitch: BasePitch,
+    condensed: bool,
+    line_spacing: LineSpacing,
+    page_length: Option<PageLength>,
+    /// Page length in 1/360 inch
+    form_length: Option<u32>,
+    /// ESC ( U unit in 1/3600 inch, if set
+    motion_unit: Option<u32>,
+    style: StyleFlags,
+    /// SO one-line double width
+    one_line_wide: bool,
+    /// Tables assigned to slots 0-3 with ESC ( t
+    table_slots: [Option<CharacterTable>; 4],
+    selected_slot: usize,
+    international: InternationalCharset,
+    encoder: Encoder,
+}
+
+impl<'a> Interpreter<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        let mut state = Interpreter {
+            bytes,
+            offset: 0,
+            unknown: Vec::new(),
+            pages: Vec::new(),
+            glyphs: Vec::new(),
+            position: 0,
+            vertical: 0,
+            base_pitch: BasePitch::Pica,
+            condensed: false,
+            line_spacing: LineSpacing::SixthInch,
+            page_length: None,
+            form_length: None,
+            motion_unit: None,
+            style: StyleFlags::NONE,
+            one_line_wide: false,
+            table_slots: [None; 4],
+            selected_slot: 1,
+            international: InternationalCharset::Usa,
+            encoder: Encoder::default(),
+        };
+        state.reset();
+        state
+    }
+
+    /// Applies ESC @ (everything except the paper position)
+    fn reset(&mut self) {
+        self.base_pitch = BasePitch::Pica;
+        self.condensed = false;
+        self.line_spacing = LineSpacing::SixthInch;
+        self.page_length = None;
+        self.form_length = None;
+        self.motion_unit = None;
+        self.style = StyleFlags::NONE;
+        self.one_line_wide = false;
+        self.table_slots = [None; 4];
+        self.selected_slot = 1;
+        self.international = InternationalCharset::Usa;
+        self.update_encoder();
+    }
+
+    /// Returns the table selected with ESC ( t and ESC t, if any
+    fn character_table(&self) -> Option<CharacterTable> {
+        self.table_slots[self.selected_slot]
+    }
+
+    /// Rebuilds the byte-to-character mapping after a table change.
+    ///
+    /// Without a table selection the printer prints its factory-default
+    /// graphics table, PC437.
+    fn update_encoder(&mut self) {
+        let table = self.character_table().unwrap_or(CharacterTable::Pc437);
+        self.encoder = Encoder::new(table, self.international, '?');
+    }
+
+    fn pitch(&self) -> Pitch {
+        match (self.base_pitch, self.condensed) {
+            (BasePitch::Pica, false) => Pitch::Cpi10,
+            (BasePitch::Elite, false) => Pitch::Cpi12,
+            (BasePitch::Cpi15, _) => Pitch::Cpi15,
+            (BasePitch::Pica, true) => Pitch::Condensed17,
+            (BasePitch::Elite, true) => Pitch::Condensed20,
+        }
+    }
+
+    fn run(&mut self) {
+        while let Some(&byte) = self.bytes.get(self.offset) {
+            let start = self.offset;
+            self.offset += 1;
+            match byte {
+                ESC => self.escape(start),
+                CR => self.position = 0,
+                LF => {
+                    self.vertical = self.vertical.saturating_add(self.line_spacing.units());
+                    self.one_line_wide = false;
+                }
+                FF => self.form_feed(),
+                0x0F => self.condensed = true,      // SI
+                0x12 => self.condensed = false,     // DC2
+                0x0E => self.one_line_wide = true,  // SO
+                0x14 => self.one_line_wide = false, // DC4
+                0x08 => {
+                    // BS
+                    let width = self.pitch().char_width();
+                    self.position = self.position.saturating_sub(width);
+                }
+                0x09 => {
+                    // HT
+                    let tab = TAB_WIDTH * self.pitch().char_width();
+                    self.position = (self.position / tab + 1) * tab;
+                }
+                0x00 | 0x07 | 0x7F => {} // NUL, BEL, DEL
+                _ => self.print(byte, start),
+            }
+        }
+    }
+
+    /// Records a printed byte and advances the print position.
+    fn print(&mut self, byte: u8, start: usize) {
+        let Some(character) = self.encoder.decode(byte) else {
+            self.report(start);
+            return;
+        };
+
+        let style = self.style.set(
+            StyleFlags::DOUBLE_WIDTH,
+            self.style.double_width() || self.one_line_wide,
+        );
+        let width = self.pitch().char_width();
+        self.glyphs.push(PrintedGlyph {
+            offset: start,
+            position: self.position,
+            vertical: self.vertical,
+            char_width: width,
+            line_spacing: self.line_spacing.units(),
+            character,
+            style,
+        });
+        self.position += width * style.glyph_width() as u32;
+    }
+
+    /// Ejects the current page and moves to top-of-form.
+    fn form_feed(&mut self) {
+        self.pages.push(PrintedPage {
+            page_length: self.page_length,
+            form_length: self.form_length,
+            line_spacing: self.line_spacing.units(),
+            glyphs: std::mem::take(&mut self.glyphs),
+        });
+        self.position = 0;
+        self.vertical = 0;
+    }
+
+    /// Takes `count` parameter bytes, or None if the stream ends first.
+    fn take(&mut self, count: usize) -> Option<&'a [u8]> {
+        let bytes = self.bytes.get(self.offset..self.offset + count)?;
+        self.offset += count;
+        Some(bytes)
+    }
+
+    /// Takes a single parameter byte.
+    fn take_byte(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    /// Records the bytes from `start` to the current offset as unknown.
+    fn report(&mut self, start: usize) {
+        self.unknown.push(UnknownSequence {
+            offset: start,
+            bytes: self.bytes[start..self.offset].to_vec(),
+        });
+    }
+
+    /// Records a truncated sequence running to the end of the stream.
+    fn report_truncated(&mut self, start: usize) {
+        self.offset = self.bytes.len();
+        self.report(start);
+    }
+
+    /// Decodes an ESC command whose ESC byte is at `start`.
+    fn escape(&mut self, start: usize) {
+        let Some(command) = self.take_byte() else {
+            self.report_truncated(start);
+            return;
+        };
+
+        let understood = match command {
+            b'@' => {
+                self.reset();
+                Some(true)
+            }
+            b'E' | b'F' => Some(self.set_style(StyleFlags::BOLD, command == b'E')),
+            b'4' | b'5' => Some(self.set_style(StyleFlags::ITALIC, command == b'4')),
+            b'G' | b'H' => Some(self.set_style(StyleFlags::DOUBLE_STRIKE, command == b'G')),
+            b'T' => {
+                self.style = self
+                    .style
+                    .set(StyleFlags::SUPERSCRIPT, false)
+                    .set(StyleFlags::SUBSCRIPT, false);
+                Some(true)
+            }
+            b'-' => self.switch(StyleFlags::UNDERLINE),
+            b'W' => self.switch(StyleFlags::DOUBLE_WIDTH),
+            b'w' => self.switch(StyleFlags::DOUBLE_HEIGHT),
+            b'S' => self.take_byte().map(|n| match n {
+                0 | b'0' => {
+                    self.style = self.style.with_superscript();
+                    true
+                }
+                1 | b'1' => {
+                    self.style = self.style.with_subscript();
+                    true
+                }
+                _ => false,
+            }),
+            b'P' => Some(self.set_base_pitch(BasePitch::Pica)),
+            b'M' => Some(self.set_base_pitch(BasePitch::Elite)),
+            b'g' => Some(self.set_base_pitch(BasePitch::Cpi15)),
+            b'!' => self.take_byte().map(|n| self.master_select(n)),
+            b'0' => Some(self.set_line_spacing(LineSpacing::EighthInch)),
+            b'1' => Some(self.set_line_spacing(LineSpacing::SevenSeventySecondInch)),
+            b'2' => Some(self.set_line_spacing(LineSpacing::SixthInch)),
+            b'3' => self
+                .take_byte()
+                .map(|n| self.set_line_spacing(LineSpacing::Units180(n))),
+            b'+' => self
+                .take_byte()
+                .map(|n| self.set_line_spacing(LineSpacing::Units360(n))),
+            b'A' => self.take_byte().map(|n| {
+                // n/60 inch
+                let spacing = match u8::try_from(n as u32 * 6) {
+                    Ok(units) => LineSpacing::Units360(units),
+                    Err(_) => LineSpacing::Units180(n.saturating_mul(3)),
+                };
+                self.set_line_spacing(spacing)
+            }),
+            b'C' => self.page_length_command(),
+            b'J' => self.take_byte().map(|n| {
+                self.vertical = self.vertical.saturating_add(n as u32 * 2);
+                true
+            }),
+            b'j' => self.take_byte().map(|n| {
+                self.vertical = self.vertical.saturating_sub(n as u32 * 2);
+                true
+            }),
+            b'$' => self.take(2).map(|n| {
+                let units = u16::from_le_bytes([n[0], n[1]]) as u32;
+                // Default unit 1/60 inch
+                self.position = units * self.motion_unit.unwrap_or(60) / 10;
+                true
+            }),
+            b'\\' => self.take(2).map(|n| {
+                let units = i16::from_le_bytes([n[0], n[1]]) as i64;
+                // Default unit 1/180 inch in letter quality
+                let delta = units * self.motion_unit.unwrap_or(20) as i64 / 10;
+                self.position = (self.position as i64 + delta).max(0) as u32;
+                true
+            }),
+            b'R' => self
+                .take_byte()
+                .map(|n| match InternationalCharset::from_code(n) {
+                    Some(charset) => {
+                        self.international = charset;
+                        self.update_encoder();
+                        true
+                    }
+                    None => false,
+                }),
+            b't' => self.take_byte().map(|n| match n {
+                0..=3 | b'0'..=b'3' => {
+                    self.selected_slot = (n & 0x03) as usize;
+                    self.update_encoder();
+                    true
+                }
+                _ => false,
+            }),
+            b'(' => self.extended_command(),
+            b'*' => self.bit_image(),
+            b'D' | b'B' | b'b' => self.skip_until_nul(),
+            // Commands with one parameter and no cell-grid equivalent
+            b'x' | b'k' | b'l' | b'Q' | b'N' | b'p' | b'a' | b' ' | b'U' | b'r' | b'q' | b'/'
+            | b'%' | b'i' | b'm' | b's' | b'I' => self.take(1).map(|_| false),
+            // Commands with two parameters and no cell-grid equivalent
+            b'c' | b'e' | b'f' => self.take(2).map(|_| false),
+            _ => Some(false),
+        };
+
+        match understood {
+            Some(true) => {}
+            Some(false) => self.report(start),
+            None => self.report_truncated(start),
+        }
+    }
+
+    fn set_style(&mut self, flag: StyleFlags, on: bool) -> bool {
+        self.style = self.style.set(flag, on);
+        true
+    }
+
+    /// Decodes the on/off parameter of ESC -, ESC W and ESC w.
+    fn switch(&mut self, flag: StyleFlags) -> Option<bool> {
+        self.take_byte().map(|n| match n {
+            0 | b'0' => self.set_style(flag, false),
+            1 | b'1' => self.set_style(flag, true),
+            _ => false,
+        })
+    }
+
+    fn set_base_pitch(&mut self, base_pitch: BasePitch) -> bool {
+        self.base_pitch = base_pitch;
+        true
+    }
+
+    fn set_line_spacing(&mut self, line_spacing: LineSpacing) -> bool {
+        self.line_spacing = line_spacing;
+        true
+    }
+
+    /// ESC ! n - master select
+    fn master_select(&mut self, n: u8) -> bool {
+        self.base_pitch = if n & 0x01 != 0 {
+            BasePitch::Elite
+        } else {
+            BasePitch::Pica
+        };
+        self.condensed = n & 0x04 != 0;
+        self.style = self
+            .style
+            .set(StyleFlags::BOLD, n & 0x08 != 0)
+            .set(StyleFlags::DOUBLE_STRIKE, n & 0x10 != 0)
+            .set(StyleFlags::DOUBLE_WIDTH, n & 0x20 != 0)
+            .set(StyleFlags::ITALIC, n & 0x40 != 0)
+            .set(StyleFlags::UNDERLINE, n & 0x80 != 0);
+        true
+    }
+
+    /// ESC C n (lines) or ESC C 0 n (inches)
+    ///
+    /// Like the printer, ignores page lengths beyond the longest form it
+    /// accepts (22 inches).
+    fn page_length_command(&mut self) -> Option<bool> {
+        let n = self.take_byte()?;
+        if n != 0 {
+            let form_length = n as u32 * self.line_spacing.units();
+            if form_length > MAX_FORM_LENGTH {
+                return Some(false);
+            }
+            self.page_length = Some(PageLength::Lines(n));
+            self.form_length = Some(form_length);
+            return Some(true);
+        }
+        let inches = self.take_byte()?;
+        if inches == 0 || inches as u32 > MAX_PAGE_LENGTH_INCHES {
+            return Some(false);
+        }
+        self.page_length = Some(PageLength::Inches(inches));
+        self.form_length = Some(inches as u32 * UNITS_PER_INCH);
+        Some(true)
+    }
+
+    /// ESC ( c nL nH data - extended commands
+    fn extended_command(&mut self) -> Option<bool> {
+        let header = self.take(3)?;
+        let (class, length) = (header[0], u16::from_le_bytes([header[1], header[2]]));
+        let data = self.take(length as usize)?;
+
+        Some(match (class, data) {
+            // ESC ( t 3 0 d1 d2 d3 - assign character table
+            (b't', &[slot, d2, d3]) => match CharacterTable::from_table_id(d2, d3) {
+                Some(table) if slot <= 3 => {
+                    self.table_slots[slot as usize] = Some(table);
+                    self.update_encoder();
+                    true
+                }
+                _ => false,
+            },
+            // ESC ( U 1 0 m - unit for horizontal positioning
+            (b'U', &[unit]) if unit > 0 => {
+                self.motion_unit = Some(unit as u32);
+                true
+            }
+            // ESC ( - 3 0 1 m d - score lines
+            (b'-', &[1, 1, d]) => self.set_style(StyleFlags::UNDERLINE, d != 0),
+            (b'-', &[1, 2, d]) => self.set_style(StyleFlags::STRIKETHROUGH, d != 0),
+            _ => false,
+        })
+    }
+
+    /// ESC * m nL nH data - bit image (skipped)
+    fn bit_image(&mut self) -> Option<bool> {
+        let header = self.take(3)?;
+        let bytes_per_column = match header[0] {
+            0..=6 => 1,
+            32..=40 => 3,
+            71..=73 => 6,
+            _ => return Some(false),
+        };
+        let columns = u16::from_le_bytes([header[1], header[2]]) as usize;
+        self.take(columns * bytes_per_column)?;
+        Some(false)
+    }
+
+    /// Tab-setting commands terminated by NUL (skipped)
+    fn skip_until_nul(&mut self) -> Option<bool> {
+        let length = self.bytes[self.offset..].iter().position(|&b| b == 0)?;
+        self.take(length + 1)?;
+        Some(false)
+    }
+
+    /// Ejects a final page left without a form feed and collects the
+    /// results.
+    fn finish(mut self) -> Interpretation {
+        if !self.glyphs.is_empty() {
+            self.form_feed();
+        }
+        Interpretation {
+            pitch: self.pitch(),
+            line_spacing: self.line_spacing,
+            character_table: self.character_table().unwrap_or_default(),
+            international_charset: self.international,
+            pages: self.pages,
+            unknown_sequences: self.unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_physical_positions() {
+        // 10 cpi, then condensed after 1/8-inch spacing and a 1/180-inch feed
+        let result = interpret(b"\x1b@\x1bPAB\r\n\x1b0\x0f\x1bJ\x03C\x0c");
+        let glyphs = &result.pages[0].glyphs;
+        let placed: Vec<(u32, u32, u32, u32, char)> = glyphs
+            .iter()
+            .map(|g| {
+                (
+                    g.position,
+                    g.vertical,
+                    g.char_width,
+                    g.line_spacing,
+                    g.character,
+                )
+            })
+            .collect();
+        assert_eq!(
+            placed,
+            [
+                (0, 0, 36, 60, 'A'),
+                (36, 0, 36, 60, 'B'),
+                (0, 66, 21, 45, 'C')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_form_length() {
+        let result = interpret(b"\x1b@\x1b0\x1bC\x10A\x0c\x1bC\x00\x0bB\x0c");
+        let lengths: Vec<Option<u32>> = result.pages.iter().map(|p| p.form_length).collect();
+        // 16 lines at 1/8 inch, then 11 inches
+        assert_eq!(lengths, [Some(720), Some(3960)]);
+        assert_eq!(result.pages[1].page_length, Some(PageLength::Inches(11)));
+    }
+
+    #[test]
+    fn test_form_length_limited_to_22_inches() {
+        // 255 lines at 255/180 inch, then 23 inches: both ignored
+        let result = interpret(b"\x1b3\xff\x1bC\xffA\x0c\x1bC\x00\x17B\x0c\x1bC\x00\x16C\x0c");
+        let lengths: Vec<Option<u32>> = result.pages.iter().map(|p| p.form_length).collect();
+        assert_eq!(lengths, [None, None, Some(7920)]);
+
+        let unknown: Vec<&[u8]> = result
+            .unknown_sequences
+            .iter()
+            .map(UnknownSequence::bytes)
+            .collect();
+        assert_eq!(unknown, [&b"\x1bC\xff"[..], &b"\x1bC\x00\x17"[..]]);
+    }
+
+    #[test]
+    fn test_final_page_without_form_feed() {
+        assert_eq!(interpret(b"\x1b@").pages.len(), 0);
+        assert_eq!(interpret(b"\x1b@text").pages.len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_sequence_display() {
+        let sequence = UnknownSequence {
+            offset: 12,
+            bytes: vec![0x1B, 0x7E, 0x01],
+        };
+        assert_eq!(
+            sequence.to_string(),
+            "unknown sequence at byte 12: 1B 7E 01"
+        );
+    }
+}
@@ -1,7 +1,16 @@
 //! ESC/P rendering engine for EPSON LQ-2090II.
 
-mod constants;
+pub(crate) mod constants;
+mod decoder;
+mod font;
+mod interpreter;
+mod options;
+mod printer;
 mod renderer;
 mod state;
 
-pub(crate) use renderer::render_document;
+pub use decoder::{Decoded, Decoder};
+pub use interpreter::UnknownSequence;
+pub use options::{HorizontalSkip, RenderOptions};
+pub use printer::{PageImage, PageImages, Printout, VirtualPrinter};
+pub(crate) use renderer::{render_document, write_document, write_page};
@@ -0,0 +1,186 @@
+//! RenderOptions and HorizontalSkip types selecting how pages are encoded to ESC/P.
+
+/// Options controlling how a document is encoded to ESC/P bytes.
+///
+/// The default reproduces [`Document::render`](crate::Document::render):
+/// every cell of every row is sent. All options keep the output
+/// deterministic and the printed page identical.
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{Document, Page, RenderOptions, StyleFlags};
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.write_str(0, 0, "Hello", StyleFlags::NONE);
+///
+/// let mut builder = Document::builder();
+/// builder.add_page(page_builder.build());
+/// let document = builder.build();
+///
+/// let full = document.render();
+/// let compact = document.render_with(RenderOptions::new().with_compact(true));
+/// assert!(compact.len() < full.len());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RenderOptions {
+    compact: bool,
+    horizontal_skip: Option<HorizontalSkip>,
+    skip_threshold: u16,
+}
+
+impl RenderOptions {
+    /// Default minimum run of blank cells considered for a horizontal skip.
+    ///
+    /// Every run is priced against its spaces, so the default considers
+    /// all of them.
+    pub const DEFAULT_SKIP_THRESHOLD: u16 = 1;
+
+    /// Creates the default options (full rows, no compaction, no skipping)
+    pub const fn new() -> Self {
+        RenderOptions {
+            compact: false,
+            horizontal_skip: None,
+            skip_threshold: Self::DEFAULT_SKIP_THRESHOLD,
+        }
+    }
+
+    /// Returns options with compact output enabled or disabled.
+    ///
+    /// Compact output drops blanks at the end of each row, feeds blank rows
+    /// with LF (or `ESC J` for longer runs) instead of printing spaces, and
+    /// leaves blank rows at the bottom of a page to the form feed. Blanks
+    /// that print something (underlined or struck through) are kept.
+    pub const fn with_compact(self, compact: bool) -> Self {
+        RenderOptions { compact, ..self }
+    }
+
+    /// Returns true if compact output is enabled
+    #[inline]
+    pub const fn compact(self) -> bool {
+        self.compact
+    }
+
+    /// Returns options that move the print head over runs of blank cells
+    /// inside a row instead of printing spaces.
+    ///
+    /// Each run of blanks followed by visible text is replaced by a move
+    /// only if that takes fewer bytes than the spaces, counting the style
+    /// switches either choice forces (spaces are printed in the style of
+    /// their cells; a move only needs underline and strikethrough off). The
+    /// text after the skip lands on the same column as with space padding.
+    /// Positions are sent in 1/360-inch units, selected once with `ESC ( U`
+    /// at initialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Document, HorizontalSkip, Page, RenderOptions, StyleFlags};
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.write_str(0, 0, "Name", StyleFlags::NONE);
+    /// page_builder.write_str(100, 0, "Date", StyleFlags::NONE);
+    ///
+    /// let mut builder = Document::builder();
+    /// builder.add_page(page_builder.build());
+    /// let document = builder.build();
+    ///
+    /// let options = RenderOptions::new().with_horizontal_skip(HorizontalSkip::Absolute);
+    /// let bytes = document.render_with(options);
+    ///
+    /// // Column 100 at 21/360 inch per column is 2100/360 inch from the margin
+    /// assert!(bytes.windows(8).any(|w| w == b"Name\x1b$\x34\x08"));
+    /// ```
+    pub const fn with_horizontal_skip(self, horizontal_skip: HorizontalSkip) -> Self {
+        RenderOptions {
+            horizontal_skip: Some(horizontal_skip),
+            ..self
+        }
+    }
+
+    /// Returns options with horizontal skipping disabled
+    pub const fn without_horizontal_skip(self) -> Self {
+        RenderOptions {
+            horizontal_skip: None,
+            ..self
+        }
+    }
+
+    /// Returns options with the minimum run of blank cells considered for a
+    /// horizontal skip (clamped to at least 1). Shorter runs are always
+    /// printed as spaces.
+    pub const fn with_skip_threshold(self, skip_threshold: u16) -> Self {
+        RenderOptions {
+            skip_threshold: if skip_threshold == 0 {
+                1
+            } else {
+                skip_threshold
+            },
+            ..self
+        }
+    }
+
+    /// Returns the horizontal skip command in use, if any
+    #[inline]
+    pub const fn horizontal_skip(self) -> Option<HorizontalSkip> {
+        self.horizontal_skip
+    }
+
+    /// Returns the minimum run of blank cells considered for a horizontal skip
+    #[inline]
+    pub const fn skip_threshold(self) -> u16 {
+        self.skip_threshold
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Commands used to move the print head over runs of blank cells.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HorizontalSkip {
+    /// ESC $ - absolute position from the left margin
+    Absolute,
+    /// ESC \ - position relative to the current print position
+    Relative,
+    /// Whichever of ESC $ and ESC \ is cheaper for each run (ESC $ when
+    /// both fit, since they take the same number of bytes)
+    Auto,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_options_default() {
+        assert_eq!(RenderOptions::default(), RenderOptions::new());
+        assert!(!RenderOptions::new().compact());
+    }
+
+    #[test]
+    fn test_render_options_horizontal_skip() {
+        let options = RenderOptions::new();
+        assert_eq!(options.horizontal_skip(), None);
+        assert_eq!(options.skip_threshold(), 1);
+
+        let options = options
+            .with_horizontal_skip(HorizontalSkip::Relative)
+            .with_compact(true)
+            .with_skip_threshold(0);
+        assert_eq!(options.horizontal_skip(), Some(HorizontalSkip::Relative));
+        assert!(options.compact());
+        assert_eq!(options.skip_threshold(), 1);
+        assert_eq!(options.without_horizontal_skip().horizontal_skip(), None);
+    }
+
+    #[test]
+    fn test_render_options_compact() {
+        let options = RenderOptions::new().with_compact(true);
+        assert!(options.compact());
+        assert!(!options.with_compact(false).compact());
+    }
+}
@@ -0,0 +1,496 @@
+//! Virtual dot-matrix printer rasterizing ESC/P byte streams.
+
+use super::constants::UNITS_PER_INCH;
+use super::font::{glyph, GLYPH_COLUMNS, GLYPH_ROWS};
+use super::interpreter::{interpret, PrintedGlyph, PrintedPage, UnknownSequence};
+
+/// Default resolution in dots per inch
+const DEFAULT_DPI: u32 = 180;
+
+/// LQ-2090II maximum printable width (13.6 inches) in 1/360 inch
+const DEFAULT_PAPER_WIDTH: u32 = 4896;
+
+/// Form length used when the stream sets none (11 inches) in 1/360 inch
+const DEFAULT_FORM_LENGTH: u32 = 11 * UNITS_PER_INCH;
+
+/// Dot columns per character cell (the glyph plus one blank column)
+const CELL_COLUMNS: f64 = GLYPH_COLUMNS as f64 + 1.0;
+
+/// Height of one glyph dot row in 1/360 inch (1/60 inch)
+const DOT_HEIGHT: f64 = 6.0;
+
+/// Underline position below the top of the print head in 1/360 inch
+const UNDERLINE_OFFSET: f64 = 45.0;
+
+/// Strikethrough position below the top of the print head in 1/360 inch
+const STRIKETHROUGH_OFFSET: f64 = 21.0;
+
+/// Thickness of underline and strikethrough rules in 1/360 inch
+const RULE_HEIGHT: f64 = 3.0;
+
+/// Ink laid down by one pass of the print head (of 255)
+const PASS_INK: u8 = 160;
+
+/// Software model of the printer that rasterizes ESC/P output.
+///
+/// The printer replays the same command set as the [`Decoder`] (pitch, line
+/// spacing, styles, page length, horizontal and vertical motion, FF) and
+/// prints each character at its physical position with a built-in 5×7 dot
+/// font. Every form feed ejects a [`PageImage`], which can be saved as a
+/// PBM or PGM file to check placement without using paper.
+///
+/// The page is as wide as the configured paper and as long as the page
+/// length set with `ESC C` (11 inches if the stream sets none). Like the
+/// real printer, page lengths beyond 22 inches are ignored. Dots beyond the
+/// paper are clipped.
+///
+/// [`Decoder`]: crate::Decoder
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{Document, Page, StyleFlags, VirtualPrinter};
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.write_str(0, 0, "INVOICE", StyleFlags::BOLD);
+///
+/// let mut builder = Document::builder();
+/// builder.add_page(page_builder.build());
+/// let document = builder.build();
+///
+/// let printout = VirtualPrinter::new().with_dpi(60).print(&document.render());
+/// let page = &printout.pages()[0];
+///
+/// // 51 lines at 1/6 inch
+/// assert_eq!(page.height(), 51 * 10);
+/// assert!(page.to_pbm().starts_with(b"P4\n816 510\n"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualPrinter {
+    dpi: u32,
+    paper_width: u32,
+    form_length: u32,
+}
+
+impl VirtualPrinter {
+    /// Creates a printer at 180 dpi with 13.6-inch paper and an 11-inch
+    /// default form length
+    pub const fn new() -> Self {
+        VirtualPrinter {
+            dpi: DEFAULT_DPI,
+            paper_width: DEFAULT_PAPER_WIDTH,
+            form_length: DEFAULT_FORM_LENGTH,
+        }
+    }
+
+    /// Returns a printer rasterizing at `dpi` dots per inch (at least 1)
+    pub const fn with_dpi(self, dpi: u32) -> Self {
+        VirtualPrinter {
+            dpi: if dpi == 0 { 1 } else { dpi },
+            ..self
+        }
+    }
+
+    /// Returns a printer with paper `width` wide, in 1/360 inch
+    pub const fn with_paper_width(self, width: u32) -> Self {
+        VirtualPrinter {
+            paper_width: width,
+            ..self
+        }
+    }
+
+    /// Returns a printer using `length` (in 1/360 inch) as the form length
+    /// of streams that do not set one with `ESC C`
+    pub const fn with_default_form_length(self, length: u32) -> Self {
+        VirtualPrinter {
+            form_length: length,
+            ..self
+        }
+    }
+
+    /// Returns the resolution in dots per inch
+    pub const fn dpi(&self) -> u32 {
+        self.dpi
+    }
+
+    /// Prints an ESC/P byte stream.
+    ///
+    /// Printing never fails: sequences the printer does not understand are
+    /// skipped and reported in [`Printout::unknown_sequences`].
+    pub fn print(&self, bytes: &[u8]) -> Printout {
+        let mut pages = self.print_pages(bytes);
+        let unknown_sequences = std::mem::take(&mut pages.unknown_sequences);
+        Printout {
+            pages: pages.collect(),
+            unknown_sequences,
+        }
+    }
+
+    /// Prints an ESC/P byte stream one page at a time.
+    ///
+    /// Each page is rasterized when the iterator reaches it, so only the
+    /// page being processed is held in memory; use this for long streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::VirtualPrinter;
+    ///
+    /// let pages = VirtualPrinter::new().with_dpi(36).print_pages(b"A\x0cB\x0c");
+    /// assert!(pages.unknown_sequences().is_empty());
+    /// assert_eq!(pages.filter(|page| !page.is_blank()).count(), 2);
+    /// ```
+    pub fn print_pages(&self, bytes: &[u8]) -> PageImages {
+        let interpretation = interpret(bytes);
+        PageImages {
+            printer: *self,
+            pages: interpretation.pages.into_iter(),
+            unknown_sequences: interpretation.unknown_sequences,
+        }
+    }
+
+    /// Rasterizes one ejected page.
+    fn rasterize(&self, page: &PrintedPage) -> PageImage {
+        let length = page.form_length.unwrap_or(self.form_length);
+        let mut image = PageImage::blank(self.pixels(self.paper_width), self.pixels(length));
+        let scale = self.dpi as f64 / UNITS_PER_INCH as f64;
+        for printed in &page.glyphs {
+            print_glyph(printed, scale, &mut image);
+        }
+        image
+    }
+
+    /// Converts a length in 1/360 inch to pixels, rounding up
+    fn pixels(&self, units: u32) -> u32 {
+        (units as u64 * self.dpi as u64).div_ceil(UNITS_PER_INCH as u64) as u32
+    }
+}
+
+impl Default for VirtualPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Prints a glyph with its style: one pass of the print head, plus a second
+/// pass for bold (shifted right) and double-strike (shifted down).
+fn print_glyph(printed: &PrintedGlyph, scale: f64, image: &mut PageImage) {
+    let style = printed.style;
+    let left = printed.position as f64;
+    let top = printed.vertical as f64;
+    let width = (printed.char_width * style.glyph_width() as u32) as f64;
+    let height_scale = if style.double_height() { 2.0 } else { 1.0 };
+
+    let dot_width = width / CELL_COLUMNS;
+    let (dot_height, glyph_top) = if style.superscript() {
+        (DOT_HEIGHT / 2.0, top)
+    } else if style.subscript() {
+        (DOT_HEIGHT / 2.0, top + STRIKETHROUGH_OFFSET)
+    } else {
+        (DOT_HEIGHT * height_scale, top)
+    };
+
+    let mut passes = vec![(0.0, 0.0)];
+    if style.bold() {
+        passes.push((dot_width / 2.0, 0.0));
+    }
+    if style.double_strike() {
+        passes.push((0.0, dot_height / 3.0));
+    }
+
+    let columns = glyph(printed.character);
+    for (dx, dy) in passes {
+        for row in 0..GLYPH_ROWS {
+            // Italic slants the upper rows to the right
+            let slant = if style.italic() {
+                (GLYPH_ROWS - 1 - row) as f64 * dot_width / 4.0
+            } else {
+                0.0
+            };
+            for (column, bits) in columns.iter().enumerate() {
+                if bits >> row & 1 == 1 {
+                    let x = left + dx + slant + column as f64 * dot_width;
+                    let y = glyph_top + dy + row as f64 * dot_height;
+                    image.ink_rect(x * scale, y * scale, dot_width * scale, dot_height * scale);
+                }
+            }
+        }
+    }
+
+    if style.underline() {
+        let y = top + UNDERLINE_OFFSET * height_scale;
+        image.ink_rect(left * scale, y * scale, width * scale, RULE_HEIGHT * scale);
+    }
+    if style.strikethrough() {
+        let y = top + STRIKETHROUGH_OFFSET * height_scale;
+        image.ink_rect(left * scale, y * scale, width * scale, RULE_HEIGHT * scale);
+    }
+}
+
+/// Pages printed by a [`VirtualPrinter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Printout {
+    pages: Vec<PageImage>,
+    unknown_sequences: Vec<UnknownSequence>,
+}
+
+impl Printout {
+    /// Returns the printed pages, one per form feed
+    pub fn pages(&self) -> &[PageImage] {
+        &self.pages
+    }
+
+    /// Consumes the printout and returns the printed pages
+    pub fn into_pages(self) -> Vec<PageImage> {
+        self.pages
+    }
+
+    /// Returns the sequences the printer skipped, in stream order
+    pub fn unknown_sequences(&self) -> &[UnknownSequence] {
+        &self.unknown_sequences
+    }
+}
+
+/// Pages printed by a [`VirtualPrinter`], rasterized one at a time.
+///
+/// Returned by [`VirtualPrinter::print_pages`].
+#[derive(Clone, Debug)]
+pub struct PageImages {
+    printer: VirtualPrinter,
+    pages: std::vec::IntoIter<PrintedPage>,
+    unknown_sequences: Vec<UnknownSequence>,
+}
+
+impl PageImages {
+    /// Returns the sequences the printer skipped, in stream order
+    pub fn unknown_sequences(&self) -> &[UnknownSequence] {
+        &self.unknown_sequences
+    }
+}
+
+impl Iterator for PageImages {
+    type Item = PageImage;
+
+    fn next(&mut self) -> Option<PageImage> {
+        let page = self.pages.next()?;
+        Some(self.printer.rasterize(&page))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pages.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PageImages {}
+
+/// Raster image of one printed page.
+///
+/// Each pixel holds the amount of ink on the paper, from 0 (blank) to 255
+/// (saturated). Overprinting, bold and double-strike add ink.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PageImage {
+    width: u32,
+    height: u32,
+    /// Ink per pixel, row-major
+    ink: Vec<u8>,
+}
+
+impl PageImage {
+    fn blank(width: u32, height: u32) -> Self {
+        PageImage {
+            width,
+            height,
+            ink: vec![0; width as usize * height as usize],
+        }
+    }
+
+    /// Returns the width in pixels
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height in pixels
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the ink at a pixel (0 outside the image)
+    pub fn ink(&self, x: u32, y: u32) -> u8 {
+        if x < self.width && y < self.height {
+            self.ink[y as usize * self.width as usize + x as usize]
+        } else {
+            0
+        }
+    }
+
+    /// Returns true if no ink was printed on the page
+    pub fn is_blank(&self) -> bool {
+        self.ink.iter().all(|&ink| ink == 0)
+    }
+
+    /// Encodes the page as a binary PBM (P4) bitmap; inked pixels are black.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut output = format!("P4\n{} {}\n", self.width, self.height).into_bytes();
+        let row_bytes = (self.width as usize).div_ceil(8);
+        for row in self.ink.chunks_exact(self.width.max(1) as usize) {
+            let mut packed = vec![0u8; row_bytes];
+            for (x, &ink) in row.iter().enumerate() {
+                if ink > 0 {
+                    packed[x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+            output.extend_from_slice(&packed);
+        }
+        output
+    }
+
+    /// Encodes the page as a binary PGM (P5) greymap; white is blank paper.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut output = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        output.extend(self.ink.iter().map(|&ink| 255 - ink));
+        output
+    }
+
+    /// Adds one pass of ink to the pixels covered by a rectangle (in
+    /// pixels), clipped to the page. Rectangles cover at least one pixel.
+    fn ink_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        let (x0, y0) = (x.floor().max(0.0), y.floor().max(0.0));
+        let x1 = (x + width).floor().max(x0 + 1.0).min(self.width as f64);
+        let y1 = (y + height).floor().max(y0 + 1.0).min(self.height as f64);
+        for py in y0 as u32..y1.max(y0) as u32 {
+            let start = py as usize * self.width as usize;
+            for px in x0 as u32..x1.max(x0) as u32 {
+                let pixel = &mut self.ink[start + px as usize];
+                *pixel = pixel.saturating_add(PASS_INK);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 36 dpi: one pixel per 10 units, so 10 cpi cells are 3.6 pixels wide
+    /// and 1/6-inch lines 6 pixels tall
+    fn printer() -> VirtualPrinter {
+        VirtualPrinter::new().with_dpi(36).with_paper_width(720)
+    }
+
+    /// Returns the inked pixels' bounding box (x0, y0, x1, y1), exclusive
+    fn ink_bounds(image: &PageImage) -> Option<(u32, u32, u32, u32)> {
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for y in 0..image.height() {
+            for x in 0..image.width() {
+                if image.ink(x, y) > 0 {
+                    let (x0, y0, x1, y1) = bounds.unwrap_or((x, y, x + 1, y + 1));
+                    bounds = Some((x0.min(x), y0.min(y), x1.max(x + 1), y1.max(y + 1)));
+                }
+            }
+        }
+        bounds
+    }
+
+    #[test]
+    fn test_page_size() {
+        let printout = printer().print(b"\x1b@\x1bC\x0cA\x0c\x1bC\x00\x03B\x0cC");
+        let sizes: Vec<(u32, u32)> = printout
+            .pages()
+            .iter()
+            .map(|page| (page.width(), page.height()))
+            .collect();
+        // 12 lines at 1/6 inch, then 3 inches for the rest of the stream
+        assert_eq!(sizes, [(72, 72), (72, 108), (72, 108)]);
+    }
+
+    #[test]
+    fn test_default_form_length() {
+        let printout = printer().print(b"A\x0c");
+        assert_eq!(printout.pages()[0].height(), 396);
+
+        let printout = printer().with_default_form_length(360).print(b"A\x0c");
+        assert_eq!(printout.pages()[0].height(), 36);
+    }
+
+    #[test]
+    fn test_form_length_limited_to_22_inches() {
+        // 255 lines at 255/180 inch would be a 361-inch form
+        let printout = printer().print(b"\x1b3\xff\x1bC\xffX\x0cX\x0cX\x0cX\x0c");
+        assert_eq!(printout.pages().len(), 4);
+        assert!(printout.pages().iter().all(|page| page.height() == 396));
+        assert_eq!(printout.unknown_sequences()[0].bytes(), b"\x1bC\xff");
+    }
+
+    #[test]
+    fn test_print_pages_matches_print() {
+        let bytes = b"\x1bC\x02A\x0c\x1bC\x03\x1b~B\x0cC";
+        let pages = printer().print_pages(bytes);
+        assert_eq!(pages.len(), 3);
+        let unknown = pages.unknown_sequences().to_vec();
+
+        let printout = printer().print(bytes);
+        assert_eq!(pages.collect::<Vec<_>>(), printout.pages());
+        assert_eq!(unknown, printout.unknown_sequences());
+    }
+
+    #[test]
+    fn test_glyph_placement() {
+        // ESC $ 60 = 1 inch from the left margin, after one 1/6-inch line
+        let printout = printer().print(b"\x1b@\x1bC\x0c\n\x1b$\x3c\x00I\x0c");
+        let page = &printout.pages()[0];
+        // 'I' inks its middle three 6-unit dot columns (366-384) and seven
+        // 6-unit rows below the first line (60-102)
+        assert_eq!(ink_bounds(page), Some((36, 6, 38, 10)));
+        assert!(printout.unknown_sequences().is_empty());
+    }
+
+    #[test]
+    fn test_styles_add_ink() {
+        let plain = printer().print(b"\x1bC\x02H\x0c");
+        let bold = printer().print(b"\x1bC\x02\x1bEH\x0c");
+        let ink = |printout: &Printout| -> u32 {
+            let page = &printout.pages()[0];
+            (0..page.height())
+                .flat_map(|y| (0..page.width()).map(move |x| (x, y)))
+                .map(|(x, y)| page.ink(x, y) as u32)
+                .sum()
+        };
+        assert!(ink(&bold) > ink(&plain));
+
+        let underlined = printer().print(b"\x1bC\x02\x1b-\x01 \x0c");
+        let (_, y0, _, y1) = ink_bounds(&underlined.pages()[0]).unwrap();
+        assert_eq!((y0, y1), (4, 5));
+    }
+
+    #[test]
+    fn test_double_width_doubles_extent() {
+        let normal = printer().with_dpi(360).print(b"\x1bC\x01M\x0c");
+        let wide = printer().with_dpi(360).print(b"\x1bC\x01\x1bW\x01M\x0c");
+        let (x0, _, x1, _) = ink_bounds(&normal.pages()[0]).unwrap();
+        let (wx0, _, wx1, _) = ink_bounds(&wide.pages()[0]).unwrap();
+        assert_eq!((x0, x1), (0, 30));
+        assert_eq!((wx0, wx1), (0, 60));
+    }
+
+    #[test]
+    fn test_clipping_and_blank_pages() {
+        let printout = printer().print(b"\x1b$\xff\x7fX\x0c\x0c");
+        assert_eq!(printout.pages().len(), 2);
+        assert!(printout.pages().iter().all(PageImage::is_blank));
+    }
+
+    #[test]
+    fn test_pbm_and_pgm_encoding() {
+        let mut image = PageImage::blank(10, 2);
+        image.ink_rect(0.0, 0.0, 1.0, 1.0);
+        image.ink_rect(9.0, 1.0, 1.0, 1.0);
+
+        assert_eq!(image.to_pbm(), b"P4\n10 2\n\x80\x00\x00\x40");
+
+        let pgm = image.to_pgm();
+        assert!(pgm.starts_with(b"P5\n10 2\n255\n"));
+        assert_eq!(pgm.len(), b"P5\n10 2\n255\n".len() + 20);
+        assert_eq!(pgm[b"P5\n10 2\n255\n".len()], 255 - PASS_INK);
+        assert_eq!(*pgm.last().unwrap(), 255 - PASS_INK);
+    }
+}
@@ -2,43 +2,273 @@
 
 use super::constants::*;
 use super::state::RenderState;
-use crate::{Cell, Document, Page};
+use super::{HorizontalSkip, RenderOptions};
+use crate::charset::Encoder;
+use crate::{
+    Cell, CharacterTable, Document, InternationalCharset, LineSpacing, Page, PageGeometry, Pitch,
+    StyleFlags,
+};
+use std::io::{self, Write};
 
 /// Renders a complete document to an ESC/P byte stream.
 ///
 /// Output format:
-/// 1. ESC_RESET + SI_CONDENSED + ESC_PAGE_LENGTH_50 (initialization)
-/// 2. Page content (one page at a time)
-/// 3. CR + FF after each page (no reset between pages)
-pub(crate) fn render_document(doc: &Document) -> Vec<u8> {
+/// 1. ESC_RESET + pitch + line spacing + character tables + motion unit +
+///    page length (initialization, page length from the first page)
+/// 2. Page content (one page at a time, every row of the page; trimmed
+///    when `options` selects compact output, with runs of blanks skipped
+///    when `options` selects a horizontal skip)
+/// 3. FF after each page (no reset between pages)
+///
+/// When a page's length differs from the previous page, a new page-length
+/// command is emitted before its content (the printer is at top-of-form
+/// after FF).
+pub(crate) fn render_document(doc: &Document, options: RenderOptions) -> Vec<u8> {
     let mut output = Vec::new();
+    let mut renderer = DocumentRenderer::new(doc, options, first_geometry(doc), &mut output);
+    for page in doc.pages() {
+        renderer.render_page(page, &mut output);
+    }
+    output
+}
 
-    // Initialization sequence
-    output.extend_from_slice(ESC_RESET);           // ESC @ - Reset printer
-    output.extend_from_slice(SI_CONDENSED);        // SI - Condensed mode
-    output.extend_from_slice(ESC_PAGE_LENGTH_50);  // ESC C 50 - Set 50-line pages
+/// Writes a complete document to `writer`, one page at a time.
+///
+/// The bytes are identical to [`render_document`]; only the initialization
+/// sequence and a single page are buffered at any time.
+pub(crate) fn write_document<W: Write>(
+    doc: &Document,
+    options: RenderOptions,
+    writer: &mut W,
+) -> io::Result<()> {
+    let mut buffer = Vec::new();
+    let mut renderer = DocumentRenderer::new(doc, options, first_geometry(doc), &mut buffer);
+    writer.write_all(&buffer)?;
 
-    // Render each page
     for page in doc.pages() {
-        render_page(page, &mut output);
+        buffer.clear();
+        renderer.render_page(page, &mut buffer);
+        writer.write_all(&buffer)?;
+    }
+    writer.flush()
+}
+
+/// Writes a single page as a complete job with default document settings.
+///
+/// The bytes are identical to rendering a default document holding only
+/// this page.
+pub(crate) fn write_page<W: Write>(page: &Page, writer: &mut W) -> io::Result<()> {
+    let defaults = Document::builder().build();
+    let mut buffer = Vec::new();
+    let mut renderer = DocumentRenderer::new(
+        &defaults,
+        RenderOptions::default(),
+        page.geometry(),
+        &mut buffer,
+    );
+    renderer.render_page(page, &mut buffer);
+    writer.write_all(&buffer)?;
+    writer.flush()
+}
+
+/// Returns the geometry of the document's first page (default if empty)
+fn first_geometry(doc: &Document) -> PageGeometry {
+    doc.pages()
+        .first()
+        .map_or(PageGeometry::default(), Page::geometry)
+}
+
+/// Incremental document renderer carrying the settings and printer state
+/// that persist from page to page.
+struct DocumentRenderer {
+    encoder: Encoder,
+    skip: Option<Skip>,
+    compact: bool,
+    /// Line spacing in 1/360-inch units
+    line_spacing: u32,
+    /// Page length currently set on the printer
+    page_length: PageLength,
+}
+
+impl DocumentRenderer {
+    /// Creates a renderer and emits the initialization sequence, with the
+    /// page length set for `first_geometry`.
+    fn new(
+        doc: &Document,
+        options: RenderOptions,
+        first_geometry: PageGeometry,
+        output: &mut Vec<u8>,
+    ) -> Self {
+        // Initialization sequence
+        output.extend_from_slice(ESC_RESET); // ESC @ - Reset printer
+        output.extend_from_slice(pitch_command(doc.pitch())); // Character pitch
+        push_line_spacing(doc.line_spacing(), output); // Line spacing
+        let line_spacing = doc.line_spacing().units();
+        push_character_table(doc.character_table(), output); // ESC ( t + ESC t
+        push_international_charset(doc.international_charset(), output); // ESC R
+        let encoder = Encoder::new(
+            doc.character_table(),
+            doc.international_charset(),
+            doc.fallback_char(),
+        );
+        let skip = options.horizontal_skip().map(|mode| Skip {
+            mode,
+            min_run: options.skip_threshold() as usize,
+            char_width: doc.pitch().char_width(),
+        });
+        if skip.is_some() {
+            output.extend_from_slice(ESC_UNIT_360); // ESC ( U - 1/360-inch motion unit
+        }
+
+        let page_length = PageLength::for_geometry(first_geometry, line_spacing);
+        page_length.write(output); // ESC C - Set page length
+
+        DocumentRenderer {
+            encoder,
+            skip,
+            compact: options.compact(),
+            line_spacing,
+            page_length,
+        }
+    }
+
+    /// Emits one page followed by its form feed.
+    fn render_page(&mut self, page: &Page, output: &mut Vec<u8>) {
+        let length = PageLength::for_geometry(page.geometry(), self.line_spacing);
+        if length != self.page_length {
+            length.write(output);
+            self.page_length = length;
+        }
+
+        if self.compact {
+            render_page_compact(page, &self.encoder, self.skip, self.line_spacing, output);
+        } else {
+            render_page(page, &self.encoder, self.skip, output);
+        }
         // Form feed to next page (no CR needed - last line already has CR+LF)
-        output.push(FF);  // Form feed to next page
+        output.push(FF); // Form feed to next page
     }
+}
 
-    output
+/// Returns the command selecting the given character pitch.
+///
+/// ESC @ selects pica, so condensed pica only needs SI.
+fn pitch_command(pitch: Pitch) -> &'static [u8] {
+    match pitch {
+        Pitch::Cpi10 => ESC_PICA,
+        Pitch::Cpi12 => ESC_ELITE,
+        Pitch::Cpi15 => ESC_15_CPI,
+        Pitch::Condensed17 => SI_CONDENSED,
+        Pitch::Condensed20 => ESC_ELITE_CONDENSED,
+    }
+}
+
+/// Emits the command selecting the given line spacing.
+///
+/// ESC @ selects 1/6-inch spacing, so the default emits nothing. ESC 1 is a
+/// 9-pin command, so 7/72 inch is set as 35/360 inch. The spacing must be
+/// set before the page length, since ESC C n counts lines at the current
+/// spacing.
+fn push_line_spacing(line_spacing: LineSpacing, output: &mut Vec<u8>) {
+    match line_spacing {
+        LineSpacing::SixthInch => {}
+        LineSpacing::EighthInch => output.extend_from_slice(ESC_LINE_SPACING_EIGHTH),
+        LineSpacing::Units180(n) => {
+            output.extend_from_slice(ESC_LINE_SPACING_180);
+            output.push(n);
+        }
+        LineSpacing::SevenSeventySecondInch | LineSpacing::Units360(_) => {
+            output.extend_from_slice(ESC_LINE_SPACING_360);
+            output.push(line_spacing.units() as u8);
+        }
+    }
+}
+
+/// Emits the commands selecting the given character table.
+///
+/// The table is assigned to slot 1 with ESC ( t and then selected with
+/// ESC t 1. ASCII needs no table, so it emits nothing.
+fn push_character_table(character_table: CharacterTable, output: &mut Vec<u8>) {
+    if let Some((d2, d3)) = character_table.table_id() {
+        output.extend_from_slice(ESC_ASSIGN_TABLE_SLOT_1);
+        output.push(d2);
+        output.push(d3);
+        output.extend_from_slice(ESC_SELECT_TABLE_SLOT_1);
+    }
+}
+
+/// Emits the command selecting the given international character set.
+///
+/// ESC @ selects USA, so the default emits nothing.
+fn push_international_charset(charset: InternationalCharset, output: &mut Vec<u8>) {
+    if charset != InternationalCharset::Usa {
+        output.extend_from_slice(ESC_INTERNATIONAL_CHARSET);
+        output.push(charset.code());
+    }
+}
+
+/// Page-length setting derived from a page's geometry and line spacing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum PageLength {
+    /// ESC C n - length in lines at the current line spacing (1-127)
+    Lines(u8),
+    /// ESC C 0 n - length in inches (1-22), for pages longer than 127 lines
+    Inches(u8),
+}
+
+impl PageLength {
+    /// Computes the page length covering every row of the geometry.
+    ///
+    /// `line_spacing` is in 1/360-inch units. Pages of up to 127 rows and
+    /// 22 inches are set by line count; longer pages are set in whole inches,
+    /// rounded up so the last row still fits on the form, and capped at the
+    /// printer's 22-inch maximum.
+    pub(crate) fn for_geometry(geometry: PageGeometry, line_spacing: u32) -> Self {
+        let rows = geometry.rows();
+        if rows <= MAX_PAGE_LENGTH_LINES && rows as u32 * line_spacing <= MAX_FORM_LENGTH {
+            PageLength::Lines(rows as u8)
+        } else {
+            let inches = (rows as u32 * line_spacing).div_ceil(UNITS_PER_INCH);
+            PageLength::Inches(inches.clamp(1, MAX_PAGE_LENGTH_INCHES) as u8)
+        }
+    }
+
+    /// Returns how many rows fit in this page length at `line_spacing`
+    /// (1/360-inch units), saturating at `u16::MAX`.
+    pub(crate) fn rows(self, line_spacing: u32) -> u16 {
+        match self {
+            PageLength::Lines(lines) => lines as u16,
+            PageLength::Inches(inches) => (inches as u32 * UNITS_PER_INCH)
+                .checked_div(line_spacing)
+                .unwrap_or(0)
+                .try_into()
+                .unwrap_or(u16::MAX),
+        }
+    }
+
+    /// Emits the ESC C command for this page length.
+    pub(crate) fn write(self, output: &mut Vec<u8>) {
+        match self {
+            PageLength::Lines(lines) => {
+                output.extend_from_slice(ESC_PAGE_LENGTH);
+                output.push(lines);
+            }
+            PageLength::Inches(inches) => {
+                output.extend_from_slice(ESC_PAGE_LENGTH_INCHES);
+                output.push(inches);
+            }
+        }
+    }
 }
 
 /// Renders a single page to the output buffer.
 ///
-/// EPSON LQ-2090II Configuration:
-/// - Renders 50 lines (lines 0-49)
-/// - Line 50 is reserved by printer (margin/boundary)
-fn render_page(page: &Page, output: &mut Vec<u8>) {
+/// Every row the page holds is emitted, terminated by CR LF.
+fn render_page(page: &Page, encoder: &Encoder, skip: Option<Skip>, output: &mut Vec<u8>) {
     let mut state = RenderState::new();
 
-    // Render 50 lines (0-49), skip line 50 (reserved)
-    for y in 0..50 {
-        render_line(&page.cells()[y as usize], &mut state, output);
+    for row in page.rows() {
+        render_line(row, encoder, skip, &mut state, output);
 
         // Line termination
         output.push(CR);
@@ -49,26 +279,252 @@ fn render_page(page: &Page, output: &mut Vec<u8>) {
     }
 }
 
-/// Renders a single line of 160 cells.
-fn render_line(cells: &[Cell; 160], state: &mut RenderState, output: &mut Vec<u8>) {
-    for cell in cells.iter() {
+/// Minimum run of blank rows fed with ESC J instead of one LF per row
+const MIN_FEED_RUN: u32 = 4;
+
+/// Renders a single page, omitting blanks that print nothing.
+///
+/// Each row is cut after its last visible cell. Blank rows only advance the
+/// paper, and blank rows at the bottom of the page are left to the form feed
+/// that follows.
+fn render_page_compact(
+    page: &Page,
+    encoder: &Encoder,
+    skip: Option<Skip>,
+    line_spacing: u32,
+    output: &mut Vec<u8>,
+) {
+    let mut state = RenderState::new();
+    let mut blank_rows = 0;
+
+    for row in page.rows() {
+        let visible = visible_len(row);
+        if visible == 0 {
+            blank_rows += 1;
+            continue;
+        }
+
+        feed_blank_rows(blank_rows, line_spacing, output);
+        blank_rows = 0;
+
+        render_line(&row[..visible], encoder, skip, &mut state, output);
+        output.push(CR);
+        output.push(LF);
+        state.reset(output);
+    }
+}
+
+/// Returns the length of the row up to and including its last visible cell.
+fn visible_len(cells: &[Cell]) -> usize {
+    cells
+        .iter()
+        .rposition(is_visible)
+        .map_or(0, |last| last + 1)
+}
+
+/// Returns true if printing the cell puts ink on the paper.
+///
+/// Spaces print nothing unless underlined or struck through; covered cells
+/// print as unstyled spaces.
+fn is_visible(cell: &Cell) -> bool {
+    let style = cell.style();
+    !cell.is_covered() && (cell.character() != ' ' || style.underline() || style.strikethrough())
+}
+
+/// Horizontal skip settings resolved for the document's pitch.
+#[derive(Copy, Clone, Debug)]
+struct Skip {
+    mode: HorizontalSkip,
+    /// Minimum run of blank cells considered for a skip
+    min_run: usize,
+    /// Column width in 1/360-inch units
+    char_width: u32,
+}
+
+impl Skip {
+    /// Returns the command moving the print head from column `from` to
+    /// column `to`, or None if the distance does not fit the commands
+    /// allowed by the mode.
+    ///
+    /// Both commands take 4 bytes, so [`HorizontalSkip::Auto`] prefers
+    /// `ESC $` and only falls back to `ESC \` when the absolute position is
+    /// out of range.
+    fn command(self, from: usize, to: usize) -> Option<[u8; 4]> {
+        let absolute = || {
+            let units = to * self.char_width as usize;
+            (units <= u16::MAX as usize).then_some((ESC_ABSOLUTE_POSITION, units))
+        };
+        let relative = || {
+            let units = (to - from) * self.char_width as usize;
+            (units <= i16::MAX as usize).then_some((ESC_RELATIVE_POSITION, units))
+        };
+        let (command, units) = match self.mode {
+            HorizontalSkip::Absolute => absolute(),
+            HorizontalSkip::Relative => relative(),
+            HorizontalSkip::Auto => absolute().or_else(relative),
+        }?;
+
+        let [low, high] = (units as u16).to_le_bytes();
+        Some([command[0], command[1], low, high])
+    }
+}
+
+/// Advances the paper over `count` blank rows.
+///
+/// Short runs use one LF each. Runs of at least [`MIN_FEED_RUN`] rows are fed
+/// with ESC J n (n/180 inch) when the line spacing is a whole number of
+/// 1/180-inch units, which covers up to 255/180 inch per command.
+fn feed_blank_rows(count: u32, line_spacing: u32, output: &mut Vec<u8>) {
+    let distance = count * line_spacing;
+    if count >= MIN_FEED_RUN && distance.is_multiple_of(2) {
+        let mut remaining = distance / 2;
+        while remaining > 0 {
+            let n = remaining.min(u8::MAX as u32);
+            output.extend_from_slice(ESC_FEED_180);
+            output.push(n as u8);
+            remaining -= n;
+        }
+    } else {
+        output.extend((0..count).map(|_| LF));
+    }
+}
+
+/// Renders a single line of cells.
+///
+/// A double-width glyph is emitted once and advances the print head two
+/// columns, so the covered cell to its right emits nothing. Cells covered by
+/// a double-height glyph from the row above print as unstyled spaces.
+/// Characters are mapped to printer bytes by `encoder`.
+///
+/// With `skip`, each run of blank cells followed by visible text is priced
+/// both ways: printed as spaces, and as a print head move. Both prices
+/// include the style switches the run forces before the next visible cell,
+/// and the cheaper output is emitted (spaces on a tie). Underline and
+/// strikethrough are turned off before a move, so the paper stays blank as
+/// with space padding.
+fn render_line(
+    cells: &[Cell],
+    encoder: &Encoder,
+    skip: Option<Skip>,
+    state: &mut RenderState,
+    output: &mut Vec<u8>,
+) {
+    let mut after_wide_glyph = false;
+    // Blank runs starting before this column were already priced
+    let mut next_skip_check = 0;
+    let mut x = 0;
+    while x < cells.len() {
+        if after_wide_glyph && cells[x].is_wide_tail() {
+            // Covered half of the glyph just printed
+            after_wide_glyph = false;
+            x += 1;
+            continue;
+        }
+
+        if let Some(skip) = skip.filter(|_| x >= next_skip_check && !is_visible(&cells[x])) {
+            let end = cells[x..]
+                .iter()
+                .position(is_visible)
+                .map_or(cells.len(), |offset| x + offset);
+            next_skip_check = end;
+
+            // Trailing blanks are never skipped (nothing follows them)
+            let command = (end < cells.len() && end - x >= skip.min_run)
+                .then(|| skip.command(x, end))
+                .flatten();
+            if let Some(command) = command {
+                let next_style = glyph_style(cells, end);
+
+                let mut spaces = Vec::new();
+                let mut spaces_state = state.clone();
+                let mut wide = after_wide_glyph;
+                for column in x..end {
+                    wide = emit_cell(cells, column, wide, encoder, &mut spaces_state, &mut spaces);
+                }
+                spaces_state.transition_to(next_style, &mut spaces);
+
+                let mut moved = Vec::new();
+                let mut moved_state = state.clone();
+                let blank_style = state
+                    .style()
+                    .set(StyleFlags::UNDERLINE, false)
+                    .set(StyleFlags::STRIKETHROUGH, false);
+                moved_state.transition_to(blank_style, &mut moved);
+                moved.extend_from_slice(&command);
+                let move_len = moved.len();
+                moved_state.clone().transition_to(next_style, &mut moved);
+
+                if moved.len() < spaces.len() {
+                    // The next cell switches to its own style as usual
+                    output.extend_from_slice(&moved[..move_len]);
+                    *state = moved_state;
+                    after_wide_glyph = false;
+                    x = end;
+                    continue;
+                }
+            }
+        }
+
+        after_wide_glyph = emit_cell(cells, x, after_wide_glyph, encoder, state, output);
+        x += 1;
+    }
+}
+
+/// Emits the cell at column `x`, returning true if it is a double-width
+/// glyph whose covered cell follows.
+///
+/// `after_wide_glyph` tells whether the previous cell was such a glyph, in
+/// which case this cell is its covered half and emits nothing.
+fn emit_cell(
+    cells: &[Cell],
+    x: usize,
+    after_wide_glyph: bool,
+    encoder: &Encoder,
+    state: &mut RenderState,
+    output: &mut Vec<u8>,
+) -> bool {
+    let cell = &cells[x];
+    if cell.is_wide_tail() && after_wide_glyph {
+        false
+    } else if cell.is_covered() {
+        // Lower half of a double-height glyph (or an orphaned tail)
+        state.transition_to(StyleFlags::NONE, output);
+        output.push(b' ');
+        false
+    } else {
+        let style = glyph_style(cells, x);
         // Transition to cell's style
-        state.transition_to(cell.style(), output);
+        state.transition_to(style, output);
 
         // Emit character (empty cells → space)
-        output.push(cell.character() as u8);
+        output.push(encoder.encode(cell.character()));
+        style.double_width()
+    }
+}
+
+/// Returns the style the uncovered cell at column `x` prints in.
+///
+/// A double-width glyph whose covered cell was replaced prints at normal
+/// width, keeping the following columns in place.
+fn glyph_style(cells: &[Cell], x: usize) -> StyleFlags {
+    let style = cells[x].style();
+    if style.double_width() && cells.get(x + 1).is_some_and(|next| !next.is_wide_tail()) {
+        style.set(StyleFlags::DOUBLE_WIDTH, false)
+    } else {
+        style
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cell::StyleFlags;
+
+    const SIXTH_INCH: u32 = 60;
 
     #[test]
     fn test_render_document_empty() {
         let document = Document::builder().build();
-        let bytes = render_document(&document);
+        let bytes = render_document(&document, RenderOptions::default());
 
         // Should still have initialization
         assert!(bytes.starts_with(ESC_RESET));
@@ -85,7 +541,7 @@ mod tests {
         builder.add_page(page);
         let document = builder.build();
 
-        let bytes = render_document(&document);
+        let bytes = render_document(&document, RenderOptions::default());
 
         // Should have initialization
         assert!(bytes.starts_with(ESC_RESET));
@@ -103,7 +559,7 @@ mod tests {
         builder.add_page(Page::builder().build());
         let document = builder.build();
 
-        let bytes = render_document(&document);
+        let bytes = render_document(&document, RenderOptions::default());
 
         // Should have 3 form-feeds
         let ff_count = bytes.iter().filter(|&&b| b == FF).count();
@@ -117,7 +573,7 @@ mod tests {
         let page = page_builder.build();
 
         let mut output = Vec::new();
-        render_page(&page, &mut output);
+        render_page(&page, &Encoder::default(), None, &mut output);
 
         // Convert to string for easier verification
         let text = String::from_utf8_lossy(&output);
@@ -134,7 +590,13 @@ mod tests {
         let mut state = RenderState::new();
         let mut output = Vec::new();
 
-        render_line(&page.cells()[0], &mut state, &mut output);
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            None,
+            &mut state,
+            &mut output,
+        );
 
         // Should contain bold codes
         assert!(output.windows(ESC_BOLD_ON.len()).any(|w| w == ESC_BOLD_ON));
@@ -145,6 +607,281 @@ mod tests {
             .any(|w| w == ESC_UNDERLINE_ON));
     }
 
+    #[test]
+    fn test_page_length_from_geometry() {
+        let mut builder = Document::builder();
+        builder.add_page(Page::builder_with_geometry(PageGeometry::new(80, 36)).build());
+        let document = builder.build();
+
+        let bytes = render_document(&document, RenderOptions::default());
+
+        assert_eq!(&bytes[3..6], &[0x1B, 0x43, 36]);
+        let lf_count = bytes.iter().filter(|&&b| b == LF).count();
+        assert_eq!(lf_count, 36);
+    }
+
+    #[test]
+    fn test_page_length_changes_between_pages() {
+        let mut builder = Document::builder();
+        builder.add_page(Page::builder().build());
+        builder.add_page(Page::builder_with_geometry(PageGeometry::new(136, 36)).build());
+        let document = builder.build();
+
+        let bytes = render_document(&document, RenderOptions::default());
+
+        assert_eq!(&bytes[3..6], &[0x1B, 0x43, 51]);
+        let first_ff = bytes.iter().position(|&b| b == FF).unwrap();
+        assert_eq!(&bytes[first_ff + 1..first_ff + 4], &[0x1B, 0x43, 36]);
+    }
+
+    #[test]
+    fn test_render_document_pitch() {
+        let cases: [(Pitch, &[u8]); 5] = [
+            (Pitch::Cpi10, &[0x1B, 0x50]),
+            (Pitch::Cpi12, &[0x1B, 0x4D]),
+            (Pitch::Cpi15, &[0x1B, 0x67]),
+            (Pitch::Condensed17, &[0x0F]),
+            (Pitch::Condensed20, &[0x1B, 0x4D, 0x0F]),
+        ];
+
+        for (pitch, command) in cases {
+            let mut builder = Document::builder();
+            builder.pitch(pitch);
+            let bytes = render_document(&builder.build(), RenderOptions::default());
+
+            assert!(bytes.starts_with(ESC_RESET));
+            assert_eq!(&bytes[2..2 + command.len()], command, "{:?}", pitch);
+            assert_eq!(&bytes[2 + command.len()..][..2], ESC_PAGE_LENGTH);
+        }
+    }
+
+    #[test]
+    fn test_render_document_line_spacing() {
+        let cases: [(LineSpacing, &[u8]); 5] = [
+            (LineSpacing::SixthInch, &[]),
+            (LineSpacing::EighthInch, &[0x1B, 0x30]),
+            (LineSpacing::SevenSeventySecondInch, &[0x1B, 0x2B, 35]),
+            (LineSpacing::Units180(20), &[0x1B, 0x33, 20]),
+            (LineSpacing::Units360(45), &[0x1B, 0x2B, 45]),
+        ];
+
+        for (line_spacing, command) in cases {
+            let mut builder = Document::builder();
+            builder.line_spacing(line_spacing);
+            let bytes = render_document(&builder.build(), RenderOptions::default());
+
+            // ESC @ SI, then spacing, then page length
+            assert_eq!(&bytes[3..3 + command.len()], command, "{:?}", line_spacing);
+            assert_eq!(&bytes[3 + command.len()..][..2], ESC_PAGE_LENGTH);
+        }
+    }
+
+    #[test]
+    fn test_page_length_uses_line_spacing() {
+        // 160 rows at 1/8" = 20 inches
+        let geometry = PageGeometry::new(160, 160);
+        assert_eq!(
+            PageLength::for_geometry(geometry, LineSpacing::EighthInch.units()),
+            PageLength::Inches(20)
+        );
+
+        let mut builder = Document::builder();
+        builder.line_spacing(LineSpacing::EighthInch);
+        builder.add_page(Page::builder_with_geometry(geometry).build());
+        let bytes = render_document(&builder.build(), RenderOptions::default());
+        assert_eq!(&bytes[3..9], &[0x1B, 0x30, 0x1B, 0x43, 0x00, 20]);
+    }
+
+    #[test]
+    fn test_page_length_lines() {
+        let geometry = PageGeometry::new(160, 127);
+        assert_eq!(
+            PageLength::for_geometry(geometry, SIXTH_INCH),
+            PageLength::Lines(127)
+        );
+    }
+
+    #[test]
+    fn test_page_length_inches_for_long_pages() {
+        // 132 rows at 1/6" = 22 inches
+        let geometry = PageGeometry::new(160, 132);
+        let length = PageLength::for_geometry(geometry, SIXTH_INCH);
+        assert_eq!(length, PageLength::Inches(22));
+
+        let mut output = Vec::new();
+        length.write(&mut output);
+        assert_eq!(output, [0x1B, 0x43, 0x00, 22]);
+
+        // 130 rows at 1/6" = 21.67 inches, rounded up
+        let geometry = PageGeometry::new(160, 130);
+        assert_eq!(
+            PageLength::for_geometry(geometry, SIXTH_INCH),
+            PageLength::Inches(22)
+        );
+    }
+
+    #[test]
+    fn test_page_length_capped_at_22_inches() {
+        // 100 rows at 1/3" = 33 inches: too long for ESC C n
+        let geometry = PageGeometry::new(160, 100);
+        assert_eq!(
+            PageLength::for_geometry(geometry, 120),
+            PageLength::Inches(22)
+        );
+
+        // 200 rows at 1/6" = 33.3 inches: only the 132 rows on the form
+        // are emitted
+        let mut builder = Document::builder();
+        builder.add_page(Page::builder_with_geometry(PageGeometry::new(160, 200)).build());
+        let bytes = render_document(&builder.build(), RenderOptions::default());
+        assert!(bytes
+            .windows(4)
+            .any(|window| window == [0x1B, 0x43, 0x00, 22]));
+        assert_eq!(bytes.windows(2).filter(|w| w == b"\r\n").count(), 132);
+    }
+
+    #[test]
+    fn test_render_page_emits_every_row() {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 50, "Last row", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let mut output = Vec::new();
+        render_page(&page, &Encoder::default(), None, &mut output);
+
+        let lf_count = output.iter().filter(|&&b| b == LF).count();
+        assert_eq!(lf_count, 51);
+
+        let text = String::from_utf8_lossy(&output);
+        let last_line = text.split("\r\n").nth(50).unwrap();
+        assert!(last_line.starts_with("Last row"));
+    }
+
+    #[test]
+    fn test_render_line_uses_geometry_width() {
+        let page = Page::builder_with_geometry(PageGeometry::new(80, 10)).build();
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            None,
+            &mut state,
+            &mut output,
+        );
+
+        assert_eq!(output.len(), 80);
+    }
+
+    #[test]
+    fn test_render_line_double_width_keeps_columns() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(10, 1));
+        page_builder.write_str(0, 0, "AB", StyleFlags::DOUBLE_WIDTH);
+        page_builder.write_str(4, 0, "cdef", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            None,
+            &mut state,
+            &mut output,
+        );
+
+        // Two double-width glyphs (4 columns) + 6 normal columns
+        let mut expected = Vec::new();
+        expected.extend_from_slice(ESC_DOUBLE_WIDTH_ON);
+        expected.extend_from_slice(b"AB");
+        expected.extend_from_slice(ESC_DOUBLE_WIDTH_OFF);
+        expected.extend_from_slice(b"cdef  ");
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_render_page_double_height_lower_row() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(4, 2));
+        page_builder.write_at(1, 0, 'H', StyleFlags::DOUBLE_HEIGHT);
+        page_builder.write_at(2, 1, 'x', StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let mut output = Vec::new();
+        render_page(&page, &Encoder::default(), None, &mut output);
+
+        let mut expected = Vec::new();
+        expected.push(b' ');
+        expected.extend_from_slice(ESC_DOUBLE_HEIGHT_ON);
+        expected.push(b'H');
+        expected.extend_from_slice(ESC_DOUBLE_HEIGHT_OFF);
+        expected.extend_from_slice(b"  \r\n");
+        expected.extend_from_slice(b"  x \r\n");
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_render_line_orphaned_wide_tail_prints_space() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(4, 1));
+        page_builder.write_at(0, 0, 'W', StyleFlags::DOUBLE_WIDTH);
+        page_builder.write_at(0, 0, 'n', StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            None,
+            &mut state,
+            &mut output,
+        );
+
+        assert_eq!(output, b"n   ");
+    }
+
+    #[test]
+    fn test_render_line_overwritten_wide_tail_keeps_columns() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(4, 1));
+        page_builder.write_at(0, 0, 'W', StyleFlags::DOUBLE_WIDTH);
+        page_builder.write_at(1, 0, 'n', StyleFlags::NONE);
+        page_builder.write_at(3, 0, '|', StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            None,
+            &mut RenderState::new(),
+            &mut output,
+        );
+        assert_eq!(output, b" n |");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_render_line_wide_glyph_without_tail_prints_single_width() {
+        // Deserialized grids may hold a glyph whose covered cell was replaced
+        let cells = vec![
+            Cell::new('W', StyleFlags::DOUBLE_WIDTH),
+            Cell::new('n', StyleFlags::NONE),
+            Cell::EMPTY,
+            Cell::new('|', StyleFlags::NONE),
+        ];
+        let page = Page::from_cells(PageGeometry::new(4, 1), cells.into_boxed_slice());
+
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            None,
+            &mut RenderState::new(),
+            &mut output,
+        );
+        assert_eq!(output, b"Wn |");
+    }
+
     #[test]
     fn test_deterministic_output() {
         let mut page_builder = Page::builder();
@@ -155,10 +892,394 @@ mod tests {
         builder.add_page(page.clone());
         let document = builder.build();
 
-        let bytes1 = render_document(&document);
-        let bytes2 = render_document(&document);
+        let bytes1 = render_document(&document, RenderOptions::default());
+        let bytes2 = render_document(&document, RenderOptions::default());
 
         // Byte-for-byte identical
         assert_eq!(bytes1, bytes2);
     }
+
+    #[test]
+    fn test_render_document_default_charset_emits_nothing() {
+        let document = Document::builder().build();
+        let bytes = render_document(&document, RenderOptions::default());
+
+        assert!(!bytes.windows(3).any(|w| w == ESC_SELECT_TABLE_SLOT_1));
+        assert!(!bytes.windows(2).any(|w| w == ESC_INTERNATIONAL_CHARSET));
+    }
+
+    #[test]
+    fn test_render_document_character_table() {
+        let mut builder = Document::builder();
+        builder.character_table(CharacterTable::Pc858);
+        let bytes = render_document(&builder.build(), RenderOptions::default());
+
+        // ESC @, SI, ESC ( t 3 0 1 13 32, ESC t 1, ESC C 51
+        assert_eq!(
+            &bytes[3..],
+            [0x1B, 0x28, 0x74, 0x03, 0x00, 0x01, 13, 32, 0x1B, 0x74, 0x01, 0x1B, 0x43, 51]
+        );
+    }
+
+    #[test]
+    fn test_render_document_international_charset() {
+        let mut builder = Document::builder();
+        builder
+            .character_table(CharacterTable::Pc850)
+            .international_charset(InternationalCharset::Germany);
+        let bytes = render_document(&builder.build(), RenderOptions::default());
+
+        // Table selection precedes ESC R, page length follows
+        assert_eq!(&bytes[14..], [0x1B, 0x52, 2, 0x1B, 0x43, 51]);
+    }
+
+    #[test]
+    fn test_render_line_encodes_characters() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(4, 1));
+        page_builder.write_str(0, 0, "é€ü", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let encoder = Encoder::new(CharacterTable::Pc858, InternationalCharset::Usa, '?');
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &encoder,
+            None,
+            &mut state,
+            &mut output,
+        );
+
+        assert_eq!(output, [0x82, 0xD5, 0x81, b' ']);
+    }
+
+    #[test]
+    fn test_render_line_unmappable_uses_fallback() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(3, 1));
+        page_builder.write_str(0, 0, "a€b", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        let encoder = Encoder::new(CharacterTable::Pc850, InternationalCharset::Usa, '#');
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &encoder,
+            None,
+            &mut state,
+            &mut output,
+        );
+
+        assert_eq!(output, b"a#b");
+    }
+
+    fn compact_page(page: &Page) -> Vec<u8> {
+        let mut output = Vec::new();
+        render_page_compact(page, &Encoder::default(), None, SIXTH_INCH, &mut output);
+        output
+    }
+
+    #[test]
+    fn test_render_page_compact_trims_trailing_blanks() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(10, 2));
+        page_builder.write_str(0, 0, "ab  c", StyleFlags::NONE);
+        page_builder.write_str(0, 1, "x", StyleFlags::BOLD);
+        let page = page_builder.build();
+
+        assert_eq!(compact_page(&page), b"ab  c\r\n\x1bEx\r\n\x1bF");
+    }
+
+    #[test]
+    fn test_render_page_compact_keeps_visible_blanks() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(10, 1));
+        page_builder.write_str(0, 0, "a  ", StyleFlags::UNDERLINE);
+        page_builder.write_str(5, 0, " ", StyleFlags::BOLD);
+        let page = page_builder.build();
+
+        // Underlined spaces print a line; the bold space prints nothing
+        assert_eq!(compact_page(&page), b"\x1b-\x01a  \r\n\x1b-\x00");
+    }
+
+    #[test]
+    fn test_render_page_compact_blank_rows() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(10, 12));
+        page_builder.write_str(0, 0, "a", StyleFlags::NONE);
+        page_builder.write_str(0, 3, "b", StyleFlags::NONE);
+        page_builder.write_str(0, 9, "c", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // 2 blank rows as LF, 5 blank rows as ESC J 150, trailing rows omitted
+        assert_eq!(compact_page(&page), b"a\r\n\n\nb\r\n\x1bJ\x96c\r\n");
+    }
+
+    #[test]
+    fn test_feed_blank_rows_chunks() {
+        let mut output = Vec::new();
+        feed_blank_rows(10, SIXTH_INCH, &mut output);
+        assert_eq!(output, [0x1B, 0x4A, 255, 0x1B, 0x4A, 45]);
+
+        // Odd 1/360-inch spacing cannot be expressed in 1/180 inch
+        let mut output = Vec::new();
+        feed_blank_rows(5, 45, &mut output);
+        assert_eq!(output, [LF; 5]);
+    }
+
+    #[test]
+    fn test_render_page_compact_blank_page() {
+        let page = Page::builder().build();
+        assert!(compact_page(&page).is_empty());
+    }
+
+    #[test]
+    fn test_render_page_compact_double_width_last() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(6, 1));
+        page_builder.write_str(0, 0, "A", StyleFlags::DOUBLE_WIDTH);
+        let page = page_builder.build();
+
+        assert_eq!(compact_page(&page), b"\x1bW\x01A\r\n\x1bW\x00");
+    }
+
+    #[test]
+    fn test_render_document_compact_init_unchanged() {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, "Hi", StyleFlags::NONE);
+        let mut builder = Document::builder();
+        builder.add_page(page_builder.build());
+        let document = builder.build();
+
+        let full = render_document(&document, RenderOptions::default());
+        let compact = render_document(&document, RenderOptions::new().with_compact(true));
+        assert_eq!(full[..6], compact[..6]);
+        assert_eq!(&compact[6..], b"Hi\r\n\x0C");
+    }
+
+    fn skip(mode: HorizontalSkip) -> Option<Skip> {
+        Some(Skip {
+            mode,
+            min_run: 5,
+            char_width: 21,
+        })
+    }
+
+    fn skipped_line(page: &Page, skip: Option<Skip>) -> Vec<u8> {
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+        render_line(
+            page.row(0).unwrap(),
+            &Encoder::default(),
+            skip,
+            &mut state,
+            &mut output,
+        );
+        output
+    }
+
+    #[test]
+    fn test_render_line_absolute_skip() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(20, 1));
+        page_builder.write_str(0, 0, "ab", StyleFlags::NONE);
+        page_builder.write_str(10, 0, "cd", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // Column 10 is 210/360 inch from the margin; trailing blanks stay
+        let output = skipped_line(&page, skip(HorizontalSkip::Absolute));
+        assert_eq!(&output[..8], b"ab\x1b$\xd2\x00cd");
+        assert_eq!(output.len(), 8 + 8);
+    }
+
+    #[test]
+    fn test_render_line_relative_skip() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(12, 1));
+        page_builder.write_str(0, 0, "ab", StyleFlags::NONE);
+        page_builder.write_str(10, 0, "cd", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // 8 columns of 21/360 inch
+        let output = skipped_line(&page, skip(HorizontalSkip::Relative));
+        assert_eq!(output, b"ab\x1b\\\xa8\x00cd");
+    }
+
+    #[test]
+    fn test_render_line_short_runs_not_skipped() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(12, 1));
+        page_builder.write_str(0, 0, "a    b     c", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // The 4-blank run stays as spaces, the 5-blank run is skipped
+        let output = skipped_line(&page, skip(HorizontalSkip::Absolute));
+        assert_eq!(output, b"a    b\x1b$\xe7\x00c");
+    }
+
+    #[test]
+    fn test_render_line_skip_keeps_underlined_blanks() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(12, 1));
+        page_builder.write_str(0, 0, "a", StyleFlags::NONE);
+        page_builder.write_str(1, 0, "      ", StyleFlags::UNDERLINE);
+        page_builder.write_str(7, 0, "b", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        assert_eq!(
+            skipped_line(&page, skip(HorizontalSkip::Absolute)),
+            skipped_line(&page, None)
+        );
+    }
+
+    #[test]
+    fn test_render_line_skip_switches_to_run_style() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(12, 1));
+        page_builder.write_str(0, 0, "a", StyleFlags::UNDERLINE);
+        page_builder.write_str(10, 0, "b", StyleFlags::UNDERLINE);
+        let page = page_builder.build();
+
+        // Underline is turned off before the skip, as for the blank cells
+        let output = skipped_line(&page, skip(HorizontalSkip::Absolute));
+        assert_eq!(
+            output,
+            b"\x1b-\x01a\x1b-\x00\x1b$\xd2\x00\x1b-\x01b\x1b-\x00 "
+        );
+    }
+
+    #[test]
+    fn test_render_line_skip_after_double_width() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(12, 1));
+        page_builder.write_str(0, 0, "A", StyleFlags::DOUBLE_WIDTH);
+        page_builder.write_str(9, 0, "b", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // The glyph covers columns 0-1, so the run is columns 2-8; double
+        // width does not affect the move and is turned off before the text
+        let output = skipped_line(&page, skip(HorizontalSkip::Relative));
+        assert_eq!(output, b"\x1bW\x01A\x1b\\\x93\x00\x1bW\x00b  ");
+    }
+
+    #[test]
+    fn test_render_line_spaces_beat_skip() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(10, 1));
+        page_builder.write_str(0, 0, "A   B", StyleFlags::DOUBLE_WIDTH);
+        let page = page_builder.build();
+
+        // Six blank columns print as three double-width spaces, one byte
+        // less than ESC $ (and no style switch either way)
+        let output = skipped_line(&page, skip(HorizontalSkip::Absolute));
+        assert_eq!(output, b"\x1bW\x01A   B");
+        assert_eq!(output, skipped_line(&page, None)[..output.len()]);
+    }
+
+    #[test]
+    fn test_render_line_skip_saves_style_switches() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(7, 1));
+        page_builder.write_str(0, 0, "ab", StyleFlags::BOLD);
+        page_builder.write_str(5, 0, "cd", StyleFlags::BOLD);
+        let page = page_builder.build();
+        let any_run = Some(Skip {
+            mode: HorizontalSkip::Absolute,
+            min_run: 1,
+            char_width: 21,
+        });
+
+        // Spaces would need ESC F, three spaces and ESC E (7 bytes); the
+        // move keeps bold on (4 bytes)
+        let output = skipped_line(&page, any_run);
+        assert_eq!(output, b"\x1bEab\x1b$\x69\x00cd");
+    }
+
+    #[test]
+    fn test_render_line_auto_skip_falls_back_to_relative() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(3300, 1));
+        page_builder.write_str(0, 0, "a", StyleFlags::NONE);
+        page_builder.write_str(2000, 0, "b", StyleFlags::NONE);
+        page_builder.write_str(3200, 0, "c", StyleFlags::NONE);
+        let page = page_builder.build();
+
+        // Column 3200 is 67200/360 inch from the margin, beyond ESC $
+        let output = skipped_line(&page, skip(HorizontalSkip::Auto));
+        assert!(output.starts_with(b"a\x1b$\x10\xa4b\x1b\\\x5b\x62c"));
+        assert!(skip(HorizontalSkip::Absolute)
+            .unwrap()
+            .command(2001, 3200)
+            .is_none());
+    }
+
+    #[test]
+    fn test_render_document_skip_sets_unit() {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, "Hi", StyleFlags::NONE);
+        let mut builder = Document::builder();
+        builder.add_page(page_builder.build());
+        let document = builder.build();
+
+        let options = RenderOptions::new().with_horizontal_skip(HorizontalSkip::Absolute);
+        let bytes = render_document(&document, options);
+        assert_eq!(&bytes[3..9], ESC_UNIT_360);
+        assert_eq!(&bytes[9..12], [0x1B, 0x43, 51]);
+
+        let bytes = render_document(&document, RenderOptions::default());
+        assert!(!bytes.windows(6).any(|w| w == ESC_UNIT_360));
+    }
+
+    #[test]
+    fn test_write_document_matches_render() {
+        let mut builder = Document::builder();
+        for i in 0..3 {
+            let mut page_builder = Page::builder_with_geometry(PageGeometry::new(20, 10 + i));
+            page_builder.write_str(0, i, "Streamed", StyleFlags::BOLD);
+            builder.add_page(page_builder.build());
+        }
+        let document = builder.build();
+
+        for options in [
+            RenderOptions::default(),
+            RenderOptions::new()
+                .with_compact(true)
+                .with_horizontal_skip(HorizontalSkip::Relative),
+        ] {
+            let mut written = Vec::new();
+            write_document(&document, options, &mut written).unwrap();
+            assert_eq!(written, render_document(&document, options));
+        }
+    }
+
+    #[test]
+    fn test_write_page_matches_single_page_document() {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(40, 30));
+        page_builder.write_str(0, 0, "Alone", StyleFlags::UNDERLINE);
+        let page = page_builder.build();
+
+        let mut builder = Document::builder();
+        builder.add_page(page.clone());
+        let expected = render_document(&builder.build(), RenderOptions::default());
+
+        let mut written = Vec::new();
+        write_page(&page, &mut written).unwrap();
+        assert_eq!(written, expected);
+    }
+
+    /// Writer recording the size of every write call
+    struct ChunkWriter(Vec<usize>);
+
+    impl Write for ChunkWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.push(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_document_writes_page_by_page() {
+        let mut builder = Document::builder();
+        builder.add_page(Page::builder().build());
+        builder.add_page(Page::builder().build());
+        let document = builder.build();
+
+        let mut writer = ChunkWriter(Vec::new());
+        write_document(&document, RenderOptions::default(), &mut writer).unwrap();
+
+        // Initialization, then one chunk per page
+        assert_eq!(writer.0.len(), 3);
+        assert_eq!(writer.0[0], 6);
+    }
 }
@@ -3,12 +3,39 @@
 use super::constants::*;
 use crate::cell::StyleFlags;
 
+/// Vertical script position (superscript and subscript are exclusive).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Script {
+    Normal,
+    Superscript,
+    Subscript,
+}
+
+impl Script {
+    fn of(style: StyleFlags) -> Self {
+        if style.superscript() {
+            Script::Superscript
+        } else if style.subscript() {
+            Script::Subscript
+        } else {
+            Script::Normal
+        }
+    }
+}
+
 /// Tracks the current style state during rendering.
 ///
 /// Minimizes ESC/P code emission by only outputting state changes.
+#[derive(Clone)]
 pub(crate) struct RenderState {
     bold: bool,
     underline: bool,
+    italic: bool,
+    double_strike: bool,
+    script: Script,
+    strikethrough: bool,
+    double_width: bool,
+    double_height: bool,
 }
 
 impl RenderState {
@@ -17,47 +44,120 @@ impl RenderState {
         RenderState {
             bold: false,
             underline: false,
+            italic: false,
+            double_strike: false,
+            script: Script::Normal,
+            strikethrough: false,
+            double_width: false,
+            double_height: false,
         }
     }
 
     /// Transitions to the target style state, emitting ESC/P codes as needed.
     ///
-    /// Only emits codes when the state actually changes.
+    /// Only emits codes when the state actually changes. Switching directly
+    /// between superscript and subscript emits a single ESC S n.
     pub(crate) fn transition_to(&mut self, target: StyleFlags, output: &mut Vec<u8>) {
-        let target_bold = target.bold();
-        let target_underline = target.underline();
-
         // Bold transition
-        if target_bold != self.bold {
-            if target_bold {
-                output.extend_from_slice(ESC_BOLD_ON);
-            } else {
-                output.extend_from_slice(ESC_BOLD_OFF);
-            }
-            self.bold = target_bold;
-        }
+        toggle(
+            &mut self.bold,
+            target.bold(),
+            ESC_BOLD_ON,
+            ESC_BOLD_OFF,
+            output,
+        );
 
         // Underline transition
-        if target_underline != self.underline {
-            if target_underline {
-                output.extend_from_slice(ESC_UNDERLINE_ON);
-            } else {
-                output.extend_from_slice(ESC_UNDERLINE_OFF);
-            }
-            self.underline = target_underline;
+        toggle(
+            &mut self.underline,
+            target.underline(),
+            ESC_UNDERLINE_ON,
+            ESC_UNDERLINE_OFF,
+            output,
+        );
+
+        // Italic transition
+        toggle(
+            &mut self.italic,
+            target.italic(),
+            ESC_ITALIC_ON,
+            ESC_ITALIC_OFF,
+            output,
+        );
+
+        // Double-strike transition
+        toggle(
+            &mut self.double_strike,
+            target.double_strike(),
+            ESC_DOUBLE_STRIKE_ON,
+            ESC_DOUBLE_STRIKE_OFF,
+            output,
+        );
+
+        // Superscript/subscript transition
+        let target_script = Script::of(target);
+        if target_script != self.script {
+            output.extend_from_slice(match target_script {
+                Script::Normal => ESC_SCRIPT_OFF,
+                Script::Superscript => ESC_SUPERSCRIPT_ON,
+                Script::Subscript => ESC_SUBSCRIPT_ON,
+            });
+            self.script = target_script;
         }
+
+        // Strikethrough transition
+        toggle(
+            &mut self.strikethrough,
+            target.strikethrough(),
+            ESC_STRIKETHROUGH_ON,
+            ESC_STRIKETHROUGH_OFF,
+            output,
+        );
+
+        // Double-width transition
+        toggle(
+            &mut self.double_width,
+            target.double_width(),
+            ESC_DOUBLE_WIDTH_ON,
+            ESC_DOUBLE_WIDTH_OFF,
+            output,
+        );
+
+        // Double-height transition
+        toggle(
+            &mut self.double_height,
+            target.double_height(),
+            ESC_DOUBLE_HEIGHT_ON,
+            ESC_DOUBLE_HEIGHT_OFF,
+            output,
+        );
+    }
+
+    /// Returns the style currently set on the printer.
+    pub(crate) fn style(&self) -> StyleFlags {
+        StyleFlags::NONE
+            .set(StyleFlags::BOLD, self.bold)
+            .set(StyleFlags::UNDERLINE, self.underline)
+            .set(StyleFlags::ITALIC, self.italic)
+            .set(StyleFlags::DOUBLE_STRIKE, self.double_strike)
+            .set(StyleFlags::SUPERSCRIPT, self.script == Script::Superscript)
+            .set(StyleFlags::SUBSCRIPT, self.script == Script::Subscript)
+            .set(StyleFlags::STRIKETHROUGH, self.strikethrough)
+            .set(StyleFlags::DOUBLE_WIDTH, self.double_width)
+            .set(StyleFlags::DOUBLE_HEIGHT, self.double_height)
     }
 
     /// Resets all styles to off, emitting necessary codes.
     pub(crate) fn reset(&mut self, output: &mut Vec<u8>) {
-        if self.bold {
-            output.extend_from_slice(ESC_BOLD_OFF);
-            self.bold = false;
-        }
-        if self.underline {
-            output.extend_from_slice(ESC_UNDERLINE_OFF);
-            self.underline = false;
-        }
+        self.transition_to(StyleFlags::NONE, output);
+    }
+}
+
+/// Switches a boolean style, emitting the on/off code only when it changes.
+fn toggle(current: &mut bool, target: bool, on: &[u8], off: &[u8], output: &mut Vec<u8>) {
+    if target != *current {
+        output.extend_from_slice(if target { on } else { off });
+        *current = target;
     }
 }
 
@@ -137,4 +237,100 @@ mod tests {
         assert!(!state.underline);
         assert!(!output.is_empty()); // Should have emitted OFF codes
     }
+
+    #[test]
+    fn test_transition_to_italic_and_double_strike() {
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+
+        state.transition_to(StyleFlags::ITALIC.with_double_strike(), &mut output);
+
+        assert!(state.italic);
+        assert!(state.double_strike);
+        assert_eq!(output, [ESC_ITALIC_ON, ESC_DOUBLE_STRIKE_ON].concat());
+    }
+
+    #[test]
+    fn test_transition_superscript_to_subscript() {
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+
+        state.transition_to(StyleFlags::SUPERSCRIPT, &mut output);
+        assert_eq!(output, ESC_SUPERSCRIPT_ON);
+        output.clear();
+
+        // Direct switch: no ESC T in between
+        state.transition_to(StyleFlags::SUBSCRIPT, &mut output);
+        assert_eq!(output, ESC_SUBSCRIPT_ON);
+        output.clear();
+
+        state.transition_to(StyleFlags::NONE, &mut output);
+        assert_eq!(output, ESC_SCRIPT_OFF);
+    }
+
+    #[test]
+    fn test_transition_to_strikethrough() {
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+
+        state.transition_to(StyleFlags::STRIKETHROUGH, &mut output);
+        assert_eq!(output, ESC_STRIKETHROUGH_ON);
+        output.clear();
+
+        state.transition_to(StyleFlags::STRIKETHROUGH, &mut output);
+        assert!(output.is_empty());
+
+        state.transition_to(StyleFlags::NONE, &mut output);
+        assert_eq!(output, ESC_STRIKETHROUGH_OFF);
+    }
+
+    #[test]
+    fn test_reset_extended_styles() {
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+
+        let style = StyleFlags::ITALIC
+            .with_double_strike()
+            .with_subscript()
+            .with_strikethrough();
+        state.transition_to(style, &mut output);
+        output.clear();
+
+        state.reset(&mut output);
+
+        assert!(!state.italic);
+        assert!(!state.double_strike);
+        assert_eq!(state.script, Script::Normal);
+        assert!(!state.strikethrough);
+        assert_eq!(
+            output,
+            [
+                ESC_ITALIC_OFF,
+                ESC_DOUBLE_STRIKE_OFF,
+                ESC_SCRIPT_OFF,
+                ESC_STRIKETHROUGH_OFF
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_transition_to_double_size() {
+        let mut state = RenderState::new();
+        let mut output = Vec::new();
+
+        state.transition_to(StyleFlags::DOUBLE_WIDTH.with_double_height(), &mut output);
+        assert!(state.double_width);
+        assert!(state.double_height);
+        assert_eq!(output, [ESC_DOUBLE_WIDTH_ON, ESC_DOUBLE_HEIGHT_ON].concat());
+        output.clear();
+
+        state.reset(&mut output);
+        assert!(!state.double_width);
+        assert!(!state.double_height);
+        assert_eq!(
+            output,
+            [ESC_DOUBLE_WIDTH_OFF, ESC_DOUBLE_HEIGHT_OFF].concat()
+        );
+    }
 }
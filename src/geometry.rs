@@ -0,0 +1,350 @@
+//! PageGeometry, Pitch and LineSpacing types describing the character grid of a page.
+
+use crate::escp::constants::UNITS_PER_INCH;
+
+/// Dimensions of a page grid in character columns and rows.
+///
+/// The default geometry is 160×51, the condensed-mode grid of the EPSON
+/// LQ-2090II on standard continuous forms. Other forms (80- or 136-column
+/// stock, short 6-inch paper) use their own geometry.
+///
+/// Both dimensions are clamped to at least 1 so that every page has at
+/// least one addressable cell.
+///
+/// The printer's form length tops out at 22 inches. A page whose rows run
+/// longer at the document's line spacing is cut to the rows that fit when
+/// it is added to a [`Document`](crate::Document).
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::PageGeometry;
+///
+/// let geometry = PageGeometry::new(80, 36);
+/// assert_eq!(geometry.columns(), 80);
+/// assert_eq!(geometry.rows(), 36);
+///
+/// assert_eq!(PageGeometry::default(), PageGeometry::LQ2090_CONDENSED);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PageGeometry {
+    columns: u16,
+    rows: u16,
+}
+
+impl PageGeometry {
+    /// EPSON LQ-2090II condensed-mode grid (160 columns × 51 rows)
+    pub const LQ2090_CONDENSED: PageGeometry = PageGeometry {
+        columns: 160,
+        rows: 51,
+    };
+
+    /// One inch in the 1/360-inch units used for paper measurements
+    pub const INCH: u32 = UNITS_PER_INCH;
+
+    /// Creates a geometry with the given number of columns and rows.
+    ///
+    /// Zero dimensions are clamped to 1.
+    pub const fn new(columns: u16, rows: u16) -> Self {
+        PageGeometry {
+            columns: if columns == 0 { 1 } else { columns },
+            rows: if rows == 0 { 1 } else { rows },
+        }
+    }
+
+    /// Creates a geometry whose column count follows from the pitch and the
+    /// printable width (in 1/360-inch units).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{PageGeometry, Pitch};
+    ///
+    /// // 8-inch printable width at 10 cpi
+    /// let geometry = PageGeometry::for_pitch(8 * PageGeometry::INCH, Pitch::Cpi10, 66);
+    /// assert_eq!(geometry.columns(), 80);
+    ///
+    /// // 13.6-inch printable width at 10 cpi
+    /// let geometry = PageGeometry::for_pitch(13 * PageGeometry::INCH + 216, Pitch::Cpi10, 66);
+    /// assert_eq!(geometry.columns(), 136);
+    /// ```
+    pub fn for_pitch(printable_width: u32, pitch: Pitch, rows: u16) -> Self {
+        Self::new(pitch.columns(printable_width), rows)
+    }
+
+    /// Creates a geometry for a form: columns follow from the pitch and the
+    /// printable width, rows from the form length divided by the line
+    /// spacing (both lengths in 1/360-inch units).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{LineSpacing, PageGeometry, Pitch};
+    ///
+    /// // 6-inch continuous stock at 1/8" spacing, 10 cpi on 8 inches
+    /// let geometry = PageGeometry::for_form(
+    ///     8 * PageGeometry::INCH,
+    ///     6 * PageGeometry::INCH,
+    ///     Pitch::Cpi10,
+    ///     LineSpacing::EighthInch,
+    /// );
+    /// assert_eq!(geometry.columns(), 80);
+    /// assert_eq!(geometry.rows(), 48);
+    /// ```
+    pub fn for_form(
+        printable_width: u32,
+        form_length: u32,
+        pitch: Pitch,
+        line_spacing: LineSpacing,
+    ) -> Self {
+        Self::new(
+            pitch.columns(printable_width),
+            line_spacing.rows(form_length),
+        )
+    }
+
+    /// Returns the number of character columns per row
+    #[inline]
+    pub const fn columns(self) -> u16 {
+        self.columns
+    }
+
+    /// Returns the number of rows per page
+    #[inline]
+    pub const fn rows(self) -> u16 {
+        self.rows
+    }
+
+    /// Returns the total number of cells in the grid
+    #[inline]
+    pub const fn cell_count(self) -> usize {
+        self.columns as usize * self.rows as usize
+    }
+
+    /// Returns true if (x, y) addresses a cell inside the grid
+    #[inline]
+    pub const fn contains(self, x: u16, y: u16) -> bool {
+        x < self.columns && y < self.rows
+    }
+}
+
+impl Default for PageGeometry {
+    fn default() -> Self {
+        Self::LQ2090_CONDENSED
+    }
+}
+
+/// Horizontal character pitch, selected once per document.
+///
+/// The default is condensed pica (17.14 cpi), which gives 160 columns on the
+/// LQ-2090II's standard printable width.
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{PageGeometry, Pitch};
+///
+/// assert_eq!(Pitch::default(), Pitch::Condensed17);
+/// assert_eq!(Pitch::Cpi12.columns(8 * PageGeometry::INCH), 96);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep")
+)]
+pub enum Pitch {
+    /// 10 characters per inch (pica, ESC P)
+    Cpi10,
+    /// 12 characters per inch (elite, ESC M)
+    Cpi12,
+    /// 15 characters per inch (ESC g)
+    Cpi15,
+    /// 17.14 characters per inch (condensed pica, SI)
+    #[default]
+    Condensed17,
+    /// 20 characters per inch (condensed elite, ESC M SI)
+    Condensed20,
+}
+
+impl Pitch {
+    /// Returns the width of one character in 1/360-inch units
+    #[inline]
+    pub const fn char_width(self) -> u32 {
+        match self {
+            Pitch::Cpi10 => 36,
+            Pitch::Cpi12 => 30,
+            Pitch::Cpi15 => 24,
+            Pitch::Condensed17 => 21,
+            Pitch::Condensed20 => 18,
+        }
+    }
+
+    /// Returns how many whole characters fit in the printable width
+    /// (in 1/360-inch units), saturating at `u16::MAX`.
+    pub fn columns(self, printable_width: u32) -> u16 {
+        (printable_width / self.char_width())
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+}
+
+/// Vertical line spacing, selected once per document.
+///
+/// The default is 1/6 inch, the printer setting after ESC @.
+///
+/// # Examples
+///
+/// ```
+/// use escp_layout::{LineSpacing, PageGeometry};
+///
+/// assert_eq!(LineSpacing::default(), LineSpacing::SixthInch);
+/// assert_eq!(LineSpacing::SixthInch.rows(11 * PageGeometry::INCH), 66);
+/// assert_eq!(LineSpacing::EighthInch.rows(11 * PageGeometry::INCH), 88);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep")
+)]
+pub enum LineSpacing {
+    /// 1/6 inch (ESC 2)
+    #[default]
+    SixthInch,
+    /// 1/8 inch (ESC 0)
+    EighthInch,
+    /// 7/72 inch, set as 35/360 inch (ESC + 35) since ESC 1 is a 9-pin
+    /// command; decodes as `Units360(35)`
+    SevenSeventySecondInch,
+    /// n/180 inch (ESC 3 n)
+    Units180(u8),
+    /// n/360 inch (ESC + n)
+    Units360(u8),
+}
+
+impl LineSpacing {
+    /// Returns the line pitch in 1/360-inch units
+    #[inline]
+    pub const fn units(self) -> u32 {
+        match self {
+            LineSpacing::SixthInch => 60,
+            LineSpacing::EighthInch => 45,
+            LineSpacing::SevenSeventySecondInch => 35,
+            LineSpacing::Units180(n) => n as u32 * 2,
+            LineSpacing::Units360(n) => n as u32,
+        }
+    }
+
+    /// Returns how many whole lines fit in the form length (in 1/360-inch
+    /// units), saturating at `u16::MAX`.
+    ///
+    /// Zero spacing (`Units180(0)`, `Units360(0)`) yields 0 rows.
+    pub fn rows(self, form_length: u32) -> u16 {
+        form_length
+            .checked_div(self.units())
+            .unwrap_or(0)
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry_default() {
+        let geometry = PageGeometry::default();
+        assert_eq!(geometry.columns(), 160);
+        assert_eq!(geometry.rows(), 51);
+        assert_eq!(geometry.cell_count(), 8160);
+    }
+
+    #[test]
+    fn test_geometry_new() {
+        let geometry = PageGeometry::new(136, 66);
+        assert_eq!(geometry.columns(), 136);
+        assert_eq!(geometry.rows(), 66);
+    }
+
+    #[test]
+    fn test_geometry_zero_clamped() {
+        let geometry = PageGeometry::new(0, 0);
+        assert_eq!(geometry.columns(), 1);
+        assert_eq!(geometry.rows(), 1);
+    }
+
+    #[test]
+    fn test_geometry_contains() {
+        let geometry = PageGeometry::new(80, 36);
+        assert!(geometry.contains(0, 0));
+        assert!(geometry.contains(79, 35));
+        assert!(!geometry.contains(80, 0));
+        assert!(!geometry.contains(0, 36));
+    }
+
+    #[test]
+    fn test_pitch_char_width() {
+        assert_eq!(Pitch::Cpi10.char_width(), 36);
+        assert_eq!(Pitch::Cpi12.char_width(), 30);
+        assert_eq!(Pitch::Cpi15.char_width(), 24);
+        assert_eq!(Pitch::Condensed17.char_width(), 21);
+        assert_eq!(Pitch::Condensed20.char_width(), 18);
+    }
+
+    #[test]
+    fn test_pitch_columns() {
+        let width = 8 * PageGeometry::INCH;
+        assert_eq!(Pitch::Cpi10.columns(width), 80);
+        assert_eq!(Pitch::Cpi12.columns(width), 96);
+        assert_eq!(Pitch::Cpi15.columns(width), 120);
+        assert_eq!(Pitch::Condensed17.columns(width), 137);
+        assert_eq!(Pitch::Condensed20.columns(width), 160);
+    }
+
+    #[test]
+    fn test_geometry_for_pitch() {
+        // 160 condensed columns need 9 1/3 inches
+        let geometry = PageGeometry::for_pitch(3360, Pitch::Condensed17, 51);
+        assert_eq!(geometry, PageGeometry::LQ2090_CONDENSED);
+    }
+
+    #[test]
+    fn test_line_spacing_units() {
+        assert_eq!(LineSpacing::SixthInch.units(), 60);
+        assert_eq!(LineSpacing::EighthInch.units(), 45);
+        assert_eq!(LineSpacing::SevenSeventySecondInch.units(), 35);
+        assert_eq!(LineSpacing::Units180(30).units(), 60);
+        assert_eq!(LineSpacing::Units360(45).units(), 45);
+    }
+
+    #[test]
+    fn test_line_spacing_rows() {
+        let length = 12 * PageGeometry::INCH;
+        assert_eq!(LineSpacing::SixthInch.rows(length), 72);
+        assert_eq!(LineSpacing::EighthInch.rows(length), 96);
+        assert_eq!(LineSpacing::SevenSeventySecondInch.rows(length), 123);
+        assert_eq!(LineSpacing::Units180(20).rows(length), 108);
+        assert_eq!(LineSpacing::Units360(0).rows(length), 0);
+    }
+
+    #[test]
+    fn test_geometry_for_form() {
+        let geometry = PageGeometry::for_form(
+            3360,
+            8 * PageGeometry::INCH + 180,
+            Pitch::Condensed17,
+            LineSpacing::SixthInch,
+        );
+        assert_eq!(geometry, PageGeometry::LQ2090_CONDENSED);
+
+        let dense = PageGeometry::for_form(
+            3360,
+            8 * PageGeometry::INCH + 180,
+            Pitch::Condensed17,
+            LineSpacing::EighthInch,
+        );
+        assert_eq!(dense.rows(), 68);
+    }
+}
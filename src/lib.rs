@@ -5,11 +5,13 @@
 //!
 //! ## Features
 //!
-//! - Fixed 160×51 character page grid
+//! - Configurable character page grid (160×51 by default)
 //! - Deterministic byte-for-byte ESC/P output
 //! - Silent truncation for content overflow
 //! - Immutable pages and documents after finalization
 //! - Zero runtime dependencies
+//! - Optional `serde` support for cells, styles, pages and documents
+//! - Printer transports for device files, `lpr`/`lp` and raw TCP
 //!
 //! ## Quick Start
 //!
@@ -35,15 +37,33 @@
 #![allow(missing_docs)] // Temporary - will be enforced in Phase 9
 
 // Module declarations
+mod border;
 mod cell;
+mod charset;
+mod diff;
 mod document;
 mod escp;
+mod geometry;
 mod page;
+mod preview;
+#[cfg(feature = "serde")]
+mod serialize;
+
+/// Printer transports
+pub mod transport;
 
 /// Widget composability system
 pub mod widget;
 
 // Public API exports
+pub use border::BorderStyle;
 pub use cell::{Cell, StyleFlags};
+pub use charset::{CharacterTable, InternationalCharset};
+pub use diff::{CellChange, DocumentDiff, PageDiff};
 pub use document::{Document, DocumentBuilder};
+pub use escp::{
+    Decoded, Decoder, HorizontalSkip, PageImage, PageImages, Printout, RenderOptions,
+    UnknownSequence, VirtualPrinter,
+};
+pub use geometry::{LineSpacing, PageGeometry, Pitch};
 pub use page::{Page, PageBuilder};
@@ -1,3 +1,75 @@
-fn main() {
-    println!("Hello, world!");
+//! `escp-layout` command-line tool (`cli` feature).
+//!
+//! Produces, inspects and sends ESC/P print files without writing Rust:
+//! see [`USAGE`] for the subcommands.
+
+mod cli;
+
+use std::process::ExitCode;
+
+/// Help text printed by `escp-layout help`
+const USAGE: &str = "\
+Usage: escp-layout <COMMAND> [ARGS]
+
+Commands:
+  render <LAYOUT> [--data FILE] [--output FILE]
+      Render a layout (JSON or TOML widget description) to ESC/P bytes.
+      With --data, label placeholders are filled from the record in FILE;
+      a list of records renders one page per record.
+  preview <INPUT> [--data FILE] [--html] [--output FILE]
+      Show a layout or .prn file as text (colored on a terminal) or as an
+      HTML page.
+  decode <FILE> [--output FILE]
+      Print the text grid of a .prn file.
+  diff <BEFORE> <AFTER>
+      Compare two .prn files (or layouts) cell by cell.
+  send <FILE> <DESTINATION> [--job-name NAME] [--timeout SECONDS]
+      Send a .prn file unchanged to a printer. DESTINATION is a device or
+      file path, HOST:PORT for a raw printer port (e.g. JetDirect 9100),
+      or lpr:[PRINTER] / lp:[PRINTER] to submit the job to the spooler.
+  help
+      Print this help.
+
+Inputs and outputs may be `-` for stdin and stdout. Files ending in
+.json or .toml are read as layouts, anything else (including stdin) as
+ESC/P bytes. --job-name applies only to lpr:/lp: destinations and
+--timeout only to HOST:PORT.
+
+Exit status: 0 on success, 1 if `diff` found differences, 2 on errors.
+";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(command) = args.next() else {
+        eprint!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+
+    let result = match command.as_str() {
+        "render" => cli::render(args),
+        "preview" => cli::preview(args),
+        "decode" => cli::decode(args),
+        "diff" => cli::diff(args),
+        "send" => cli::send(args),
+        "help" | "--help" | "-h" => {
+            print!("{}", USAGE);
+            Ok(ExitCode::SUCCESS)
+        }
+        "--version" | "-V" => {
+            println!("escp-layout {}", env!("CARGO_PKG_VERSION"));
+            Ok(ExitCode::SUCCESS)
+        }
+        _ => Err(cli::UsageError(format!("unknown command {:?}", command)).into()),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("escp-layout: {}", error);
+            if error.is::<cli::UsageError>() {
+                eprintln!("Run `escp-layout help` for usage.");
+            }
+            ExitCode::from(2)
+        }
+    }
 }
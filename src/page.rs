@@ -1,15 +1,22 @@
-//! Page and PageBuilder types for representing 160×51 character grids.
+//! Page and PageBuilder types for representing character grids.
 
+use crate::border::BorderStyle;
 use crate::cell::{Cell, StyleFlags};
+use crate::diff::PageDiff;
+use crate::geometry::PageGeometry;
+use std::io::{self, Write};
 
-/// Represents a single 160×51 character grid page.
+/// Represents a single character grid page.
 ///
 /// Pages are immutable after construction using the builder pattern.
-/// Each page contains a fixed grid of cells storing characters and styles.
-#[derive(Clone, Debug)]
+/// Each page contains a grid of cells storing characters and styles, sized
+/// by its [`PageGeometry`] (160×51 by default).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Page {
-    /// Fixed 160×51 cell grid (row-major order for cache efficiency)
-    cells: Box<[[Cell; 160]; 51]>,
+    /// Grid dimensions
+    geometry: PageGeometry,
+    /// Cell grid (row-major order for cache efficiency)
+    cells: Box<[Cell]>,
 }
 
 impl Page {
@@ -24,7 +31,27 @@ impl Page {
     /// let page = builder.build();
     /// ```
     pub fn builder() -> PageBuilder {
-        PageBuilder::new()
+        PageBuilder::new(PageGeometry::default())
+    }
+
+    /// Creates a new PageBuilder for a page with the given geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Page, PageGeometry};
+    ///
+    /// let builder = Page::builder_with_geometry(PageGeometry::new(80, 36));
+    /// let page = builder.build();
+    /// assert_eq!(page.geometry().columns(), 80);
+    /// ```
+    pub fn builder_with_geometry(geometry: PageGeometry) -> PageBuilder {
+        PageBuilder::new(geometry)
+    }
+
+    /// Returns the grid dimensions of this page.
+    pub fn geometry(&self) -> PageGeometry {
+        self.geometry
     }
 
     /// Returns the cell at the specified position, if within bounds.
@@ -42,38 +69,224 @@ impl Page {
     /// assert_eq!(cell.character(), 'A');
     /// ```
     pub fn get_cell(&self, x: u16, y: u16) -> Option<Cell> {
-        if x < 160 && y < 51 {
-            Some(self.cells[y as usize][x as usize])
+        if self.geometry.contains(x, y) {
+            Some(self.cells[index(self.geometry, x, y)])
         } else {
             None
         }
     }
 
-    /// Returns a reference to the entire cell grid.
-    pub fn cells(&self) -> &[[Cell; 160]; 51] {
+    /// Returns the entire cell grid in row-major order.
+    pub fn cells(&self) -> &[Cell] {
         &self.cells
     }
+
+    /// Returns the cells of a single row, if within bounds.
+    pub fn row(&self, y: u16) -> Option<&[Cell]> {
+        if y < self.geometry.rows() {
+            let start = y as usize * self.geometry.columns() as usize;
+            Some(&self.cells[start..start + self.geometry.columns() as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows of the page, top to bottom.
+    pub fn rows(&self) -> std::slice::ChunksExact<'_, Cell> {
+        self.cells.chunks_exact(self.geometry.columns() as usize)
+    }
+
+    /// Returns the page as plain text, one line per row.
+    ///
+    /// Trailing blanks and trailing blank rows are trimmed and styles are
+    /// dropped. Double-width glyphs are followed by a space so columns line
+    /// up with the printed page. Useful for logs and snapshot tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.write_str(2, 0, "Invoice", StyleFlags::BOLD);
+    /// builder.write_str(0, 2, "Total: 42", StyleFlags::NONE);
+    /// let page = builder.build();
+    ///
+    /// assert_eq!(page.to_plain_text(), "  Invoice\n\nTotal: 42\n");
+    /// ```
+    pub fn to_plain_text(&self) -> String {
+        crate::preview::page_text(self, false)
+    }
+
+    /// Returns the page as text for a terminal, with bold, italic, underline
+    /// and strikethrough shown as ANSI escape codes.
+    ///
+    /// Trimmed like [`Page::to_plain_text`], except that underlined or
+    /// struck-through blanks are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.write_str(0, 0, "Total", StyleFlags::BOLD);
+    /// let page = builder.build();
+    ///
+    /// assert_eq!(page.to_ansi(), "\x1b[0;1mTotal\x1b[0m\n");
+    /// ```
+    pub fn to_ansi(&self) -> String {
+        crate::preview::page_text(self, true)
+    }
+
+    /// Compares this page with `other`, cell by cell.
+    ///
+    /// See [`PageDiff`] for what is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.write_str(0, 0, "Total", StyleFlags::NONE);
+    /// let before = builder.build();
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.write_str(0, 0, "Total", StyleFlags::BOLD);
+    /// let after = builder.build();
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.changed_rows(), [0]);
+    /// assert_eq!(diff.style_changes().count(), 5);
+    /// assert_eq!(diff.text_changes().count(), 0);
+    /// ```
+    pub fn diff(&self, other: &Page) -> PageDiff {
+        PageDiff::new(self, other)
+    }
+
+    /// Writes this page to `writer` as a complete single-page ESC/P job.
+    ///
+    /// Uses default document settings (condensed pitch, 1/6-inch spacing,
+    /// ASCII); the bytes are identical to rendering a default [`Document`]
+    /// holding only this page. Use [`Document::render_to`] for other
+    /// settings or several pages.
+    ///
+    /// [`Document`]: crate::Document
+    /// [`Document::render_to`]: crate::Document::render_to
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.write_str(0, 0, "Receipt", StyleFlags::BOLD);
+    /// let page = builder.build();
+    ///
+    /// let mut output = Vec::new();
+    /// page.render_to(&mut output)?;
+    /// assert!(output.starts_with(b"\x1b@"));
+    /// assert_eq!(output.last(), Some(&0x0C));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn render_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        crate::escp::write_page(self, &mut writer)
+    }
+}
+
+impl Page {
+    /// Creates a page from a row-major cell grid sized to `geometry`
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_cells(geometry: PageGeometry, cells: Box<[Cell]>) -> Self {
+        debug_assert_eq!(cells.len(), geometry.cell_count());
+        Page { geometry, cells }
+    }
+
+    /// Returns the page cut to at most `columns` columns and `rows` rows.
+    /// Double-size glyphs that no longer fit entirely are dropped.
+    pub(crate) fn truncate(self, columns: u16, rows: u16) -> Page {
+        let geometry = PageGeometry::new(
+            columns.min(self.geometry.columns()),
+            rows.min(self.geometry.rows()),
+        );
+        if geometry == self.geometry {
+            return self;
+        }
+        let mut cells: Vec<Cell> = self
+            .rows()
+            .take(geometry.rows() as usize)
+            .flat_map(|row| row[..geometry.columns() as usize].iter().copied())
+            .collect();
+
+        let (last_x, last_y) = (geometry.columns() - 1, geometry.rows() - 1);
+        if geometry.columns() < self.geometry.columns() {
+            for y in 0..geometry.rows() {
+                let head = cells[index(geometry, last_x, y)];
+                if head.is_covered() || !head.style().double_width() {
+                    continue;
+                }
+                cells[index(geometry, last_x, y)] = Cell::EMPTY;
+                if head.style().double_height() && y < last_y {
+                    cells[index(geometry, last_x, y + 1)] = Cell::EMPTY;
+                }
+            }
+        }
+        if geometry.rows() < self.geometry.rows() {
+            for cell in &mut cells[index(geometry, 0, last_y)..] {
+                if cell.style().double_height() {
+                    *cell = Cell::EMPTY;
+                }
+            }
+        }
+        Page {
+            geometry,
+            cells: cells.into_boxed_slice(),
+        }
+    }
+}
+
+/// Row-major index of (x, y); callers must check bounds first.
+#[inline]
+fn index(geometry: PageGeometry, x: u16, y: u16) -> usize {
+    y as usize * geometry.columns() as usize + x as usize
 }
 
 /// Builder for constructing Pages with mutable operations.
 ///
 /// Consumes itself when building to enforce immutability.
 pub struct PageBuilder {
-    cells: Box<[[Cell; 160]; 51]>,
+    geometry: PageGeometry,
+    cells: Box<[Cell]>,
 }
 
 impl PageBuilder {
     /// Creates a new PageBuilder with all cells initialized to EMPTY.
-    fn new() -> Self {
+    fn new(geometry: PageGeometry) -> Self {
         PageBuilder {
-            cells: Box::new([[Cell::EMPTY; 160]; 51]),
+            geometry,
+            cells: vec![Cell::EMPTY; geometry.cell_count()].into_boxed_slice(),
         }
     }
 
+    /// Returns the grid dimensions of the page being built.
+    pub fn geometry(&self) -> PageGeometry {
+        self.geometry
+    }
+
     /// Writes a single character at the specified position.
     ///
     /// Out-of-bounds writes are silently ignored (no panic, no error).
     ///
+    /// A double-width glyph also covers the cell to its right, and a
+    /// double-height glyph the cell below it. Glyphs that do not fit on the
+    /// page entirely are dropped. Writing over any cell of an earlier
+    /// double-width or double-height glyph blanks the rest of that glyph.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,16 +300,97 @@ impl PageBuilder {
     /// builder.write_at(200, 0, 'X', StyleFlags::NONE);
     /// ```
     pub fn write_at(&mut self, x: u16, y: u16, ch: char, style: StyleFlags) -> &mut Self {
-        if x < 160 && y < 51 {
-            self.cells[y as usize][x as usize] = Cell::new(ch, style);
+        let (width, height) = (style.glyph_width(), style.glyph_height());
+        let right = x.saturating_add(width - 1);
+        let bottom = y.saturating_add(height - 1);
+        if !self.geometry.contains(x, y) || !self.geometry.contains(right, bottom) {
+            // Silent truncation - no panic, no error
+            return self;
+        }
+
+        for dy in 0..height {
+            for dx in 0..width {
+                self.erase_glyph_at(x + dx, y + dy);
+            }
+        }
+
+        self.cells[index(self.geometry, x, y)] = Cell::new(ch, style);
+        if width == 2 {
+            self.cells[index(self.geometry, x + 1, y)] = Cell::wide_tail(style);
+        }
+        if height == 2 {
+            for dx in 0..width {
+                self.cells[index(self.geometry, x + dx, y + 1)] = Cell::TALL_TAIL;
+            }
         }
-        // Silent truncation - no panic, no error
         self
     }
 
+    /// Blanks every cell of the glyph covering (x, y), which must be on the
+    /// page.
+    ///
+    /// Overwriting only part of a double-size glyph would leave a glyph
+    /// head without its covered cells (or covered cells without a head),
+    /// which the printer cannot reproduce.
+    fn erase_glyph_at(&mut self, x: u16, y: u16) {
+        let Some((head_x, head_y)) = self.glyph_head(x, y) else {
+            self.cells[index(self.geometry, x, y)] = Cell::EMPTY;
+            return;
+        };
+        let style = self.cells[index(self.geometry, head_x, head_y)].style();
+        self.cells[index(self.geometry, head_x, head_y)] = Cell::EMPTY;
+
+        let right = head_x.saturating_add(1);
+        if style.double_width()
+            && self.geometry.contains(right, head_y)
+            && self.cells[index(self.geometry, right, head_y)].is_wide_tail()
+        {
+            self.cells[index(self.geometry, right, head_y)] = Cell::EMPTY;
+        }
+        if style.double_height() {
+            let below = head_y.saturating_add(1);
+            for cover_x in head_x..head_x.saturating_add(style.glyph_width()) {
+                if self.geometry.contains(cover_x, below)
+                    && self.cells[index(self.geometry, cover_x, below)].is_tall_tail()
+                {
+                    self.cells[index(self.geometry, cover_x, below)] = Cell::EMPTY;
+                }
+            }
+        }
+    }
+
+    /// Returns the position of the glyph covering (x, y), or None for a
+    /// covered cell whose glyph is gone.
+    fn glyph_head(&self, x: u16, y: u16) -> Option<(u16, u16)> {
+        let cell = |x: u16, y: u16| self.cells[index(self.geometry, x, y)];
+        let is_head = |x: u16, y: u16, style: StyleFlags| {
+            let head = cell(x, y);
+            !head.is_covered()
+                && (!style.double_width() || head.style().double_width())
+                && (!style.double_height() || head.style().double_height())
+        };
+
+        let covered = cell(x, y);
+        if !covered.is_covered() {
+            Some((x, y))
+        } else if covered.is_wide_tail() {
+            (x > 0 && is_head(x - 1, y, StyleFlags::DOUBLE_WIDTH)).then(|| (x - 1, y))
+        } else if y == 0 {
+            None
+        } else if is_head(x, y - 1, StyleFlags::DOUBLE_HEIGHT) {
+            Some((x, y - 1))
+        } else {
+            // Lower right quarter of a double-width, double-height glyph
+            let both = StyleFlags::DOUBLE_WIDTH.with_double_height();
+            (x > 0 && cell(x, y - 1).is_wide_tail() && is_head(x - 1, y - 1, both))
+                .then(|| (x - 1, y - 1))
+        }
+    }
+
     /// Writes a string starting at the specified position.
     ///
-    /// Characters exceeding line width are silently truncated.
+    /// Characters exceeding line width are silently truncated. Double-width
+    /// text advances two columns per character.
     ///
     /// # Examples
     ///
@@ -107,17 +401,120 @@ impl PageBuilder {
     /// builder.write_str(0, 0, "Hello, World!", StyleFlags::NONE);
     /// ```
     pub fn write_str(&mut self, x: u16, y: u16, text: &str, style: StyleFlags) -> &mut Self {
-        let mut current_x = x;
-        for ch in text.chars() {
-            if current_x >= 160 {
-                break; // Truncate at line boundary
-            }
+        let columns = self.geometry.columns();
+        let advance = style.glyph_width() as usize;
+        for (current_x, ch) in (x..columns).step_by(advance).zip(text.chars()) {
+            // Range ends at the line boundary, truncating the remainder
             self.write_at(current_x, y, ch, style);
-            current_x += 1;
         }
         self
     }
 
+    /// Draws a horizontal line of `length` cells starting at (x, y).
+    ///
+    /// Cells beyond the page edge are silently truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{BorderStyle, Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.draw_hline(0, 2, 40, BorderStyle::Double, StyleFlags::NONE);
+    /// let page = builder.build();
+    /// assert_eq!(page.get_cell(39, 2).unwrap().character(), '═');
+    /// ```
+    pub fn draw_hline(
+        &mut self,
+        x: u16,
+        y: u16,
+        length: u16,
+        border: BorderStyle,
+        style: StyleFlags,
+    ) -> &mut Self {
+        let end = x.saturating_add(length).min(self.geometry.columns());
+        for current_x in x..end {
+            self.write_at(current_x, y, border.horizontal(), style);
+        }
+        self
+    }
+
+    /// Draws a vertical line of `length` cells starting at (x, y).
+    ///
+    /// Cells beyond the page edge are silently truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{BorderStyle, Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.draw_vline(10, 0, 5, BorderStyle::Single, StyleFlags::NONE);
+    /// let page = builder.build();
+    /// assert_eq!(page.get_cell(10, 4).unwrap().character(), '│');
+    /// ```
+    pub fn draw_vline(
+        &mut self,
+        x: u16,
+        y: u16,
+        length: u16,
+        border: BorderStyle,
+        style: StyleFlags,
+    ) -> &mut Self {
+        let end = y.saturating_add(length).min(self.geometry.rows());
+        for current_y in y..end {
+            self.write_at(x, current_y, border.vertical(), style);
+        }
+        self
+    }
+
+    /// Draws a box outline `width` × `height` cells with its top-left corner
+    /// at (x, y). The interior is left untouched.
+    ///
+    /// Boxes narrower or shorter than 2 cells are ignored. Parts of the box
+    /// beyond the page edge are silently truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escp_layout::{BorderStyle, Page, StyleFlags};
+    ///
+    /// let mut builder = Page::builder();
+    /// builder.draw_box(0, 0, 20, 5, BorderStyle::Single, StyleFlags::NONE);
+    /// builder.write_str(2, 2, "Framed", StyleFlags::NONE);
+    /// let page = builder.build();
+    ///
+    /// assert_eq!(page.get_cell(0, 0).unwrap().character(), '┌');
+    /// assert_eq!(page.get_cell(19, 4).unwrap().character(), '┘');
+    /// ```
+    pub fn draw_box(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        border: BorderStyle,
+        style: StyleFlags,
+    ) -> &mut Self {
+        if width < 2 || height < 2 {
+            return self;
+        }
+        let right = x.saturating_add(width - 1);
+        let bottom = y.saturating_add(height - 1);
+
+        let (inner_x, inner_y) = (x.saturating_add(1), y.saturating_add(1));
+
+        self.draw_hline(inner_x, y, width - 2, border, style);
+        self.draw_hline(inner_x, bottom, width - 2, border, style);
+        self.draw_vline(x, inner_y, height - 2, border, style);
+        self.draw_vline(right, inner_y, height - 2, border, style);
+
+        self.write_at(x, y, border.top_left(), style);
+        self.write_at(right, y, border.top_right(), style);
+        self.write_at(x, bottom, border.bottom_left(), style);
+        self.write_at(right, bottom, border.bottom_right(), style)
+    }
+
     /// Render a widget tree to this page.
     ///
     /// The widget tree is traversed depth-first, with each widget rendering
@@ -129,7 +526,7 @@ impl PageBuilder {
     /// # Errors
     ///
     /// Returns `RenderError::OutOfBounds` if any widget attempts to render
-    /// outside page bounds (160 × 51).
+    /// outside page bounds (160 × 51 by default, see [`PageGeometry`]).
     ///
     /// # Examples
     ///
@@ -155,6 +552,42 @@ impl PageBuilder {
         widget.render_to(&mut context, (0, 0))
     }
 
+    /// Render a widget tree built from a
+    /// [`WidgetDescription`](crate::widget::WidgetDescription) at (0, 0).
+    ///
+    /// Like [`render`](Self::render), but for layouts loaded at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RenderError::OutOfBounds` if a widget starts outside the page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::WidgetDescription;
+    /// use escp_layout::Page;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let description = WidgetDescription::Label {
+    ///     width: 20,
+    ///     text: "Hello".to_string(),
+    ///     style: Vec::new(),
+    /// };
+    ///
+    /// let mut page_builder = Page::builder();
+    /// page_builder.render_layout(&description.build()?)?;
+    /// assert_eq!(page_builder.build().to_plain_text(), "Hello\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_layout(
+        &mut self,
+        layout: &crate::widget::Layout,
+    ) -> Result<(), crate::widget::RenderError> {
+        let mut context = crate::widget::RenderContext::new(self);
+        layout.render_to(&mut context, (0, 0))
+    }
+
     /// Consumes the builder and returns an immutable Page.
     ///
     /// After calling this, the builder cannot be reused.
@@ -169,7 +602,10 @@ impl PageBuilder {
     /// let page = builder.build();
     /// ```
     pub fn build(self) -> Page {
-        Page { cells: self.cells }
+        Page {
+            geometry: self.geometry,
+            cells: self.cells,
+        }
     }
 }
 
@@ -179,25 +615,25 @@ mod tests {
 
     #[test]
     fn test_page_builder_new() {
-        let builder = PageBuilder::new();
+        let builder = PageBuilder::new(PageGeometry::default());
         // Verify all cells are EMPTY
-        assert_eq!(builder.cells[0][0], Cell::EMPTY);
-        assert_eq!(builder.cells[50][159], Cell::EMPTY);
+        assert_eq!(builder.cells[0], Cell::EMPTY);
+        assert_eq!(builder.cells[8159], Cell::EMPTY);
     }
 
     #[test]
     fn test_page_builder_write_at() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
         builder.write_at(10, 5, 'A', StyleFlags::BOLD);
 
-        let cell = builder.cells[5][10];
+        let cell = builder.cells[5 * 160 + 10];
         assert_eq!(cell.character(), 'A');
         assert_eq!(cell.style(), StyleFlags::BOLD);
     }
 
     #[test]
     fn test_page_builder_write_at_out_of_bounds() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
 
         // Should not panic - silent truncation
         builder.write_at(200, 0, 'X', StyleFlags::NONE);
@@ -206,31 +642,31 @@ mod tests {
 
     #[test]
     fn test_page_builder_write_str() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
         builder.write_str(0, 0, "Hello", StyleFlags::NONE);
 
-        assert_eq!(builder.cells[0][0].character(), 'H');
-        assert_eq!(builder.cells[0][1].character(), 'e');
-        assert_eq!(builder.cells[0][2].character(), 'l');
-        assert_eq!(builder.cells[0][3].character(), 'l');
-        assert_eq!(builder.cells[0][4].character(), 'o');
+        assert_eq!(builder.cells[0].character(), 'H');
+        assert_eq!(builder.cells[1].character(), 'e');
+        assert_eq!(builder.cells[2].character(), 'l');
+        assert_eq!(builder.cells[3].character(), 'l');
+        assert_eq!(builder.cells[4].character(), 'o');
     }
 
     #[test]
     fn test_page_builder_write_str_truncation() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
 
         // Write a very long string - should truncate at column 160
         let long_string = "A".repeat(200);
         builder.write_str(0, 0, &long_string, StyleFlags::NONE);
 
-        assert_eq!(builder.cells[0][159].character(), 'A');
+        assert_eq!(builder.cells[159].character(), 'A');
         // No panic occurred
     }
 
     #[test]
     fn test_page_builder_build() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
         builder.write_str(0, 0, "Test", StyleFlags::NONE);
 
         let page = builder.build();
@@ -241,7 +677,7 @@ mod tests {
 
     #[test]
     fn test_page_get_cell_valid() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
         builder.write_at(50, 25, 'X', StyleFlags::UNDERLINE);
         let page = builder.build();
 
@@ -260,7 +696,7 @@ mod tests {
 
     #[test]
     fn test_page_builder_write_at_exact_boundaries() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
 
         // Test exact boundaries - should work without panic
         builder.write_at(159, 50, 'X', StyleFlags::NONE);
@@ -275,7 +711,7 @@ mod tests {
         assert_eq!(page.get_cell(0, 50).unwrap().character(), 'Z');
 
         // Test just beyond boundaries - should be silent (no panic)
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
         builder.write_at(160, 0, 'A', StyleFlags::NONE);
         builder.write_at(0, 51, 'B', StyleFlags::NONE);
         // No assertions needed - test passes if no panic
@@ -283,12 +719,258 @@ mod tests {
 
     #[test]
     fn test_page_cells() {
-        let mut builder = PageBuilder::new();
+        let mut builder = PageBuilder::new(PageGeometry::default());
         builder.write_at(0, 0, 'A', StyleFlags::NONE);
         let page = builder.build();
 
         let cells = page.cells();
-        assert_eq!(cells[0][0].character(), 'A');
+        assert_eq!(cells.len(), 160 * 51);
+        assert_eq!(cells[0].character(), 'A');
+    }
+
+    #[test]
+    fn test_page_custom_geometry() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(80, 36));
+        builder.write_at(79, 35, 'X', StyleFlags::NONE);
+        builder.write_at(80, 0, 'Y', StyleFlags::NONE);
+        builder.write_at(0, 36, 'Z', StyleFlags::NONE);
+        let page = builder.build();
+
+        assert_eq!(page.geometry(), PageGeometry::new(80, 36));
+        assert_eq!(page.cells().len(), 80 * 36);
+        assert_eq!(page.get_cell(79, 35).unwrap().character(), 'X');
+        assert!(page.get_cell(80, 0).is_none());
+        assert!(page.get_cell(0, 36).is_none());
+    }
+
+    #[test]
+    fn test_page_write_str_truncates_at_geometry_width() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(80, 36));
+        builder.write_str(75, 0, "ABCDEFGHIJ", StyleFlags::NONE);
+        let page = builder.build();
+
+        let row: String = page.row(0).unwrap().iter().map(|c| c.character()).collect();
+        assert!(row.ends_with("ABCDE"));
+        assert_eq!(row.len(), 80);
+    }
+
+    #[test]
+    fn test_page_rows() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(10, 3));
+        builder.write_str(0, 2, "Last", StyleFlags::NONE);
+        let page = builder.build();
+
+        assert_eq!(page.rows().count(), 3);
+        assert!(page.rows().all(|row| row.len() == 10));
+        assert_eq!(page.row(2).unwrap()[0].character(), 'L');
+        assert!(page.row(3).is_none());
+    }
+
+    #[test]
+    fn test_page_draw_box() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(6, 4));
+        builder.draw_box(0, 0, 5, 3, BorderStyle::Single, StyleFlags::NONE);
+        let page = builder.build();
+
+        let rows: Vec<String> = page
+            .rows()
+            .map(|row| row.iter().map(|c| c.character()).collect())
+            .collect();
+        assert_eq!(rows, ["┌───┐ ", "│   │ ", "└───┘ ", "      "]);
+    }
+
+    #[test]
+    fn test_page_draw_box_truncated_at_edge() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(4, 2));
+        builder.draw_box(2, 0, 5, 5, BorderStyle::Double, StyleFlags::BOLD);
+        let page = builder.build();
+
+        assert_eq!(
+            page.get_cell(2, 0).unwrap(),
+            Cell::new('╔', StyleFlags::BOLD)
+        );
+        assert_eq!(page.get_cell(3, 0).unwrap().character(), '═');
+        assert_eq!(page.get_cell(2, 1).unwrap().character(), '║');
+        assert_eq!(page.get_cell(3, 1).unwrap().character(), ' ');
+    }
+
+    #[test]
+    fn test_page_draw_box_too_small_ignored() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(4, 4));
+        builder.draw_box(0, 0, 1, 4, BorderStyle::Ascii, StyleFlags::NONE);
+        builder.draw_box(0, 0, 4, 0, BorderStyle::Ascii, StyleFlags::NONE);
+        let page = builder.build();
+
+        assert!(page.cells().iter().all(|cell| *cell == Cell::EMPTY));
+    }
+
+    #[test]
+    fn test_page_draw_box_at_max_coordinates() {
+        let mut builder = PageBuilder::new(PageGeometry::default());
+        // Entirely off the page - must not overflow
+        builder.draw_box(
+            u16::MAX,
+            u16::MAX,
+            5,
+            5,
+            BorderStyle::Ascii,
+            StyleFlags::NONE,
+        );
+        builder.draw_box(u16::MAX, 0, 2, 2, BorderStyle::Ascii, StyleFlags::NONE);
+        builder.draw_box(0, u16::MAX, 2, 2, BorderStyle::Ascii, StyleFlags::NONE);
+        let page = builder.build();
+
+        assert!(page.cells().iter().all(|cell| *cell == Cell::EMPTY));
+    }
+
+    #[test]
+    fn test_page_draw_lines() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(5, 5));
+        builder.draw_hline(1, 0, u16::MAX, BorderStyle::Ascii, StyleFlags::NONE);
+        builder.draw_vline(0, 2, 2, BorderStyle::Ascii, StyleFlags::NONE);
+        let page = builder.build();
+
+        let top: String = page.row(0).unwrap().iter().map(|c| c.character()).collect();
+        assert_eq!(top, " ----");
+        assert_eq!(page.get_cell(0, 1).unwrap().character(), ' ');
+        assert_eq!(page.get_cell(0, 2).unwrap().character(), '|');
+        assert_eq!(page.get_cell(0, 3).unwrap().character(), '|');
+        assert_eq!(page.get_cell(0, 4).unwrap().character(), ' ');
+    }
+
+    #[test]
+    fn test_page_write_double_width() {
+        let mut builder = PageBuilder::new(PageGeometry::default());
+        let style = StyleFlags::DOUBLE_WIDTH;
+        builder.write_str(0, 0, "AB", style);
+        builder.write_at(4, 0, 'C', StyleFlags::NONE);
+        let page = builder.build();
+
+        assert_eq!(page.get_cell(0, 0).unwrap(), Cell::new('A', style));
+        assert!(page.get_cell(1, 0).unwrap().is_covered());
+        assert_eq!(page.get_cell(2, 0).unwrap(), Cell::new('B', style));
+        assert!(page.get_cell(3, 0).unwrap().is_covered());
+        assert_eq!(page.get_cell(4, 0).unwrap().character(), 'C');
+    }
+
+    #[test]
+    fn test_page_write_double_height() {
+        let mut builder = PageBuilder::new(PageGeometry::default());
+        let style = StyleFlags::DOUBLE_HEIGHT.with_double_width();
+        builder.write_at(10, 5, 'H', style);
+        let page = builder.build();
+
+        assert_eq!(page.get_cell(10, 5).unwrap().character(), 'H');
+        assert!(page.get_cell(11, 5).unwrap().is_covered());
+        assert!(page.get_cell(10, 6).unwrap().is_covered());
+        assert!(page.get_cell(11, 6).unwrap().is_covered());
+        assert!(!page.get_cell(12, 5).unwrap().is_covered());
+        assert!(!page.get_cell(10, 7).unwrap().is_covered());
+    }
+
+    #[test]
+    fn test_page_double_size_truncated_at_edges() {
+        let mut builder = PageBuilder::new(PageGeometry::default());
+        // Right half would fall off the line
+        builder.write_at(159, 0, 'W', StyleFlags::DOUBLE_WIDTH);
+        // Lower half would fall off the page
+        builder.write_at(0, 50, 'H', StyleFlags::DOUBLE_HEIGHT);
+        // Odd remaining width: only whole glyphs are written
+        builder.write_str(155, 1, "ABCDE", StyleFlags::DOUBLE_WIDTH);
+        let page = builder.build();
+
+        assert_eq!(page.get_cell(159, 0).unwrap(), Cell::EMPTY);
+        assert_eq!(page.get_cell(0, 50).unwrap(), Cell::EMPTY);
+        assert_eq!(page.get_cell(155, 1).unwrap().character(), 'A');
+        assert_eq!(page.get_cell(157, 1).unwrap().character(), 'B');
+        assert_eq!(page.get_cell(159, 1).unwrap(), Cell::EMPTY);
+    }
+
+    #[test]
+    fn test_page_truncate_rows() {
+        let mut builder = PageBuilder::new(PageGeometry::new(8, 4));
+        builder.write_str(0, 0, "top", StyleFlags::NONE);
+        builder.write_str(0, 1, "AB", StyleFlags::DOUBLE_HEIGHT);
+        builder.write_str(4, 1, "W", StyleFlags::DOUBLE_WIDTH.with_double_height());
+        builder.write_str(0, 3, "cut", StyleFlags::NONE);
+        let page = builder.build().truncate(8, 2);
+
+        assert_eq!(page.geometry(), PageGeometry::new(8, 2));
+        assert_eq!(page.to_plain_text(), "top\n");
+        assert!(page.cells().iter().skip(8).all(|&cell| cell == Cell::EMPTY));
+
+        let page = Page::builder_with_geometry(PageGeometry::new(8, 4)).build();
+        assert_eq!(page.clone().truncate(8, 4), page);
+        assert_eq!(page.clone().truncate(100, 100), page);
+    }
+
+    #[test]
+    fn test_page_truncate_columns() {
+        let mut builder = PageBuilder::new(PageGeometry::new(8, 3));
+        builder.write_str(0, 0, "abcdefgh", StyleFlags::NONE);
+        builder.write_str(0, 1, "W", StyleFlags::DOUBLE_WIDTH);
+        builder.write_str(4, 1, "W", StyleFlags::DOUBLE_WIDTH.with_double_height());
+        let page = builder.build().truncate(5, 3);
+
+        assert_eq!(page.geometry(), PageGeometry::new(5, 3));
+        assert_eq!(page.to_plain_text(), "abcde\nW\n");
+        // The double-size W at column 4 lost its right half
+        assert_eq!(page.get_cell(4, 1).unwrap(), Cell::EMPTY);
+        assert_eq!(page.get_cell(4, 2).unwrap(), Cell::EMPTY);
+    }
+
+    #[test]
+    fn test_page_overwrite_wide_tail_erases_glyph() {
+        let mut builder = PageBuilder::new(PageGeometry::new(4, 1));
+        builder.write_at(0, 0, 'W', StyleFlags::DOUBLE_WIDTH);
+        builder.write_at(1, 0, 'n', StyleFlags::NONE);
+        builder.write_at(3, 0, '|', StyleFlags::NONE);
+        let page = builder.build();
+
+        assert_eq!(page.get_cell(0, 0).unwrap(), Cell::EMPTY);
+        assert_eq!(page.get_cell(1, 0).unwrap().character(), 'n');
+        assert_eq!(page.to_plain_text(), " n |\n");
+    }
+
+    #[test]
+    fn test_page_overwrite_head_erases_covered_cells() {
+        let mut builder = PageBuilder::new(PageGeometry::new(4, 2));
+        let style = StyleFlags::DOUBLE_WIDTH.with_double_height();
+        builder.write_at(0, 0, 'H', style);
+        builder.write_at(0, 0, 'x', StyleFlags::NONE);
+        let page = builder.build();
+
+        assert!(page.cells().iter().all(|cell| !cell.is_covered()));
+        assert_eq!(page.get_cell(0, 0).unwrap().character(), 'x');
+    }
+
+    #[test]
+    fn test_page_overwrite_tall_tail_erases_glyph() {
+        let style = StyleFlags::DOUBLE_WIDTH.with_double_height();
+        for (x, y) in [(0, 1), (1, 1)] {
+            let mut builder = PageBuilder::new(PageGeometry::new(4, 2));
+            builder.write_at(0, 0, 'H', style);
+            builder.write_at(x, y, 'x', StyleFlags::NONE);
+            let page = builder.build();
+
+            assert_eq!(page.get_cell(0, 0).unwrap(), Cell::EMPTY);
+            assert_eq!(page.get_cell(x, y).unwrap().character(), 'x');
+            assert!(page.cells().iter().all(|cell| !cell.is_covered()));
+        }
+    }
+
+    #[test]
+    fn test_page_overlapping_double_width_glyphs() {
+        let mut builder = PageBuilder::new(PageGeometry::new(4, 1));
+        builder.write_str(0, 0, "AB", StyleFlags::DOUBLE_WIDTH);
+        // Covers the tail of A and the head of B
+        builder.write_at(1, 0, 'C', StyleFlags::DOUBLE_WIDTH);
+        let page = builder.build();
+
+        assert_eq!(page.get_cell(0, 0).unwrap(), Cell::EMPTY);
+        assert_eq!(page.get_cell(1, 0).unwrap().character(), 'C');
+        assert!(page.get_cell(2, 0).unwrap().is_covered());
+        assert_eq!(page.get_cell(3, 0).unwrap(), Cell::EMPTY);
     }
 
     #[test]
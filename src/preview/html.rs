@@ -0,0 +1,198 @@
+//! Self-contained HTML print preview.
+
+use super::{
+    format_number, push_escaped, style_runs, vertical_metrics, Run, ADVANCE_EM, FONT_FAMILY,
+    UNITS_PER_POINT,
+};
+use crate::cell::StyleFlags;
+use crate::document::Document;
+
+/// Renders a document as an HTML page with one `<pre>` block per page.
+///
+/// Lengths are in points (1/5 of the 1/360-inch paper unit), so the grid of
+/// every page matches the printed one: the font size makes one `ch` exactly
+/// one column and the line height is the line spacing.
+pub(crate) fn document_html(document: &Document) -> String {
+    let char_width = document.pitch().char_width();
+    let line_spacing = document.line_spacing().units();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Print preview</title>\n<style>\n");
+    html.push_str("body { margin: 0; padding: 18pt; background: #ccc; }\n");
+    html.push_str(&format!(
+        "pre.page {{ box-sizing: content-box; margin: 0 auto 18pt; padding: 0; \
+         overflow: hidden; background: #fff; color: #000; \
+         font-family: {}; font-size: {}pt; line-height: {}pt; }}\n",
+        FONT_FAMILY,
+        format_number(char_width as f64 / ADVANCE_EM / UNITS_PER_POINT),
+        format_number(line_spacing as f64 / UNITS_PER_POINT),
+    ));
+    html.push_str("@media print { body { padding: 0; background: none; } ");
+    html.push_str("pre.page { margin: 0; break-after: page; } }\n");
+    html.push_str("</style>\n</head>\n<body>\n");
+
+    for page in document.pages() {
+        let geometry = page.geometry();
+        html.push_str(&format!(
+            "<pre class=\"page\" style=\"width: {}pt; height: {}pt\">",
+            format_number((geometry.columns() as u32 * char_width) as f64 / UNITS_PER_POINT),
+            format_number((geometry.rows() as u32 * line_spacing) as f64 / UNITS_PER_POINT),
+        ));
+        for (y, row) in page.rows().enumerate() {
+            if y > 0 {
+                html.push('\n');
+            }
+            for run in style_runs(row) {
+                push_run(&run, &mut html);
+            }
+        }
+        html.push_str("</pre>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Appends a run wrapped in its style tags.
+///
+/// Double-size and script glyphs are scaled inside an inline block as wide
+/// as the run's columns; the block is outermost so the text decorations
+/// scale with the glyphs.
+fn push_run(run: &Run, output: &mut String) {
+    let style = run.style;
+    let scaled =
+        style.double_width() || style.double_height() || style.superscript() || style.subscript();
+    if scaled {
+        let (scale_y, _) = vertical_metrics(style);
+        let origin = if style.subscript() && !style.double_height() {
+            "bottom"
+        } else {
+            "top"
+        };
+        output.push_str(&format!(
+            "<span style=\"display: inline-block; width: {}ch; \
+             transform: scale({}, {}); transform-origin: left {}\">",
+            run.columns,
+            style.glyph_width(),
+            format_number(scale_y),
+            origin,
+        ));
+    }
+
+    let tags = style_tags(style);
+    for tag in &tags {
+        output.push('<');
+        output.push_str(tag);
+        output.push('>');
+    }
+    push_escaped(&run.text, output);
+    for tag in tags.iter().rev() {
+        output.push_str("</");
+        output.push_str(tag);
+        output.push('>');
+    }
+
+    if scaled {
+        output.push_str("</span>");
+    }
+}
+
+/// Returns the HTML elements showing a style, outermost first.
+///
+/// Double-strike prints darker, so it shows as bold.
+fn style_tags(style: StyleFlags) -> Vec<&'static str> {
+    let mut tags = Vec::new();
+    if style.bold() || style.double_strike() {
+        tags.push("b");
+    }
+    if style.italic() {
+        tags.push("i");
+    }
+    if style.underline() {
+        tags.push("u");
+    }
+    if style.strikethrough() {
+        tags.push("s");
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{LineSpacing, PageGeometry, Pitch};
+    use crate::page::Page;
+
+    fn document(build: impl FnOnce(&mut crate::page::PageBuilder)) -> Document {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(20, 3));
+        build(&mut page_builder);
+        let mut builder = Document::builder();
+        builder
+            .pitch(Pitch::Cpi10)
+            .line_spacing(LineSpacing::EighthInch)
+            .add_page(page_builder.build());
+        builder.build()
+    }
+
+    fn page_body(html: &str) -> &str {
+        let start = html.find("<pre").unwrap();
+        let end = html.find("</pre>").unwrap() + "</pre>".len();
+        &html[start..end]
+    }
+
+    #[test]
+    fn test_html_page_grid() {
+        let html = document_html(&document(|_| {}));
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.contains("font-size: 12pt; line-height: 9pt;"));
+        assert_eq!(
+            page_body(&html),
+            "<pre class=\"page\" style=\"width: 144pt; height: 27pt\">\n\n</pre>"
+        );
+    }
+
+    #[test]
+    fn test_html_style_runs() {
+        let html = document_html(&document(|page| {
+            page.write_str(0, 0, "Total:", StyleFlags::BOLD);
+            page.write_str(7, 0, "<42>", StyleFlags::UNDERLINE.with_italic());
+            page.write_str(2, 1, "R&D", StyleFlags::DOUBLE_STRIKE.with_strikethrough());
+        }));
+        assert_eq!(
+            page_body(&html),
+            "<pre class=\"page\" style=\"width: 144pt; height: 27pt\">\
+             <b>Total:</b> <i><u>&lt;42&gt;</u></i>\n  <b><s>R&amp;D</s></b>\n</pre>"
+        );
+    }
+
+    #[test]
+    fn test_html_scaled_glyphs() {
+        let html = document_html(&document(|page| {
+            page.write_str(0, 0, "AB", StyleFlags::DOUBLE_WIDTH.with_underline());
+            page.write_str(4, 0, "T", StyleFlags::DOUBLE_HEIGHT);
+            page.write_str(5, 0, "2", StyleFlags::SUBSCRIPT);
+        }));
+        assert_eq!(
+            page_body(&html),
+            "<pre class=\"page\" style=\"width: 144pt; height: 27pt\">\
+             <span style=\"display: inline-block; width: 4ch; \
+             transform: scale(2, 1); transform-origin: left top\"><u>AB</u></span>\
+             <span style=\"display: inline-block; width: 1ch; \
+             transform: scale(1, 2); transform-origin: left top\">T</span>\
+             <span style=\"display: inline-block; width: 1ch; \
+             transform: scale(1, 0.6); transform-origin: left bottom\">2</span>\n\n</pre>"
+        );
+    }
+
+    #[test]
+    fn test_html_one_block_per_page() {
+        let mut builder = Document::builder();
+        builder
+            .add_page(Page::builder().build())
+            .add_page(Page::builder().build());
+        let html = document_html(&builder.build());
+        assert_eq!(html.matches("<pre class=\"page\"").count(), 2);
+        assert!(html.ends_with("</pre>\n</body>\n</html>\n"));
+    }
+}
@@ -0,0 +1,261 @@
+//! Previews and exports of pages and documents: plain text, ANSI, HTML,
+//! SVG and PDF.
+
+mod html;
+mod pdf;
+mod svg;
+mod text;
+
+pub(crate) use html::document_html;
+pub(crate) use pdf::document_pdf;
+pub(crate) use svg::document_svg;
+pub(crate) use text::{document_text, line_text, page_text};
+
+use crate::cell::{Cell, StyleFlags};
+
+/// Monospace fonts whose advance is 0.6 em, like the printer's Courier
+const FONT_FAMILY: &str = "'Courier New', Courier, monospace";
+
+/// Glyph advance as a fraction of the font size
+const ADVANCE_EM: f64 = 0.6;
+
+/// Baseline offset from the top of a line, as a fraction of the line pitch
+const BASELINE: f64 = 0.75;
+
+/// Vertical scale of superscript and subscript glyphs
+const SCRIPT_SCALE: f64 = 0.6;
+
+/// Paper units (1/360 inch) per point
+const UNITS_PER_POINT: f64 = 5.0;
+
+/// Run of adjacent cells sharing one style within a row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Run {
+    /// First column of the run
+    column: u16,
+    /// Columns covered, including the right halves of double-width glyphs
+    columns: u16,
+    /// One character per glyph (double-width glyphs appear once)
+    text: String,
+    style: StyleFlags,
+}
+
+impl Run {
+    /// Returns true if the run puts ink on the paper
+    fn is_visible(&self) -> bool {
+        self.text.chars().any(|ch| ch != ' ')
+            || self.style.underline()
+            || self.style.strikethrough()
+    }
+
+    /// Returns the run without leading and trailing blanks, or None if
+    /// nothing is left.
+    ///
+    /// Underlined or struck-through blanks print, so they are kept.
+    fn trimmed(&self) -> Option<Run> {
+        if !self.is_visible() {
+            return None;
+        }
+        if self.style.underline() || self.style.strikethrough() {
+            return Some(self.clone());
+        }
+        let text = self.text.trim_matches(' ');
+        let leading = self.text.len() - self.text.trim_start_matches(' ').len();
+        let width = self.style.glyph_width();
+        Some(Run {
+            column: self.column + leading as u16 * width,
+            columns: text.chars().count() as u16 * width,
+            text: text.to_string(),
+            style: self.style,
+        })
+    }
+}
+
+/// Splits a row into style runs.
+///
+/// The lower halves of double-height glyphs (and orphaned right halves)
+/// read as unstyled spaces.
+/// Trailing blanks are dropped, so a blank row yields no runs.
+fn style_runs(row: &[Cell]) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    for (x, cell) in row.iter().enumerate() {
+        let after_wide_glyph =
+            x > 0 && !row[x - 1].is_covered() && row[x - 1].style().double_width();
+        if cell.is_wide_tail() && after_wide_glyph {
+            if let Some(run) = runs.last_mut() {
+                run.columns += 1;
+            }
+            continue;
+        }
+
+        let style = if cell.is_covered() {
+            StyleFlags::NONE
+        } else {
+            cell.style()
+        };
+        match runs.last_mut() {
+            Some(run) if run.style == style => {
+                run.columns += 1;
+                run.text.push(cell.character());
+            }
+            _ => runs.push(Run {
+                column: x as u16,
+                columns: 1,
+                text: cell.character().to_string(),
+                style,
+            }),
+        }
+    }
+
+    // Trim trailing blanks
+    while let Some(run) = runs.last_mut() {
+        if !run.is_visible() {
+            runs.pop();
+            continue;
+        }
+        if !run.style.underline() && !run.style.strikethrough() {
+            let blanks = run.text.len() - run.text.trim_end_matches(' ').len();
+            run.text.truncate(run.text.len() - blanks);
+            run.columns -= blanks as u16 * run.style.glyph_width();
+        }
+        break;
+    }
+    runs
+}
+
+/// Returns the vertical scale of a style's glyphs and their baseline, in
+/// lines below the top of the row.
+///
+/// Double-height glyphs extend over the next row; superscripts sit in the
+/// upper part of the line and subscripts on the normal baseline.
+fn vertical_metrics(style: StyleFlags) -> (f64, f64) {
+    if style.double_height() {
+        (2.0, 2.0 * BASELINE)
+    } else if style.superscript() {
+        (SCRIPT_SCALE, BASELINE * SCRIPT_SCALE)
+    } else if style.subscript() {
+        (SCRIPT_SCALE, BASELINE)
+    } else {
+        (1.0, BASELINE)
+    }
+}
+
+/// Appends `text` with the XML special characters escaped
+fn push_escaped(text: &str, output: &mut String) {
+    for ch in text.chars() {
+        match ch {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(ch),
+        }
+    }
+}
+
+/// Formats a length with at most three decimals and no trailing zeros.
+///
+/// Fixed precision keeps the output byte-for-byte deterministic.
+fn format_number(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(column: u16, columns: u16, text: &str, style: StyleFlags) -> Run {
+        Run {
+            column,
+            columns,
+            text: text.to_string(),
+            style,
+        }
+    }
+
+    #[test]
+    fn test_style_runs() {
+        let row = [
+            Cell::new('A', StyleFlags::BOLD),
+            Cell::new('b', StyleFlags::BOLD),
+            Cell::new(' ', StyleFlags::NONE),
+            Cell::new('c', StyleFlags::NONE),
+            Cell::new(' ', StyleFlags::NONE),
+            Cell::new(' ', StyleFlags::NONE),
+        ];
+        assert_eq!(
+            style_runs(&row),
+            [
+                run(0, 2, "Ab", StyleFlags::BOLD),
+                run(2, 2, " c", StyleFlags::NONE),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_style_runs_double_size() {
+        let wide = StyleFlags::DOUBLE_WIDTH;
+        let row = [
+            Cell::new('W', wide),
+            Cell::wide_tail(wide),
+            Cell::TALL_TAIL,
+            Cell::new('x', StyleFlags::NONE),
+        ];
+        assert_eq!(
+            style_runs(&row),
+            [run(0, 2, "W", wide), run(2, 2, " x", StyleFlags::NONE)]
+        );
+    }
+
+    #[test]
+    fn test_style_runs_keep_underlined_blanks() {
+        let row = [
+            Cell::new(' ', StyleFlags::UNDERLINE),
+            Cell::new(' ', StyleFlags::UNDERLINE),
+            Cell::new(' ', StyleFlags::NONE),
+        ];
+        assert_eq!(style_runs(&row), [run(0, 2, "  ", StyleFlags::UNDERLINE)]);
+        assert!(style_runs(&[Cell::EMPTY; 4]).is_empty());
+    }
+
+    #[test]
+    fn test_run_trimmed() {
+        let plain = run(3, 6, "  ab  ", StyleFlags::NONE);
+        assert_eq!(plain.trimmed(), Some(run(5, 2, "ab", StyleFlags::NONE)));
+
+        let wide = run(0, 6, " W ", StyleFlags::DOUBLE_WIDTH);
+        assert_eq!(
+            wide.trimmed(),
+            Some(run(2, 2, "W", StyleFlags::DOUBLE_WIDTH))
+        );
+
+        let underlined = run(0, 3, "   ", StyleFlags::UNDERLINE);
+        assert_eq!(underlined.trimmed(), Some(underlined.clone()));
+        assert_eq!(run(0, 2, "  ", StyleFlags::BOLD).trimmed(), None);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(8.0), "8");
+        assert_eq!(format_number(0.125), "0.125");
+        assert_eq!(format_number(1.0 / 3.0), "0.333");
+        assert_eq!(format_number(-0.0001), "0");
+    }
+
+    #[test]
+    fn test_push_escaped() {
+        let mut output = String::new();
+        push_escaped("<a href=\"x\">Tom & Jerry's</a>", &mut output);
+        assert_eq!(
+            output,
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+}
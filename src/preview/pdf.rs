@@ -0,0 +1,366 @@
+//! Deterministic PDF export using the standard Courier fonts.
+
+use super::{format_number, style_runs, vertical_metrics, Run, ADVANCE_EM, UNITS_PER_POINT};
+use crate::cell::StyleFlags;
+use crate::charset::box_drawing_fallback;
+use crate::document::Document;
+use crate::page::Page;
+
+/// Object number of the document catalog
+const CATALOG_ID: usize = 1;
+
+/// Object number of the page tree
+const PAGES_ID: usize = 2;
+
+/// Object number of the first font; fonts are numbered in `FONTS` order
+const FIRST_FONT_ID: usize = 3;
+
+/// Standard Type 1 fonts: regular, bold, oblique and bold oblique Courier
+const FONTS: [&str; 4] = [
+    "Courier",
+    "Courier-Bold",
+    "Courier-Oblique",
+    "Courier-BoldOblique",
+];
+
+/// Object number of the first page; each page is followed by its content
+const FIRST_PAGE_ID: usize = FIRST_FONT_ID + FONTS.len();
+
+/// Underline offset below the baseline, as a fraction of the font size
+const UNDERLINE_OFFSET: f64 = 0.1;
+
+/// Strikethrough offset above the baseline, as a fraction of the font size
+const STRIKETHROUGH_OFFSET: f64 = 0.25;
+
+/// Rule thickness, as a fraction of the font size
+const RULE_THICKNESS: f64 = 0.05;
+
+/// Renders a document as a PDF with one page per [`Page`].
+///
+/// Each page is sized to the paper covered by its grid, and the Courier
+/// font size is chosen so one glyph advance is one column at the document's
+/// pitch. The file has no creation date or ID, so identical documents give
+/// identical bytes.
+pub(crate) fn document_pdf(document: &Document) -> Vec<u8> {
+    let char_width = document.pitch().char_width();
+    let line_spacing = document.line_spacing().units();
+    let pages = document.pages();
+
+    let mut pdf = PdfWriter::new();
+    pdf.object(CATALOG_ID, b"<< /Type /Catalog /Pages 2 0 R >>");
+
+    let kids: Vec<String> = (0..pages.len())
+        .map(|index| format!("{} 0 R", FIRST_PAGE_ID + 2 * index))
+        .collect();
+    pdf.object(
+        PAGES_ID,
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        )
+        .as_bytes(),
+    );
+
+    for (index, font) in FONTS.iter().enumerate() {
+        pdf.object(
+            FIRST_FONT_ID + index,
+            format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                font
+            )
+            .as_bytes(),
+        );
+    }
+
+    let font_resources: Vec<String> = (0..FONTS.len())
+        .map(|index| format!("/F{} {} 0 R", index + 1, FIRST_FONT_ID + index))
+        .collect();
+    for (index, page) in pages.iter().enumerate() {
+        let id = FIRST_PAGE_ID + 2 * index;
+        let geometry = page.geometry();
+        let width = geometry.columns() as u32 * char_width;
+        let height = geometry.rows() as u32 * line_spacing;
+        pdf.object(
+            id,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << {} >> >> /Contents {} 0 R >>",
+                format_number(width as f64 / UNITS_PER_POINT),
+                format_number(height as f64 / UNITS_PER_POINT),
+                font_resources.join(" "),
+                id + 1
+            )
+            .as_bytes(),
+        );
+        pdf.stream(id + 1, &page_content(page, char_width, line_spacing));
+    }
+
+    pdf.finish()
+}
+
+/// Builds the content stream drawing a page, one text object per run.
+fn page_content(page: &Page, char_width: u32, line_spacing: u32) -> Vec<u8> {
+    let page_height = page.geometry().rows() as f64 * line_spacing as f64 / UNITS_PER_POINT;
+    let column = char_width as f64 / UNITS_PER_POINT;
+    let line = line_spacing as f64 / UNITS_PER_POINT;
+    let font_size = column / ADVANCE_EM;
+
+    let mut content = Vec::new();
+    for (y, row) in page.rows().enumerate() {
+        let top = y as f64 * line;
+        for run in style_runs(row).iter().filter_map(Run::trimmed) {
+            let (scale_y, baseline) = vertical_metrics(run.style);
+            let x = run.column as f64 * column;
+            let baseline = page_height - top - line * baseline;
+
+            content.extend_from_slice(
+                format!(
+                    "BT /F{} {} Tf {} 0 0 {} {} {} Tm (",
+                    font_number(run.style),
+                    format_number(font_size),
+                    run.style.glyph_width(),
+                    format_number(scale_y),
+                    format_number(x),
+                    format_number(baseline),
+                )
+                .as_bytes(),
+            );
+            push_pdf_string(&run.text, &mut content);
+            content.extend_from_slice(b") Tj ET\n");
+
+            let width = run.columns as f64 * column;
+            let thickness = font_size * RULE_THICKNESS;
+            if run.style.underline() {
+                let y = baseline - font_size * scale_y * UNDERLINE_OFFSET;
+                push_rule(x, y, width, thickness, &mut content);
+            }
+            if run.style.strikethrough() {
+                let y = baseline + font_size * scale_y * STRIKETHROUGH_OFFSET;
+                push_rule(x, y, width, thickness, &mut content);
+            }
+        }
+    }
+    content
+}
+
+/// Returns the resource number of the Courier variant showing a style.
+///
+/// Double-strike prints darker, so it uses the bold font.
+fn font_number(style: StyleFlags) -> usize {
+    let bold = style.bold() || style.double_strike();
+    match (bold, style.italic()) {
+        (false, false) => 1,
+        (true, false) => 2,
+        (false, true) => 3,
+        (true, true) => 4,
+    }
+}
+
+/// Appends a filled rectangle for an underline or strikethrough
+fn push_rule(x: f64, y: f64, width: f64, thickness: f64, content: &mut Vec<u8>) {
+    content.extend_from_slice(
+        format!(
+            "{} {} {} {} re f\n",
+            format_number(x),
+            format_number(y - thickness / 2.0),
+            format_number(width),
+            format_number(thickness),
+        )
+        .as_bytes(),
+    );
+}
+
+/// Appends `text` encoded in WinAnsi with the string delimiters escaped.
+///
+/// Box-drawing characters fall back to their ASCII look-alikes; other
+/// characters missing from WinAnsi print as '?'.
+fn push_pdf_string(text: &str, output: &mut Vec<u8>) {
+    for ch in text.chars() {
+        let byte = win_ansi(ch)
+            .or_else(|| box_drawing_fallback(ch).and_then(win_ansi))
+            .unwrap_or(b'?');
+        if matches!(byte, b'(' | b')' | b'\\') {
+            output.push(b'\\');
+        }
+        output.push(byte);
+    }
+}
+
+/// Returns the WinAnsiEncoding byte of a character, if it has one
+fn win_ansi(ch: char) -> Option<u8> {
+    let byte = match ch {
+        ' '..='~' | '\u{A0}'..='\u{FF}' => ch as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        'ƒ' => 0x83,
+        '„' => 0x84,
+        '…' => 0x85,
+        '†' => 0x86,
+        '‡' => 0x87,
+        'ˆ' => 0x88,
+        '‰' => 0x89,
+        'Š' => 0x8A,
+        '‹' => 0x8B,
+        'Œ' => 0x8C,
+        'Ž' => 0x8E,
+        '\u{2018}' => 0x91,
+        '\u{2019}' => 0x92,
+        '\u{201C}' => 0x93,
+        '\u{201D}' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '˜' => 0x98,
+        '™' => 0x99,
+        'š' => 0x9A,
+        '›' => 0x9B,
+        'œ' => 0x9C,
+        'ž' => 0x9E,
+        'Ÿ' => 0x9F,
+        _ => return None,
+    };
+    Some(byte)
+}
+
+/// Writes PDF objects in order and records their offsets for the
+/// cross-reference table.
+struct PdfWriter {
+    output: Vec<u8>,
+    /// Byte offset of each object, indexed by object number - 1
+    offsets: Vec<usize>,
+}
+
+impl PdfWriter {
+    /// Starts a PDF 1.4 file
+    fn new() -> Self {
+        // The binary comment marks the file as binary for transfer tools
+        let mut output = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        output.reserve(4096);
+        PdfWriter {
+            output,
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Writes an object; objects must be written in number order
+    fn object(&mut self, id: usize, body: &[u8]) {
+        debug_assert_eq!(id, self.offsets.len() + 1);
+        self.offsets.push(self.output.len());
+        self.output
+            .extend_from_slice(format!("{} 0 obj\n", id).as_bytes());
+        self.output.extend_from_slice(body);
+        self.output.extend_from_slice(b"\nendobj\n");
+    }
+
+    /// Writes an uncompressed stream object
+    fn stream(&mut self, id: usize, data: &[u8]) {
+        let mut body = format!("<< /Length {} >>\nstream\n", data.len()).into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\nendstream");
+        self.object(id, &body);
+    }
+
+    /// Appends the cross-reference table and trailer
+    fn finish(mut self) -> Vec<u8> {
+        let xref_offset = self.output.len();
+        let size = self.offsets.len() + 1;
+        self.output
+            .extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", size).as_bytes());
+        for offset in &self.offsets {
+            // Entries are exactly 20 bytes, including the two-byte EOL
+            self.output
+                .extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        self.output.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+                size, CATALOG_ID, xref_offset
+            )
+            .as_bytes(),
+        );
+        self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{PageGeometry, Pitch};
+
+    fn document(build: impl FnOnce(&mut crate::page::PageBuilder)) -> Document {
+        let mut page_builder = Page::builder_with_geometry(PageGeometry::new(80, 66));
+        build(&mut page_builder);
+        let mut builder = Document::builder();
+        builder.pitch(Pitch::Cpi10).add_page(page_builder.build());
+        builder.build()
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    }
+
+    #[test]
+    fn test_pdf_structure() {
+        let pdf = document_pdf(&document(|_| {}));
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(contains(&pdf, b"/Kids [7 0 R] /Count 1"));
+        // Letter-size page for an 80x66 grid at 10 cpi and 1/6-inch spacing
+        assert!(contains(&pdf, b"/MediaBox [0 0 576 792]"));
+        assert!(contains(&pdf, b"/BaseFont /Courier-BoldOblique"));
+        assert!(contains(&pdf, b"trailer\n<< /Size 9 /Root 1 0 R >>"));
+    }
+
+    #[test]
+    fn test_pdf_xref_offsets() {
+        let pdf = document_pdf(&document(|page| {
+            page.write_str(0, 0, "Hello", StyleFlags::NONE);
+        }));
+        let text = String::from_utf8_lossy(&pdf);
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[startxref..].starts_with(b"xref\n0 9\n"));
+
+        let entries = String::from_utf8_lossy(&pdf[startxref..]);
+        for (id, line) in entries.lines().skip(3).take(8).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            let header = format!("{} 0 obj\n", id + 1);
+            assert!(pdf[offset..].starts_with(header.as_bytes()));
+            assert_eq!(line.len() + 1, 20);
+        }
+    }
+
+    #[test]
+    fn test_pdf_text_runs() {
+        let pdf = document_pdf(&document(|page| {
+            page.write_str(2, 0, "Total (EUR)", StyleFlags::BOLD.with_underline());
+            page.write_str(0, 1, "W", StyleFlags::DOUBLE_WIDTH.with_italic());
+            page.write_str(0, 2, "€ 5 ═", StyleFlags::NONE);
+        }));
+        assert!(contains(
+            &pdf,
+            b"BT /F2 12 Tf 1 0 0 1 14.4 783 Tm (Total \\(EUR\\)) Tj ET\n\
+              14.4 781.5 79.2 0.6 re f\n"
+        ));
+        assert!(contains(&pdf, b"BT /F3 12 Tf 2 0 0 1 0 771 Tm (W) Tj ET\n"));
+        assert!(contains(
+            &pdf,
+            b"BT /F1 12 Tf 1 0 0 1 0 759 Tm (\x80 5 =) Tj ET\n"
+        ));
+    }
+
+    #[test]
+    fn test_pdf_deterministic() {
+        let document = document(|page| {
+            page.write_str(0, 0, "Invoice", StyleFlags::BOLD);
+        });
+        assert_eq!(document_pdf(&document), document_pdf(&document));
+    }
+}
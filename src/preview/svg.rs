@@ -0,0 +1,160 @@
+//! SVG print preview.
+
+use super::{
+    format_number, push_escaped, style_runs, vertical_metrics, Run, ADVANCE_EM, FONT_FAMILY,
+    UNITS_PER_POINT,
+};
+use crate::document::Document;
+use crate::page::Page;
+
+/// Renders each page of a document as an SVG image sized to the paper.
+pub(crate) fn document_svg(document: &Document) -> Vec<String> {
+    document
+        .pages()
+        .iter()
+        .map(|page| {
+            page_svg(
+                page,
+                document.pitch().char_width(),
+                document.line_spacing().units(),
+            )
+        })
+        .collect()
+}
+
+/// Renders a page with one `<text>` element per visible style run, trimmed
+/// of blanks.
+///
+/// User units are the 1/360-inch paper units; `width` and `height` give the
+/// physical size in points. Each run is stretched to its columns with
+/// `textLength`, so glyphs land exactly on the printer grid.
+fn page_svg(page: &Page, char_width: u32, line_spacing: u32) -> String {
+    let geometry = page.geometry();
+    let width = geometry.columns() as u32 * char_width;
+    let height = geometry.rows() as u32 * line_spacing;
+    let font_size = char_width as f64 / ADVANCE_EM;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}pt\" height=\"{}pt\" \
+         viewBox=\"0 0 {} {}\" font-family=\"{}\" font-size=\"{}\" xml:space=\"preserve\">\n",
+        format_number(width as f64 / UNITS_PER_POINT),
+        format_number(height as f64 / UNITS_PER_POINT),
+        width,
+        height,
+        FONT_FAMILY,
+        format_number(font_size),
+    );
+    svg.push_str(&format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"#fff\"/>\n",
+        width, height
+    ));
+
+    for (y, row) in page.rows().enumerate() {
+        let top = y as f64 * line_spacing as f64;
+        for run in style_runs(row).iter().filter_map(Run::trimmed) {
+            push_text(&run, top, char_width, line_spacing, font_size, &mut svg);
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Appends the `<text>` element for one run.
+fn push_text(
+    run: &Run,
+    top: f64,
+    char_width: u32,
+    line_spacing: u32,
+    font_size: f64,
+    output: &mut String,
+) {
+    let style = run.style;
+    let (scale_y, baseline) = vertical_metrics(style);
+    let size = font_size * scale_y;
+    let baseline = top + line_spacing as f64 * baseline;
+
+    output.push_str(&format!(
+        "<text x=\"{}\" y=\"{}\" textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\"",
+        run.column as u32 * char_width,
+        format_number(baseline),
+        run.columns as u32 * char_width,
+    ));
+    if size != font_size {
+        output.push_str(&format!(" font-size=\"{}\"", format_number(size)));
+    }
+    if style.bold() || style.double_strike() {
+        output.push_str(" font-weight=\"bold\"");
+    }
+    if style.italic() {
+        output.push_str(" font-style=\"italic\"");
+    }
+    match (style.underline(), style.strikethrough()) {
+        (true, true) => output.push_str(" text-decoration=\"underline line-through\""),
+        (true, false) => output.push_str(" text-decoration=\"underline\""),
+        (false, true) => output.push_str(" text-decoration=\"line-through\""),
+        (false, false) => {}
+    }
+    output.push('>');
+    push_escaped(&run.text, output);
+    output.push_str("</text>\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::StyleFlags;
+    use crate::geometry::PageGeometry;
+
+    #[test]
+    fn test_svg_sized_to_paper() {
+        let page = Page::builder_with_geometry(PageGeometry::new(80, 66)).build();
+        let svg = page_svg(&page, 36, 60);
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"576pt\" height=\"792pt\" \
+             viewBox=\"0 0 2880 3960\""
+        ));
+        assert!(svg.contains("font-size=\"60\""));
+        assert!(svg.ends_with("<rect width=\"2880\" height=\"3960\" fill=\"#fff\"/>\n</svg>\n"));
+    }
+
+    #[test]
+    fn test_svg_text_per_run() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(20, 3));
+        builder.write_str(2, 0, "Name: Bob", StyleFlags::NONE);
+        builder.write_str(12, 0, "<b>", StyleFlags::BOLD.with_underline());
+        builder.write_str(0, 1, "W", StyleFlags::DOUBLE_WIDTH);
+        builder.write_str(0, 2, "    ", StyleFlags::NONE);
+        let svg = page_svg(&builder.build(), 36, 60);
+
+        let texts: Vec<&str> = svg.lines().filter(|l| l.starts_with("<text")).collect();
+        assert_eq!(
+            texts,
+            [
+                "<text x=\"72\" y=\"45\" textLength=\"324\" \
+                 lengthAdjust=\"spacingAndGlyphs\">Name: Bob</text>",
+                "<text x=\"432\" y=\"45\" textLength=\"108\" lengthAdjust=\"spacingAndGlyphs\" \
+                 font-weight=\"bold\" text-decoration=\"underline\">&lt;b&gt;</text>",
+                "<text x=\"0\" y=\"105\" textLength=\"72\" \
+                 lengthAdjust=\"spacingAndGlyphs\">W</text>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_svg_double_height_and_scripts() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(20, 3));
+        builder.write_str(0, 0, "T", StyleFlags::DOUBLE_HEIGHT);
+        builder.write_str(2, 0, "2", StyleFlags::SUPERSCRIPT);
+        let svg = page_svg(&builder.build(), 36, 60);
+
+        assert!(svg.contains(
+            "<text x=\"0\" y=\"90\" textLength=\"36\" \
+             lengthAdjust=\"spacingAndGlyphs\" font-size=\"120\">T</text>"
+        ));
+        assert!(svg.contains(
+            "<text x=\"72\" y=\"27\" textLength=\"36\" \
+             lengthAdjust=\"spacingAndGlyphs\" font-size=\"36\">2</text>"
+        ));
+    }
+}
@@ -0,0 +1,191 @@
+//! Plain-text and ANSI terminal previews of pages and documents.
+
+use crate::cell::{Cell, StyleFlags};
+use crate::document::Document;
+use crate::page::Page;
+
+/// ANSI escape resetting all text attributes
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Renders a page as text, one line per row.
+///
+/// Trailing blanks and trailing blank rows are trimmed. Double-width glyphs
+/// keep their two columns (the glyph followed by a space) so columns line up
+/// with the printed page; the lower half of double-height glyphs is blank.
+pub(crate) fn page_text(page: &Page, ansi: bool) -> String {
+    let lines: Vec<String> = page.rows().map(|row| line_text(row, ansi)).collect();
+    let used = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+
+    let mut text = String::new();
+    for line in &lines[..used] {
+        text.push_str(line);
+        text.push('\n');
+    }
+    text
+}
+
+/// Renders a document page by page, with a marker line before every page
+/// after the first.
+pub(crate) fn document_text(document: &Document, ansi: bool) -> String {
+    let mut text = String::new();
+    for (index, page) in document.pages().iter().enumerate() {
+        if index > 0 {
+            text.push_str(&page_break_marker(index + 1));
+            text.push('\n');
+        }
+        text.push_str(&page_text(page, ansi));
+    }
+    text
+}
+
+/// Returns the marker line shown above page `number` (1-based)
+fn page_break_marker(number: usize) -> String {
+    format!("--- page {} ---", number)
+}
+
+/// Renders one row, trimmed after its last visible cell.
+pub(crate) fn line_text(row: &[Cell], ansi: bool) -> String {
+    let visible = |cell: &Cell| {
+        !cell.is_covered()
+            && (cell.character() != ' '
+                || ansi && (cell.style().underline() || cell.style().strikethrough()))
+    };
+    let len = row.iter().rposition(visible).map_or(0, |last| last + 1);
+
+    let mut line = String::new();
+    let mut current = StyleFlags::NONE;
+    for cell in &row[..len] {
+        // Covered cells read as spaces; wide tails keep the glyph's style
+        let (character, style) = (cell.character(), cell.style());
+        if ansi && sgr_style(style) != sgr_style(current) {
+            push_sgr(style, &mut line);
+        }
+        current = style;
+        line.push(character);
+    }
+    if ansi && sgr_style(current) != StyleFlags::NONE {
+        line.push_str(ANSI_RESET);
+    }
+    line
+}
+
+/// Keeps only the attributes a terminal can show
+fn sgr_style(style: StyleFlags) -> StyleFlags {
+    StyleFlags::NONE
+        .set(StyleFlags::BOLD, style.bold())
+        .set(StyleFlags::ITALIC, style.italic())
+        .set(StyleFlags::UNDERLINE, style.underline())
+        .set(StyleFlags::STRIKETHROUGH, style.strikethrough())
+}
+
+/// Appends the SGR sequence switching to `style`.
+///
+/// Always resets first, so switching attributes off needs no extra codes.
+fn push_sgr(style: StyleFlags, output: &mut String) {
+    output.push_str("\x1b[0");
+    if style.bold() {
+        output.push_str(";1");
+    }
+    if style.italic() {
+        output.push_str(";3");
+    }
+    if style.underline() {
+        output.push_str(";4");
+    }
+    if style.strikethrough() {
+        output.push_str(";9");
+    }
+    output.push('m');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::PageGeometry;
+
+    fn page(build: impl FnOnce(&mut crate::page::PageBuilder)) -> Page {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(20, 6));
+        build(&mut builder);
+        builder.build()
+    }
+
+    #[test]
+    fn test_plain_text_trims_blanks() {
+        let page = page(|p| {
+            p.write_str(2, 0, "Hello", StyleFlags::BOLD);
+            p.write_str(0, 2, "World", StyleFlags::NONE);
+            p.write_str(10, 2, "   ", StyleFlags::UNDERLINE);
+        });
+        assert_eq!(page_text(&page, false), "  Hello\n\nWorld\n");
+    }
+
+    #[test]
+    fn test_plain_text_empty_page() {
+        assert_eq!(page_text(&page(|_| {}), false), "");
+    }
+
+    #[test]
+    fn test_plain_text_double_size() {
+        let page = page(|p| {
+            p.write_str(0, 0, "AB", StyleFlags::DOUBLE_WIDTH);
+            p.write_str(5, 0, "T", StyleFlags::DOUBLE_HEIGHT);
+            p.write_str(6, 1, "x", StyleFlags::NONE);
+        });
+        assert_eq!(page_text(&page, false), "A B  T\n      x\n");
+    }
+
+    #[test]
+    fn test_ansi_styles() {
+        let page = page(|p| {
+            p.write_str(0, 0, "ab", StyleFlags::BOLD);
+            p.write_str(2, 0, "cd", StyleFlags::BOLD.with_underline());
+            p.write_str(4, 0, "e", StyleFlags::NONE);
+            p.write_str(0, 1, "f", StyleFlags::ITALIC.with_strikethrough());
+            p.write_str(0, 2, "g", StyleFlags::DOUBLE_STRIKE);
+        });
+        assert_eq!(
+            page_text(&page, true),
+            "\x1b[0;1mab\x1b[0;1;4mcd\x1b[0me\n\x1b[0;3;9mf\x1b[0m\ng\n"
+        );
+    }
+
+    #[test]
+    fn test_ansi_double_width_keeps_style() {
+        let page = page(|p| {
+            p.write_str(0, 0, "AB", StyleFlags::DOUBLE_WIDTH.with_bold());
+        });
+        assert_eq!(page_text(&page, true), "\x1b[0;1mA B\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_ansi_keeps_underlined_blanks() {
+        let page = page(|p| {
+            p.write_str(0, 0, "Sign:", StyleFlags::NONE);
+            p.write_str(6, 0, "    ", StyleFlags::UNDERLINE);
+        });
+        assert_eq!(page_text(&page, true), "Sign: \x1b[0;4m    \x1b[0m\n");
+        assert_eq!(page_text(&page, false), "Sign:\n");
+    }
+
+    #[test]
+    fn test_document_page_breaks() {
+        let mut builder = Document::builder();
+        builder
+            .add_page(page(|p| {
+                p.write_str(0, 0, "one", StyleFlags::NONE);
+            }))
+            .add_page(page(|_| {}))
+            .add_page(page(|p| {
+                p.write_str(0, 1, "three", StyleFlags::NONE);
+            }));
+        let document = builder.build();
+
+        assert_eq!(
+            document_text(&document, false),
+            "one\n--- page 2 ---\n--- page 3 ---\n\nthree\n"
+        );
+    }
+}
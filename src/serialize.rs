@@ -0,0 +1,424 @@
+//! Serde support for cells, styles, pages and documents (`serde` feature).
+//!
+//! Pages use a compact form instead of one object per cell: the text of
+//! each row, trimmed of trailing blanks, plus runs of styled cells and runs
+//! of cells covered by double-size glyphs. A blank 160×51 page serializes
+//! to `{"columns":160,"rows":51,"lines":[]}`.
+//!
+//! Deserialized pages are limited to the largest grid the printer can fill,
+//! [`MAX_COLUMNS`] × [`MAX_ROWS`] cells; larger dimensions are rejected
+//! before any cells are allocated. Documents are limited to
+//! [`MAX_DOCUMENT_CELLS`] cells in all, and are rebuilt with
+//! [`DocumentBuilder`](crate::DocumentBuilder) so pages larger than the
+//! printer's form are cut as usual.
+
+use crate::cell::{Cell, StyleFlags};
+use crate::charset::{CharacterTable, InternationalCharset};
+use crate::document::Document;
+use crate::escp::constants::{MAX_FORM_LENGTH, MAX_PRINT_WIDTH};
+use crate::geometry::{LineSpacing, PageGeometry, Pitch};
+use crate::page::Page;
+use serde_dep::de::{Error as _, SeqAccess, Visitor};
+use serde_dep::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Style flags serialize as their bit mask (bold = 1, underline = 2, ...).
+impl Serialize for StyleFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for StyleFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u16::deserialize(deserializer)?;
+        StyleFlags::from_bits(bits)
+            .ok_or_else(|| D::Error::custom(format!("invalid style flags {:#x}", bits)))
+    }
+}
+
+/// Cells serialize as a `(character, style)` pair.
+///
+/// Covered cells use U+0000 (right half of a double-width glyph) and U+0001
+/// (lower half of a double-height glyph), which no printable cell holds.
+impl Serialize for Cell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.character, self.style).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cell {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (character, style) = <(char, StyleFlags)>::deserialize(deserializer)?;
+        Ok(match character {
+            '\u{0}' => Cell::wide_tail(style),
+            '\u{1}' => Cell::TALL_TAIL,
+            _ => Cell::new(character, style),
+        })
+    }
+}
+
+/// Most columns a deserialized page may have: 13.6 inches at 20 cpi
+const MAX_COLUMNS: u16 = (MAX_PRINT_WIDTH / Pitch::Condensed20.char_width()) as u16;
+
+/// Most rows a deserialized page may have: 22 inches at 1/360-inch spacing
+const MAX_ROWS: u16 = MAX_FORM_LENGTH as u16;
+
+/// Most cells all pages of a deserialized document may have together,
+/// about 2,000 pages of 160×51
+const MAX_DOCUMENT_CELLS: usize = 1 << 24;
+
+/// Compact form of a page
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "serde_dep")]
+struct PageRepr {
+    columns: u16,
+    rows: u16,
+    /// Row text, trimmed of trailing blanks and trailing blank rows
+    lines: Vec<String>,
+    /// Runs of styled cells: (row, column, length, style)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    styles: Vec<(u16, u16, u16, StyleFlags)>,
+    /// Runs of covered cells: (row, column, length)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    covered: Vec<(u16, u16, u16)>,
+}
+
+impl PageRepr {
+    fn new(page: &Page) -> Self {
+        let mut lines = Vec::new();
+        let mut styles = Vec::new();
+        let mut covered = Vec::new();
+        for (y, row) in (0u16..).zip(page.rows()) {
+            let line: String = row.iter().map(Cell::character).collect();
+            lines.push(line.trim_end_matches(' ').to_string());
+
+            let style_runs = runs(row, Cell::style, |style| *style != StyleFlags::NONE);
+            styles.extend(style_runs.map(|(x, len, style)| (y, x, len, style)));
+            let covered_runs = runs(row, Cell::is_covered, |covered| *covered);
+            covered.extend(covered_runs.map(|(x, len, _)| (y, x, len)));
+        }
+        let used = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |last| last + 1);
+        lines.truncate(used);
+
+        PageRepr {
+            columns: page.geometry().columns(),
+            rows: page.geometry().rows(),
+            lines,
+            styles,
+            covered,
+        }
+    }
+
+    fn into_page(self) -> Result<Page, String> {
+        let geometry = PageGeometry::new(self.columns, self.rows);
+        if (geometry.columns(), geometry.rows()) != (self.columns, self.rows) {
+            return Err("page must have at least one column and one row".to_string());
+        }
+        if self.columns > MAX_COLUMNS || self.rows > MAX_ROWS {
+            return Err(format!(
+                "{}×{} page exceeds the {}×{} limit",
+                self.columns, self.rows, MAX_COLUMNS, MAX_ROWS
+            ));
+        }
+        if self.lines.len() > self.rows as usize {
+            return Err(format!(
+                "{} lines on a {}-row page",
+                self.lines.len(),
+                self.rows
+            ));
+        }
+
+        let columns = self.columns as usize;
+        let mut characters = vec![' '; geometry.cell_count()];
+        for (y, line) in self.lines.iter().enumerate() {
+            let start = y * columns;
+            for (x, character) in line.chars().enumerate() {
+                if x == columns {
+                    return Err(format!("line {} is longer than {} columns", y, columns));
+                }
+                characters[start + x] = character;
+            }
+        }
+
+        let mut styles = vec![StyleFlags::NONE; geometry.cell_count()];
+        for &(y, x, len, style) in &self.styles {
+            styles[run_range(geometry, y, x, len)?].fill(style);
+        }
+        let mut cells: Vec<Cell> = characters
+            .into_iter()
+            .zip(styles)
+            .map(|(character, style)| Cell::new(character, style))
+            .collect();
+        for &(y, x, len) in &self.covered {
+            for cell in &mut cells[run_range(geometry, y, x, len)?] {
+                *cell = if cell.style().double_width() {
+                    Cell::wide_tail(cell.style())
+                } else {
+                    Cell::TALL_TAIL
+                };
+            }
+        }
+        Ok(Page::from_cells(geometry, cells.into_boxed_slice()))
+    }
+}
+
+/// Returns the runs of adjacent cells sharing a key the filter accepts, as
+/// (column, length, key).
+fn runs<'a, K: PartialEq + Copy + 'a>(
+    row: &'a [Cell],
+    key: impl Fn(&Cell) -> K + 'a,
+    keep: impl Fn(&K) -> bool + 'a,
+) -> impl Iterator<Item = (u16, u16, K)> + 'a {
+    let mut runs: Vec<(u16, u16, K)> = Vec::new();
+    for (x, cell) in (0u16..).zip(row) {
+        let key = key(cell);
+        match runs.last_mut() {
+            Some((_, len, last)) if *last == key => *len += 1,
+            _ => runs.push((x, 1, key)),
+        }
+    }
+    runs.into_iter().filter(move |(_, _, key)| keep(key))
+}
+
+/// Returns the cell index range of a run, or an error if it leaves the page
+fn run_range(
+    geometry: PageGeometry,
+    y: u16,
+    x: u16,
+    len: u16,
+) -> Result<std::ops::Range<usize>, String> {
+    let end = x as usize + len as usize;
+    if y >= geometry.rows() || end > geometry.columns() as usize {
+        return Err(format!("run at row {} column {} leaves the page", y, x));
+    }
+    let start = y as usize * geometry.columns() as usize;
+    Ok(start + x as usize..start + end)
+}
+
+/// Pages serialize in a compact form: see the module documentation.
+impl Serialize for Page {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PageRepr::new(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Page {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PageRepr::deserialize(deserializer)?
+            .into_page()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Document fields as serialized by the derived `Serialize`
+#[derive(Deserialize)]
+#[serde(crate = "serde_dep")]
+struct DocumentRepr {
+    #[serde(deserialize_with = "deserialize_pages")]
+    pages: Vec<Page>,
+    pitch: Pitch,
+    line_spacing: LineSpacing,
+    character_table: CharacterTable,
+    international_charset: InternationalCharset,
+    fallback_char: char,
+}
+
+/// Deserializes pages until they exceed [`MAX_DOCUMENT_CELLS`] in all
+fn deserialize_pages<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Page>, D::Error> {
+    struct PagesVisitor;
+
+    impl<'de> Visitor<'de> for PagesVisitor {
+        type Value = Vec<Page>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a list of pages")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<Page>, A::Error> {
+            let mut pages = Vec::new();
+            let mut cells = 0;
+            while let Some(page) = seq.next_element::<Page>()? {
+                cells += page.geometry().cell_count();
+                if cells > MAX_DOCUMENT_CELLS {
+                    return Err(A::Error::custom(format!(
+                        "document exceeds {} cells",
+                        MAX_DOCUMENT_CELLS
+                    )));
+                }
+                pages.push(page);
+            }
+            Ok(pages)
+        }
+    }
+
+    deserializer.deserialize_seq(PagesVisitor)
+}
+
+impl<'de> Deserialize<'de> for Document {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = DocumentRepr::deserialize(deserializer)?;
+        let mut builder = Document::builder();
+        builder
+            .pitch(repr.pitch)
+            .line_spacing(repr.line_spacing)
+            .character_table(repr.character_table)
+            .international_charset(repr.international_charset)
+            .fallback_char(repr.fallback_char);
+        for page in repr.pages {
+            builder.add_page(page);
+        }
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+        let json = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_style_flags() {
+        let style = StyleFlags::BOLD.with_underline().with_double_height();
+        assert_eq!(serde_json::to_string(&style).unwrap(), "259");
+        assert_eq!(round_trip(&style), style);
+
+        assert!(serde_json::from_str::<StyleFlags>("512").is_err());
+        assert!(serde_json::from_str::<StyleFlags>("48").is_err());
+    }
+
+    #[test]
+    fn test_cell() {
+        let cell = Cell::new('é', StyleFlags::BOLD);
+        assert_eq!(serde_json::to_string(&cell).unwrap(), "[\"é\",1]");
+        assert_eq!(round_trip(&cell), cell);
+
+        let tail = Cell::wide_tail(StyleFlags::DOUBLE_WIDTH);
+        assert_eq!(round_trip(&tail), tail);
+        assert_eq!(round_trip(&Cell::TALL_TAIL), Cell::TALL_TAIL);
+
+        let cell: Cell = serde_json::from_str("[\"\\n\",0]").unwrap();
+        assert_eq!(cell.character(), '?');
+    }
+
+    #[test]
+    fn test_blank_page() {
+        let page = Page::builder().build();
+        assert_eq!(
+            serde_json::to_string(&page).unwrap(),
+            "{\"columns\":160,\"rows\":51,\"lines\":[]}"
+        );
+        assert_eq!(round_trip(&page), page);
+    }
+
+    #[test]
+    fn test_page_larger_than_printer_grid_rejected() {
+        let json = r#"{"columns":65535,"rows":65535,"lines":[]}"#;
+        let error = serde_json::from_str::<Page>(json).unwrap_err();
+        assert!(error.to_string().contains("exceeds the 272×7920 limit"));
+        assert!(serde_json::from_str::<Page>(r#"{"columns":273,"rows":1,"lines":[]}"#).is_err());
+        assert!(serde_json::from_str::<Page>(r#"{"columns":1,"rows":7921,"lines":[]}"#).is_err());
+
+        let json = r#"{"columns":272,"rows":7920,"lines":[]}"#;
+        let page: Page = serde_json::from_str(json).unwrap();
+        assert_eq!(page.geometry(), PageGeometry::new(272, 7920));
+    }
+
+    #[test]
+    fn test_page_compact_form() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(12, 4));
+        builder.write_str(0, 0, "Total", StyleFlags::BOLD);
+        builder.write_str(6, 0, "42", StyleFlags::NONE);
+        builder.write_str(0, 1, "W", StyleFlags::DOUBLE_WIDTH.with_double_height());
+        let page = builder.build();
+
+        assert_eq!(
+            serde_json::to_string(&page).unwrap(),
+            "{\"columns\":12,\"rows\":4,\"lines\":[\"Total 42\",\"W\"],\
+             \"styles\":[[0,0,5,1],[1,0,2,384]],\"covered\":[[1,1,1],[2,0,2]]}"
+        );
+        assert_eq!(round_trip(&page), page);
+    }
+
+    #[test]
+    fn test_page_keeps_orphaned_halves() {
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(8, 3));
+        builder.write_str(0, 0, "AB", StyleFlags::DOUBLE_WIDTH);
+        builder.write_at(2, 0, 'x', StyleFlags::NONE);
+        builder.write_at(0, 1, 'T', StyleFlags::DOUBLE_HEIGHT);
+        builder.write_at(0, 2, 'y', StyleFlags::UNDERLINE);
+        let page = builder.build();
+        assert_eq!(round_trip(&page), page);
+    }
+
+    #[test]
+    fn test_page_rejects_invalid_input() {
+        let invalid = [
+            "{\"columns\":0,\"rows\":1,\"lines\":[]}",
+            "{\"columns\":2,\"rows\":1,\"lines\":[\"abc\"]}",
+            "{\"columns\":2,\"rows\":1,\"lines\":[\"a\",\"b\"]}",
+            "{\"columns\":2,\"rows\":1,\"lines\":[],\"styles\":[[0,1,2,1]]}",
+            "{\"columns\":2,\"rows\":1,\"lines\":[],\"covered\":[[1,0,1]]}",
+        ];
+        for json in invalid {
+            assert!(serde_json::from_str::<Page>(json).is_err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn test_document() {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, "Grüße", StyleFlags::ITALIC);
+
+        let mut builder = Document::builder();
+        builder
+            .pitch(Pitch::Cpi12)
+            .line_spacing(LineSpacing::Units360(50))
+            .character_table(CharacterTable::Pc850)
+            .fallback_char('#')
+            .add_page(page_builder.build())
+            .add_page(Page::builder().build());
+        let document = builder.build();
+
+        let decoded = round_trip(&document);
+        assert_eq!(decoded, document);
+        assert_eq!(decoded.render(), document.render());
+    }
+
+    /// Document JSON with the given pages and line spacing
+    fn document_json(pages: &[&str], line_spacing: &str) -> String {
+        format!(
+            r#"{{"pages":[{}],"pitch":"Cpi10","line_spacing":{},"character_table":"Ascii","international_charset":"Usa","fallback_char":"?"}}"#,
+            pages.join(","),
+            line_spacing
+        )
+    }
+
+    #[test]
+    fn test_document_cell_limit() {
+        let page = r#"{"columns":272,"rows":7920,"lines":[]}"#;
+        let spacing = r#"{"Units360":1}"#;
+
+        // 7 pages of 272×7920 cells fit in 2^24 cells, 8 do not
+        let decoded: Document = serde_json::from_str(&document_json(&[page; 7], spacing)).unwrap();
+        assert_eq!(decoded.page_count(), 7);
+        let error =
+            serde_json::from_str::<Document>(&document_json(&[page; 8], spacing)).unwrap_err();
+        assert!(error.to_string().contains("document exceeds"), "{}", error);
+    }
+
+    #[test]
+    fn test_document_pages_cut_to_form_length() {
+        let page = r#"{"columns":80,"rows":200,"lines":[]}"#;
+        let json = document_json(&[page], r#""SixthInch""#);
+        let document: Document = serde_json::from_str(&json).unwrap();
+        assert_eq!(document.pages()[0].geometry(), PageGeometry::new(80, 132));
+    }
+}
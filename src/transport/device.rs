@@ -0,0 +1,77 @@
+//! Transport writing to a device or file path.
+
+use super::{write_job, Transport};
+use std::fs::OpenOptions;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Writes jobs to a character device (e.g. `/dev/usb/lp0`) or file path.
+///
+/// The path is opened for each job. It must already exist, so a mistyped
+/// device name fails instead of creating a stray file; regular files are
+/// appended to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use escp_layout::transport::{DeviceTransport, Transport};
+///
+/// let mut printer = DeviceTransport::new("/dev/usb/lp0");
+/// printer.send(b"\x1b@Hello\x0c")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceTransport {
+    path: PathBuf,
+}
+
+impl DeviceTransport {
+    /// Creates a transport writing to `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        DeviceTransport { path: path.into() }
+    }
+
+    /// Returns the device or file path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Transport for DeviceTransport {
+    fn send(&mut self, job: &[u8]) -> io::Result<()> {
+        let with_path = |error: io::Error| {
+            io::Error::new(error.kind(), format!("{}: {}", self.path.display(), error))
+        };
+        let mut device = OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(with_path)?;
+        write_job(&mut device, job).map_err(with_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_appends_to_existing_file() {
+        let path = std::env::temp_dir().join(format!("escp-device-{}", std::process::id()));
+        std::fs::write(&path, b"").unwrap();
+
+        let mut transport = DeviceTransport::new(&path);
+        transport.send(b"first\x0c").unwrap();
+        transport.send(b"second\x0c").unwrap();
+        let written = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, b"first\x0csecond\x0c");
+    }
+
+    #[test]
+    fn test_missing_path_fails() {
+        let mut transport = DeviceTransport::new("/nonexistent/lp0");
+        let error = transport.send(b"job").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().starts_with("/nonexistent/lp0: "));
+    }
+}
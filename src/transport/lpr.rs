@@ -0,0 +1,214 @@
+//! Transport piping jobs to the `lpr` or `lp` spooler.
+
+use super::{write_job, Transport};
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Command-line syntax of a spooler program
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Syntax {
+    /// BSD `lpr -P printer -T title`
+    Lpr,
+    /// System V `lp -d printer -t title`
+    Lp,
+}
+
+/// Pipes jobs to the `lpr` or `lp` spooler, e.g. of CUPS.
+///
+/// Jobs are submitted with `-o raw`, so the spooler passes the ESC/P bytes
+/// to the printer unchanged. Without a printer name the spooler's default
+/// printer is used.
+///
+/// # Examples
+///
+/// ```no_run
+/// use escp_layout::transport::{LprTransport, Transport};
+///
+/// let mut printer = LprTransport::lpr()
+///     .with_printer("EPSON_LQ_2090II")
+///     .with_job_name("invoice-1042");
+/// printer.send(b"\x1b@Hello\x0c")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LprTransport {
+    syntax: Syntax,
+    program: String,
+    printer: Option<String>,
+    job_name: Option<String>,
+}
+
+impl LprTransport {
+    /// Creates a transport running `lpr`
+    pub fn lpr() -> Self {
+        LprTransport {
+            syntax: Syntax::Lpr,
+            program: "lpr".to_string(),
+            printer: None,
+            job_name: None,
+        }
+    }
+
+    /// Creates a transport running `lp`
+    pub fn lp() -> Self {
+        LprTransport {
+            syntax: Syntax::Lp,
+            program: "lp".to_string(),
+            ..LprTransport::lpr()
+        }
+    }
+
+    /// Returns the transport submitting jobs to the named printer queue
+    pub fn with_printer(self, printer: impl Into<String>) -> Self {
+        LprTransport {
+            printer: Some(printer.into()),
+            ..self
+        }
+    }
+
+    /// Returns the transport submitting jobs under the given name
+    pub fn with_job_name(self, job_name: impl Into<String>) -> Self {
+        LprTransport {
+            job_name: Some(job_name.into()),
+            ..self
+        }
+    }
+
+    /// Returns the transport running the spooler program at `program`
+    /// instead of looking up `lpr` or `lp` in `PATH`
+    pub fn with_program(self, program: impl Into<String>) -> Self {
+        LprTransport {
+            program: program.into(),
+            ..self
+        }
+    }
+
+    /// Returns the spooler command, reading the job from stdin
+    fn command(&self) -> Command {
+        let (printer_flag, title_flag) = match self.syntax {
+            Syntax::Lpr => ("-P", "-T"),
+            Syntax::Lp => ("-d", "-t"),
+        };
+        let mut command = Command::new(&self.program);
+        if let Some(printer) = &self.printer {
+            command.arg(printer_flag).arg(printer);
+        }
+        if let Some(job_name) = &self.job_name {
+            command.arg(title_flag).arg(job_name);
+        }
+        command.arg("-o").arg("raw");
+        command
+    }
+}
+
+impl Transport for LprTransport {
+    fn send(&mut self, job: &[u8]) -> io::Result<()> {
+        let with_program =
+            |error: io::Error| io::Error::new(error.kind(), format!("{}: {}", self.program, error));
+        let mut child = self
+            .command()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(with_program)?;
+
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let written = write_job(&mut stdin, job);
+        drop(stdin);
+        let output = child.wait_with_output().map_err(with_program)?;
+
+        // A spooler exiting early breaks the pipe; its own message says why
+        if !output.status.success() {
+            let message = String::from_utf8_lossy(&output.stderr);
+            let message = message.trim();
+            return Err(io::Error::other(format!(
+                "{}: {}",
+                self.program,
+                if message.is_empty() {
+                    output.status.to_string()
+                } else {
+                    message.to_string()
+                }
+            )));
+        }
+        written.map_err(with_program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(transport: &LprTransport) -> Vec<String> {
+        let command = transport.command();
+        let args = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned());
+        std::iter::once(command.get_program().to_string_lossy().into_owned())
+            .chain(args)
+            .collect()
+    }
+
+    #[test]
+    fn test_command_lines() {
+        assert_eq!(args(&LprTransport::lpr()), ["lpr", "-o", "raw"]);
+        assert_eq!(
+            args(
+                &LprTransport::lpr()
+                    .with_printer("EPSON")
+                    .with_job_name("inv 1")
+            ),
+            ["lpr", "-P", "EPSON", "-T", "inv 1", "-o", "raw"]
+        );
+        assert_eq!(
+            args(
+                &LprTransport::lp()
+                    .with_printer("EPSON")
+                    .with_job_name("inv 1")
+            ),
+            ["lp", "-d", "EPSON", "-t", "inv 1", "-o", "raw"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pipes_job_to_spooler() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("escp-lpr-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let spooler = dir.join("lpr");
+        let spooled = dir.join("job");
+        std::fs::write(
+            &spooler,
+            format!(
+                "#!/bin/sh\necho \"$@\" > '{0}.args'\ncat > '{0}'\n",
+                spooled.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&spooler, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut transport = LprTransport::lpr()
+            .with_program(spooler.to_str().unwrap())
+            .with_job_name("test");
+        transport.send(b"\x1b@Hello\x0c").unwrap();
+        let job = std::fs::read(&spooled).unwrap();
+        let args = std::fs::read_to_string(dir.join("job.args")).unwrap();
+
+        let failing = LprTransport::lpr().with_program("false").send(b"job");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(job, b"\x1b@Hello\x0c");
+        assert_eq!(args, "-T test -o raw\n");
+        assert!(failing.is_err());
+    }
+
+    #[test]
+    fn test_missing_program_fails() {
+        let mut transport = LprTransport::lpr().with_program("/nonexistent/lpr");
+        let error = transport.send(b"job").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,159 @@
+//! Transports delivering ESC/P print jobs to printers.
+//!
+//! Every backend implements [`Transport`]:
+//! - [`DeviceTransport`] writes to a character device or file path
+//! - [`LprTransport`] pipes jobs to the `lpr` or `lp` spooler (CUPS)
+//! - [`TcpTransport`] sends jobs to a raw TCP port (JetDirect, port 9100)
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use escp_layout::transport::{TcpTransport, Transport};
+//! use escp_layout::{Document, Page, StyleFlags};
+//!
+//! let mut page_builder = Page::builder();
+//! page_builder.write_str(0, 0, "Hello, printer!", StyleFlags::NONE);
+//!
+//! let mut doc_builder = Document::builder();
+//! doc_builder.add_page(page_builder.build());
+//!
+//! let mut printer = TcpTransport::new("192.168.1.20:9100");
+//! printer.send_document(&doc_builder.build())?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+mod device;
+mod lpr;
+mod tcp;
+
+pub use device::DeviceTransport;
+pub use lpr::LprTransport;
+pub use tcp::TcpTransport;
+
+use crate::Document;
+use std::io::{self, Write};
+
+/// Destination for print jobs.
+pub trait Transport {
+    /// Sends one print job of raw ESC/P bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the job could not be delivered completely; the
+    /// message says how many bytes were sent before the failure.
+    fn send(&mut self, job: &[u8]) -> io::Result<()>;
+
+    /// Renders a document and sends it as one print job.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Transport::send`].
+    fn send_document(&mut self, document: &Document) -> io::Result<()> {
+        self.send(&document.render())
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for &mut T {
+    fn send(&mut self, job: &[u8]) -> io::Result<()> {
+        (**self).send(job)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn send(&mut self, job: &[u8]) -> io::Result<()> {
+        (**self).send(job)
+    }
+}
+
+/// Writes a whole job, retrying partial and interrupted writes.
+///
+/// Errors keep their kind and report how much of the job was written.
+pub(crate) fn write_job<W: Write>(writer: &mut W, job: &[u8]) -> io::Result<()> {
+    let mut written = 0;
+    while written < job.len() {
+        match writer.write(&job[written..]) {
+            Ok(0) => {
+                return Err(partial_write(
+                    io::Error::from(io::ErrorKind::WriteZero),
+                    written,
+                    job.len(),
+                ))
+            }
+            Ok(count) => written += count,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(partial_write(error, written, job.len())),
+        }
+    }
+    writer
+        .flush()
+        .map_err(|error| partial_write(error, written, job.len()))
+}
+
+/// Adds the progress of a job to a write error
+fn partial_write(error: io::Error, written: usize, total: usize) -> io::Error {
+    io::Error::new(
+        error.kind(),
+        format!("{} (sent {} of {} bytes)", error, written, total),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writer accepting at most a few bytes per call, with failures
+    struct Flaky {
+        written: Vec<u8>,
+        chunk: usize,
+        interrupts: usize,
+        capacity: usize,
+    }
+
+    impl Write for Flaky {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.interrupts > 0 {
+                self.interrupts -= 1;
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let count = buf
+                .len()
+                .min(self.chunk)
+                .min(self.capacity - self.written.len());
+            self.written.extend_from_slice(&buf[..count]);
+            Ok(count)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_job_retries_partial_writes() {
+        let mut writer = Flaky {
+            written: Vec::new(),
+            chunk: 3,
+            interrupts: 2,
+            capacity: usize::MAX,
+        };
+        write_job(&mut writer, b"\x1b@Hello\x0c").unwrap();
+        assert_eq!(writer.written, b"\x1b@Hello\x0c");
+    }
+
+    #[test]
+    fn test_write_job_reports_progress() {
+        let mut writer = Flaky {
+            written: Vec::new(),
+            chunk: 4,
+            interrupts: 0,
+            capacity: 6,
+        };
+        let error = write_job(&mut writer, b"0123456789").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        assert!(
+            error.to_string().ends_with("(sent 6 of 10 bytes)"),
+            "{}",
+            error
+        );
+    }
+}
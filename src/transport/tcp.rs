@@ -0,0 +1,206 @@
+//! Transport sending jobs to a raw TCP printer port.
+
+use super::{write_job, Transport};
+use std::io;
+use std::net::{Shutdown, SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Sends jobs over raw TCP, as understood by JetDirect-style print servers.
+///
+/// Each job opens a new connection, writes the bytes and closes the
+/// connection. Connecting and writing give up after their timeouts (10 and
+/// 30 seconds by default), so an unreachable or stalled printer fails the
+/// job instead of blocking forever.
+///
+/// # Examples
+///
+/// ```no_run
+/// use escp_layout::transport::{TcpTransport, Transport};
+/// use std::time::Duration;
+///
+/// let mut printer = TcpTransport::new("printer.local")
+///     .with_connect_timeout(Duration::from_secs(3));
+/// printer.send(b"\x1b@Hello\x0c")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TcpTransport {
+    address: String,
+    connect_timeout: Duration,
+    write_timeout: Duration,
+}
+
+impl TcpTransport {
+    /// Raw printing port used when the address has none
+    pub const DEFAULT_PORT: u16 = 9100;
+
+    /// Creates a transport for `host:port`, or `host` on port 9100.
+    ///
+    /// IPv6 addresses take a port in brackets (`[::1]:9100`); a bare IPv6
+    /// address such as `fe80::1` uses port 9100. The address is resolved
+    /// for each job.
+    pub fn new(address: impl Into<String>) -> Self {
+        TcpTransport {
+            address: address.into(),
+            connect_timeout: Duration::from_secs(10),
+            write_timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Returns the transport with the given connect timeout
+    pub fn with_connect_timeout(self, timeout: Duration) -> Self {
+        TcpTransport {
+            connect_timeout: timeout,
+            ..self
+        }
+    }
+
+    /// Returns the transport with the given timeout for each write
+    pub fn with_write_timeout(self, timeout: Duration) -> Self {
+        TcpTransport {
+            write_timeout: timeout,
+            ..self
+        }
+    }
+
+    /// Returns the printer address
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Resolves the address, adding the default port if it has none
+    fn socket_addrs(&self) -> io::Result<Vec<SocketAddr>> {
+        let addrs = if has_port(&self.address) {
+            self.address.to_socket_addrs()?
+        } else {
+            let host = self
+                .address
+                .strip_prefix('[')
+                .and_then(|host| host.strip_suffix(']'))
+                .unwrap_or(&self.address);
+            (host, Self::DEFAULT_PORT).to_socket_addrs()?
+        };
+        Ok(addrs.collect())
+    }
+
+    /// Connects to the first address accepting a connection
+    fn connect(&self) -> io::Result<TcpStream> {
+        let mut last_error = None;
+        for addr in self.socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, self.connect_timeout) {
+                Ok(stream) => return Ok(stream),
+                Err(error) => last_error = Some(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "address resolved to nothing")
+        }))
+    }
+}
+
+impl Transport for TcpTransport {
+    fn send(&mut self, job: &[u8]) -> io::Result<()> {
+        let with_address =
+            |error: io::Error| io::Error::new(error.kind(), format!("{}: {}", self.address, error));
+        let mut stream = self.connect().map_err(with_address)?;
+        stream
+            .set_write_timeout(Some(self.write_timeout))
+            .map_err(with_address)?;
+        write_job(&mut stream, job).map_err(with_address)?;
+        stream.shutdown(Shutdown::Write).map_err(with_address)
+    }
+}
+
+/// Returns true if `address` ends in a port: a socket address,
+/// `[host]:port` or `host:port` with a single colon
+fn has_port(address: &str) -> bool {
+    if address.parse::<SocketAddr>().is_ok() {
+        return true;
+    }
+    let port = match address.strip_prefix('[') {
+        Some(bracketed) => bracketed.rsplit_once("]:").map(|(_, port)| port),
+        None if address.matches(':').count() == 1 => address.split_once(':').map(|(_, port)| port),
+        None => None,
+    };
+    port.is_some_and(|port| port.parse::<u16>().is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::{Ipv6Addr, TcpListener};
+
+    #[test]
+    fn test_sends_job_to_listener() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let receiver = std::thread::spawn(move || {
+            let mut jobs = Vec::new();
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut job = Vec::new();
+                stream.read_to_end(&mut job).unwrap();
+                jobs.push(job);
+            }
+            jobs
+        });
+
+        // Large enough to need several writes
+        let large: Vec<u8> = (0..=255).cycle().take(1 << 20).collect();
+        let mut transport = TcpTransport::new(address);
+        transport.send(b"\x1b@Hello\x0c").unwrap();
+        transport.send(&large).unwrap();
+
+        let jobs = receiver.join().unwrap();
+        assert_eq!(jobs[0], b"\x1b@Hello\x0c");
+        assert_eq!(jobs[1], large);
+    }
+
+    #[test]
+    fn test_connection_refused() {
+        // Bind and drop a listener to find a port nobody listens on
+        let address = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .to_string();
+        let mut transport =
+            TcpTransport::new(address.clone()).with_connect_timeout(Duration::from_secs(1));
+        let error = transport.send(b"job").unwrap_err();
+        assert!(error.to_string().starts_with(&address), "{}", error);
+    }
+
+    #[test]
+    fn test_default_port() {
+        let transport = TcpTransport::new("127.0.0.1");
+        assert_eq!(
+            transport.socket_addrs().unwrap(),
+            ["127.0.0.1:9100".parse::<SocketAddr>().unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_ipv6_addresses() {
+        let addrs = |address: &str| TcpTransport::new(address).socket_addrs().unwrap();
+        let loopback = |port: u16| vec![SocketAddr::from((Ipv6Addr::LOCALHOST, port))];
+        assert_eq!(addrs("::1"), loopback(9100));
+        assert_eq!(addrs("[::1]"), loopback(9100));
+        assert_eq!(addrs("[::1]:515"), loopback(515));
+        assert_eq!(
+            addrs("fe80::1"),
+            ["[fe80::1]:9100".parse::<SocketAddr>().unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_has_port() {
+        assert!(has_port("printer.local:9100"));
+        assert!(has_port("127.0.0.1:9100"));
+        assert!(has_port("[fe80::1]:9100"));
+        assert!(!has_port("printer.local"));
+        assert!(!has_port("fe80::1"));
+        assert!(!has_port("[fe80::1]"));
+        assert!(!has_port("printer.local:lpt"));
+    }
+}
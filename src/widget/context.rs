@@ -27,11 +27,12 @@ impl<'a> RenderContext<'a> {
     /// Create a new RenderContext wrapping a PageBuilder.
     ///
     /// Called internally by `Page::render()` to create context for widget tree traversal.
-    /// Initializes clip_bounds to full page (160×51 per EPSON LQ-2090II spec).
+    /// Initializes clip_bounds to the full page geometry (160×51 by default).
     pub(crate) fn new(page_builder: &'a mut PageBuilder) -> Self {
+        let geometry = page_builder.geometry();
         Self {
             page_builder,
-            clip_bounds: (0, 0, 160, 51), // EPSON LQ-2090II page bounds
+            clip_bounds: (0, 0, geometry.columns(), geometry.rows()),
         }
     }
 
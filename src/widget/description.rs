@@ -0,0 +1,762 @@
+//! Declarative widget tree descriptions built at runtime.
+//!
+//! A [`WidgetDescription`] describes nested rects, columns, rows, stacks and
+//! labels with their sizes, so layouts can be loaded from data instead of
+//! being compiled in with const generics. With the `serde` feature it can be
+//! deserialized from JSON, TOML or any other serde format:
+//!
+//! ```json
+//! {
+//!   "type": "column",
+//!   "width": 80,
+//!   "height": 10,
+//!   "areas": [
+//!     {
+//!       "size": 2,
+//!       "children": [
+//!         {
+//!           "position": [0, 0],
+//!           "widget": { "type": "label", "width": 20, "text": "INVOICE", "style": ["bold"] }
+//!         }
+//!       ]
+//!     },
+//!     { "size": 8 }
+//!   ]
+//! }
+//! ```
+//!
+//! [`WidgetDescription::build`] validates the description with the same rules
+//! as [`Rect::add_child`](super::Rect::add_child), the layout `area()`
+//! methods and [`Label::add_text`](super::Label::add_text), and returns a
+//! [`Layout`] that renders like the equivalent compiled widget tree.
+
+use super::label::{check_text, visible_text};
+use super::rect::check_child;
+use super::{RenderContext, RenderError};
+use crate::cell::StyleFlags;
+use std::fmt;
+
+/// Description of a widget and its children.
+///
+/// Mirrors the compiled widgets: `Rect` places children at explicit
+/// positions, `Column` and `Row` split their area into consecutive areas
+/// (each a rect holding its own children), `Stack` overlays full-size layers
+/// and `Label` holds one line of styled text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep", tag = "type", rename_all = "snake_case")
+)]
+pub enum WidgetDescription {
+    /// Container with children at explicit positions (like `Rect`)
+    Rect {
+        /// Width in columns
+        width: u16,
+        /// Height in rows
+        height: u16,
+        /// Children, validated for bounds and overlaps in order
+        #[cfg_attr(feature = "serde", serde(default))]
+        children: Vec<ChildDescription>,
+    },
+    /// Vertical division into areas of the given heights (like `Column`)
+    Column {
+        /// Width in columns
+        width: u16,
+        /// Height in rows
+        height: u16,
+        /// Areas from top to bottom; each `size` is a height
+        #[cfg_attr(feature = "serde", serde(default))]
+        areas: Vec<AreaDescription>,
+    },
+    /// Horizontal division into areas of the given widths (like `Row`)
+    Row {
+        /// Width in columns
+        width: u16,
+        /// Height in rows
+        height: u16,
+        /// Areas from left to right; each `size` is a width
+        #[cfg_attr(feature = "serde", serde(default))]
+        areas: Vec<AreaDescription>,
+    },
+    /// Overlapping full-size layers, rendered in order (like `Stack`)
+    Stack {
+        /// Width in columns
+        width: u16,
+        /// Height in rows
+        height: u16,
+        /// Children of each layer; layers may overlap each other
+        #[cfg_attr(feature = "serde", serde(default))]
+        layers: Vec<Vec<ChildDescription>>,
+    },
+    /// Single line of styled text, one row high, or two with the
+    /// `double_height` style (like `Label`)
+    Label {
+        /// Width in columns
+        width: u16,
+        /// Text content; empty labels render nothing
+        #[cfg_attr(feature = "serde", serde(default))]
+        text: String,
+        /// Styles applied to the text
+        #[cfg_attr(feature = "serde", serde(default))]
+        style: Vec<TextStyle>,
+    },
+}
+
+/// A child widget and its position relative to its parent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep")
+)]
+pub struct ChildDescription {
+    /// Position (column, row) within the parent
+    pub position: (u16, u16),
+    /// The child widget
+    pub widget: WidgetDescription,
+}
+
+/// One area of a column or row layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep")
+)]
+pub struct AreaDescription {
+    /// Height (in a column) or width (in a row) of the area
+    pub size: u16,
+    /// Children positioned within the area
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub children: Vec<ChildDescription>,
+}
+
+/// Text style named in a label description.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_dep::Serialize, serde_dep::Deserialize),
+    serde(crate = "serde_dep", rename_all = "snake_case")
+)]
+pub enum TextStyle {
+    /// Bold text
+    Bold,
+    /// Underlined text
+    Underline,
+    /// Italic text
+    Italic,
+    /// Double-strike text
+    DoubleStrike,
+    /// Superscript text (replaces subscript)
+    Superscript,
+    /// Subscript text (replaces superscript)
+    Subscript,
+    /// Struck-through text
+    Strikethrough,
+    /// Double-width text
+    DoubleWidth,
+    /// Double-height text
+    DoubleHeight,
+}
+
+impl TextStyle {
+    /// Returns `style` with this style applied
+    fn apply(self, style: StyleFlags) -> StyleFlags {
+        match self {
+            TextStyle::Bold => style.with_bold(),
+            TextStyle::Underline => style.with_underline(),
+            TextStyle::Italic => style.with_italic(),
+            TextStyle::DoubleStrike => style.with_double_strike(),
+            TextStyle::Superscript => style.with_superscript(),
+            TextStyle::Subscript => style.with_subscript(),
+            TextStyle::Strikethrough => style.with_strikethrough(),
+            TextStyle::DoubleWidth => style.with_double_width(),
+            TextStyle::DoubleHeight => style.with_double_height(),
+        }
+    }
+}
+
+impl WidgetDescription {
+    /// Returns the (width, height) the widget occupies in its parent
+    pub fn size(&self) -> (u16, u16) {
+        match *self {
+            WidgetDescription::Rect { width, height, .. }
+            | WidgetDescription::Column { width, height, .. }
+            | WidgetDescription::Row { width, height, .. }
+            | WidgetDescription::Stack { width, height, .. } => (width, height),
+            WidgetDescription::Label {
+                width, ref style, ..
+            } => {
+                let double_height = style.contains(&TextStyle::DoubleHeight);
+                (width, if double_height { 2 } else { 1 })
+            }
+        }
+    }
+
+    /// Validates the description and builds a renderable widget tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LayoutError`] naming the first invalid widget and the
+    /// [`RenderError`] the equivalent compiled widget would have reported:
+    ///
+    /// - `ChildExceedsParent`: a child extends beyond its parent or area
+    /// - `OverlappingChildren`: a child overlaps an earlier sibling
+    /// - `InsufficientSpace`: column or row areas exceed the layout size
+    /// - `IntegerOverflow`: a position plus size overflows
+    /// - `TextExceedsWidth`: label text is too long or contains newlines
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::{ChildDescription, TextStyle, WidgetDescription};
+    /// use escp_layout::Page;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let description = WidgetDescription::Rect {
+    ///     width: 40,
+    ///     height: 3,
+    ///     children: vec![ChildDescription {
+    ///         position: (2, 1),
+    ///         widget: WidgetDescription::Label {
+    ///             width: 20,
+    ///             text: "Hello".to_string(),
+    ///             style: vec![TextStyle::Bold],
+    ///         },
+    ///     }],
+    /// };
+    /// let layout = description.build()?;
+    ///
+    /// let mut page = Page::builder();
+    /// page.render_layout(&layout)?;
+    /// assert_eq!(page.build().get_cell(2, 1).unwrap().character(), 'H');
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(&self) -> Result<Layout, LayoutError> {
+        Ok(Layout {
+            root: build_node(self, "root")?,
+        })
+    }
+
+    /// Calls `visit` with the path and text of every label, in the order
+    /// [`build`](Self::build) visits them.
+    pub(crate) fn visit_labels(&mut self, path: &str, visit: &mut dyn FnMut(&str, &mut String)) {
+        let mut visit_children = |children: &mut [ChildDescription], path: &str| {
+            for (index, child) in children.iter_mut().enumerate() {
+                child
+                    .widget
+                    .visit_labels(&format!("{}[{}]", path, index), visit);
+            }
+        };
+        match self {
+            WidgetDescription::Rect { children, .. } => {
+                visit_children(children, &format!("{}.children", path))
+            }
+            WidgetDescription::Column { areas, .. } | WidgetDescription::Row { areas, .. } => {
+                for (index, area) in areas.iter_mut().enumerate() {
+                    let area_path = format!("{}.areas[{}].children", path, index);
+                    visit_children(&mut area.children, &area_path);
+                }
+            }
+            WidgetDescription::Stack { layers, .. } => {
+                for (index, layer) in layers.iter_mut().enumerate() {
+                    visit_children(layer, &format!("{}.layers[{}]", path, index));
+                }
+            }
+            WidgetDescription::Label { text, .. } => visit(path, text),
+        }
+    }
+}
+
+/// Widget tree built from a [`WidgetDescription`].
+///
+/// Render it onto a page with
+/// [`PageBuilder::render_layout`](crate::PageBuilder::render_layout).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    root: Node,
+}
+
+impl Layout {
+    /// Returns the width of the root widget in columns
+    pub fn width(&self) -> u16 {
+        self.root.size().0
+    }
+
+    /// Returns the height of the root widget in rows
+    pub fn height(&self) -> u16 {
+        self.root.size().1
+    }
+
+    /// Render the tree to the provided context at the given absolute position.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RenderError::OutOfBounds` if a widget starts outside the
+    /// context's clip bounds.
+    pub fn render_to(
+        &self,
+        context: &mut RenderContext,
+        position: (u16, u16),
+    ) -> Result<(), RenderError> {
+        self.root.render_to(context, position)
+    }
+
+    /// Returns the text of every label, in the order
+    /// [`WidgetDescription::visit_labels`] visits them.
+    pub(crate) fn label_texts_mut(&mut self) -> Vec<&mut String> {
+        fn collect<'a>(node: &'a mut Node, texts: &mut Vec<&'a mut String>) {
+            match node {
+                Node::Container { children, .. } => {
+                    for (_, child) in children {
+                        collect(child, texts);
+                    }
+                }
+                Node::Label { text, .. } => texts.push(text),
+            }
+        }
+        let mut texts = Vec::new();
+        collect(&mut self.root, &mut texts);
+        texts
+    }
+}
+
+/// Validated widget in a built tree
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Node {
+    /// Rect, or an area of a column, row or stack
+    Container {
+        width: u16,
+        height: u16,
+        children: Vec<((u16, u16), Node)>,
+    },
+    Label {
+        width: u16,
+        text: String,
+        style: StyleFlags,
+    },
+}
+
+impl Node {
+    fn size(&self) -> (u16, u16) {
+        match *self {
+            Node::Container { width, height, .. } => (width, height),
+            Node::Label { width, style, .. } => (width, style.glyph_height()),
+        }
+    }
+
+    fn render_to(
+        &self,
+        context: &mut RenderContext,
+        position: (u16, u16),
+    ) -> Result<(), RenderError> {
+        match self {
+            Node::Container { children, .. } => {
+                // Render all children with cumulative offset
+                for (offset, child) in children {
+                    let child_pos = (position.0 + offset.0, position.1 + offset.1);
+                    child.render_to(context, child_pos)?;
+                }
+                Ok(())
+            }
+            Node::Label { width, text, style } => {
+                if !text.is_empty() {
+                    context.write_styled(visible_text(text, *width, *style), position, *style)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Builds the node for `description`, found at `path`
+fn build_node(description: &WidgetDescription, path: &str) -> Result<Node, LayoutError> {
+    match description {
+        WidgetDescription::Rect {
+            width,
+            height,
+            children,
+        } => build_container((*width, *height), children, &format!("{}.children", path)),
+        WidgetDescription::Column {
+            width,
+            height,
+            areas,
+        } => build_areas((*width, *height), areas, "Column", path),
+        WidgetDescription::Row {
+            width,
+            height,
+            areas,
+        } => build_areas((*width, *height), areas, "Row", path),
+        WidgetDescription::Stack {
+            width,
+            height,
+            layers,
+        } => {
+            let mut children = Vec::new();
+            for (index, layer) in layers.iter().enumerate() {
+                let layer_path = format!("{}.layers[{}]", path, index);
+                children.push((
+                    (0, 0),
+                    build_container((*width, *height), layer, &layer_path)?,
+                ));
+            }
+            Ok(Node::Container {
+                width: *width,
+                height: *height,
+                children,
+            })
+        }
+        WidgetDescription::Label { width, text, style } => {
+            check_text(text, *width).map_err(|error| LayoutError::new(path, error))?;
+            Ok(Node::Label {
+                width: *width,
+                text: text.clone(),
+                style: style
+                    .iter()
+                    .fold(StyleFlags::NONE, |flags, style| style.apply(flags)),
+            })
+        }
+    }
+}
+
+/// Builds a container of `size` holding `children`, found at `path`
+fn build_container(
+    size: (u16, u16),
+    children: &[ChildDescription],
+    path: &str,
+) -> Result<Node, LayoutError> {
+    let mut nodes: Vec<((u16, u16), Node)> = Vec::new();
+    for (index, child) in children.iter().enumerate() {
+        let child_path = format!("{}[{}]", path, index);
+        let siblings = nodes
+            .iter()
+            .map(|(position, node)| (*position, node.size()));
+        check_child(size, siblings, child.position, child.widget.size())
+            .map_err(|error| LayoutError::new(&child_path, error))?;
+        nodes.push((child.position, build_node(&child.widget, &child_path)?));
+    }
+    Ok(Node::Container {
+        width: size.0,
+        height: size.1,
+        children: nodes,
+    })
+}
+
+/// Builds a column or row layout of `size` split into `areas`
+fn build_areas(
+    size: (u16, u16),
+    areas: &[AreaDescription],
+    layout_type: &'static str,
+    path: &str,
+) -> Result<Node, LayoutError> {
+    let vertical = layout_type == "Column";
+    let total = if vertical { size.1 } else { size.0 };
+
+    let mut offset: u16 = 0;
+    let mut children = Vec::new();
+    for (index, area) in areas.iter().enumerate() {
+        let area_path = format!("{}.areas[{}]", path, index);
+        if area.size > total - offset {
+            return Err(LayoutError::new(
+                &area_path,
+                RenderError::InsufficientSpace {
+                    available: total - offset,
+                    required: area.size,
+                    layout_type,
+                },
+            ));
+        }
+
+        let (area_size, position) = if vertical {
+            ((size.0, area.size), (0, offset))
+        } else {
+            ((area.size, size.1), (offset, 0))
+        };
+        let node = build_container(
+            area_size,
+            &area.children,
+            &format!("{}.children", area_path),
+        )?;
+        children.push((position, node));
+        offset += area.size;
+    }
+    Ok(Node::Container {
+        width: size.0,
+        height: size.1,
+        children,
+    })
+}
+
+/// Validation error for a [`WidgetDescription`].
+///
+/// Wraps the [`RenderError`] with the path of the offending widget, such as
+/// `root.areas[1].children[0]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutError {
+    path: String,
+    error: RenderError,
+}
+
+impl LayoutError {
+    fn new(path: &str, error: RenderError) -> Self {
+        LayoutError {
+            path: path.to_string(),
+            error,
+        }
+    }
+
+    /// Returns the path of the invalid widget within the description
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the validation error
+    pub fn error(&self) -> &RenderError {
+        &self.error
+    }
+
+    /// Consumes the error and returns the validation error
+    pub fn into_error(self) -> RenderError {
+        self.error
+    }
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
+impl std::error::Error for LayoutError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::page::Page;
+
+    fn label(width: u16, text: &str) -> WidgetDescription {
+        WidgetDescription::Label {
+            width,
+            text: text.to_string(),
+            style: Vec::new(),
+        }
+    }
+
+    fn child(position: (u16, u16), widget: WidgetDescription) -> ChildDescription {
+        ChildDescription { position, widget }
+    }
+
+    fn render(description: &WidgetDescription) -> Page {
+        let layout = description.build().unwrap();
+        let mut page = Page::builder();
+        page.render_layout(&layout).unwrap();
+        page.build()
+    }
+
+    #[test]
+    fn test_rect_children() {
+        let description = WidgetDescription::Rect {
+            width: 40,
+            height: 5,
+            children: vec![
+                child((0, 0), label(10, "Name")),
+                child(
+                    (10, 1),
+                    WidgetDescription::Rect {
+                        width: 20,
+                        height: 2,
+                        children: vec![child((2, 1), label(10, "nested"))],
+                    },
+                ),
+            ],
+        };
+        let page = render(&description);
+        assert_eq!(page.to_plain_text(), "Name\n\n            nested\n");
+    }
+
+    #[test]
+    fn test_column_and_row_areas() {
+        let description = WidgetDescription::Column {
+            width: 30,
+            height: 4,
+            areas: vec![
+                AreaDescription {
+                    size: 1,
+                    children: vec![child(
+                        (0, 0),
+                        WidgetDescription::Label {
+                            width: 10,
+                            text: "TITLE".to_string(),
+                            style: vec![TextStyle::Bold, TextStyle::Underline],
+                        },
+                    )],
+                },
+                AreaDescription {
+                    size: 3,
+                    children: vec![child(
+                        (0, 1),
+                        WidgetDescription::Row {
+                            width: 30,
+                            height: 1,
+                            areas: vec![
+                                AreaDescription {
+                                    size: 10,
+                                    children: vec![child((0, 0), label(10, "left"))],
+                                },
+                                AreaDescription {
+                                    size: 20,
+                                    children: vec![child((0, 0), label(10, "right"))],
+                                },
+                            ],
+                        },
+                    )],
+                },
+            ],
+        };
+        let layout = description.build().unwrap();
+        assert_eq!((layout.width(), layout.height()), (30, 4));
+
+        let page = render(&description);
+        assert_eq!(page.to_plain_text(), "TITLE\n\nleft      right\n");
+        assert_eq!(
+            page.get_cell(0, 0).unwrap().style(),
+            StyleFlags::BOLD.with_underline()
+        );
+    }
+
+    #[test]
+    fn test_stack_layers_may_overlap() {
+        let description = WidgetDescription::Stack {
+            width: 20,
+            height: 2,
+            layers: vec![
+                vec![child((0, 0), label(10, "background"))],
+                vec![child((0, 0), label(4, "FORE"))],
+            ],
+        };
+        assert_eq!(render(&description).to_plain_text(), "FOREground\n");
+    }
+
+    #[test]
+    fn test_double_width_label_truncates() {
+        let description = WidgetDescription::Label {
+            width: 6,
+            text: "ABCDEF".to_string(),
+            style: vec![TextStyle::DoubleWidth],
+        };
+        assert_eq!(render(&description).to_plain_text(), "A B C\n");
+    }
+
+    #[test]
+    fn test_label_width_counts_characters() {
+        assert!(label(6, "Müller").build().is_ok());
+        assert!(label(5, "Müller").build().is_err());
+    }
+
+    #[test]
+    fn test_double_height_label_takes_two_rows() {
+        let heading = WidgetDescription::Label {
+            width: 6,
+            text: "TOTAL".to_string(),
+            style: vec![TextStyle::DoubleHeight],
+        };
+        assert_eq!(heading.size(), (6, 2));
+
+        let description = WidgetDescription::Rect {
+            width: 10,
+            height: 3,
+            children: vec![child((0, 0), heading), child((0, 1), label(4, "next"))],
+        };
+        let error = description.build().unwrap_err();
+        assert!(matches!(
+            error.error(),
+            RenderError::OverlappingChildren { .. }
+        ));
+    }
+
+    #[test]
+    fn test_errors_name_the_widget() {
+        let description = WidgetDescription::Rect {
+            width: 20,
+            height: 5,
+            children: vec![child((15, 0), label(10, "wide"))],
+        };
+        let error = description.build().unwrap_err();
+        assert_eq!(error.path(), "root.children[0]");
+        assert!(matches!(
+            error.error(),
+            RenderError::ChildExceedsParent {
+                parent_width: 20,
+                child_width: 10,
+                position: (15, 0),
+                ..
+            }
+        ));
+
+        let description = WidgetDescription::Rect {
+            width: 20,
+            height: 5,
+            children: vec![child((0, 0), label(10, "a")), child((5, 0), label(10, "b"))],
+        };
+        let error = description.build().unwrap_err();
+        assert_eq!(error.path(), "root.children[1]");
+        assert_eq!(
+            error.into_error(),
+            RenderError::OverlappingChildren {
+                child1_bounds: (0, 0, 10, 1),
+                child2_bounds: (5, 0, 10, 1),
+            }
+        );
+    }
+
+    #[test]
+    fn test_area_and_text_errors() {
+        let area = |size| AreaDescription {
+            size,
+            children: Vec::new(),
+        };
+        let description = WidgetDescription::Row {
+            width: 30,
+            height: 2,
+            areas: vec![area(20), area(15)],
+        };
+        let error = description.build().unwrap_err();
+        assert_eq!(error.path(), "root.areas[1]");
+        assert_eq!(
+            error.error(),
+            &RenderError::InsufficientSpace {
+                available: 10,
+                required: 15,
+                layout_type: "Row",
+            }
+        );
+
+        let description = WidgetDescription::Column {
+            width: 30,
+            height: 2,
+            areas: vec![AreaDescription {
+                size: 2,
+                children: vec![child((0, 1), label(4, "too long"))],
+            }],
+        };
+        let error = description.build().unwrap_err();
+        assert_eq!(error.path(), "root.areas[0].children[0]");
+        assert!(matches!(
+            error.error(),
+            RenderError::TextExceedsWidth {
+                text_length: 8,
+                widget_width: 4
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "root.areas[0].children[0]: Text length (8) exceeds widget width (4)"
+        );
+    }
+}
@@ -109,6 +109,17 @@ pub enum RenderError {
         /// Widget width constraint
         widget_width: u16,
     },
+
+    /// Text glyphs are taller than the widget.
+    ///
+    /// Returned by `Label::add_text()` and when rendering a label whose
+    /// double-height style needs more rows than the label has.
+    TextExceedsHeight {
+        /// Rows each glyph covers
+        glyph_height: u16,
+        /// Widget height constraint
+        widget_height: u16,
+    },
 }
 
 impl fmt::Display for RenderError {
@@ -159,6 +170,14 @@ impl fmt::Display for RenderError {
                 "Text length ({}) exceeds widget width ({})",
                 text_length, widget_width
             ),
+            RenderError::TextExceedsHeight {
+                glyph_height,
+                widget_height,
+            } => write!(
+                f,
+                "Text height ({}) exceeds widget height ({})",
+                glyph_height, widget_height
+            ),
         }
     }
 }
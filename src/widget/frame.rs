@@ -0,0 +1,336 @@
+//! Frame container widget drawing a border with an optional title.
+
+use super::label::{fit_height, visible_text};
+use super::rect::check_child;
+use super::tree::WidgetNode;
+use super::{RenderContext, RenderError, Widget};
+use crate::border::BorderStyle;
+use crate::cell::StyleFlags;
+
+/// Horizontal placement of a frame title.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Next to the top-left corner
+    #[default]
+    Left,
+    /// Centered in the top border
+    Center,
+    /// Next to the top-right corner
+    Right,
+}
+
+/// Container widget drawing a border around its children.
+///
+/// The border takes the outermost rows and columns, leaving an inner area
+/// of (WIDTH − 2) × (HEIGHT − 2) for children. Child positions are relative
+/// to the inside of the border, so `(0, 0)` is the first cell inside the
+/// top-left corner.
+///
+/// An optional title is printed in the top border, padded with one space
+/// on each side and kept one border glyph away from the corners
+/// (`+- Title ------+`). Titles too long for the border are truncated.
+///
+/// # Validation
+///
+/// Per Constitution Principle VI validation hierarchy:
+/// - **Compile-time**: Const generic dimensions (WIDTH, HEIGHT)
+/// - **Debug-time**: `debug_assert!(WIDTH >= 2 && HEIGHT >= 2)` in `new()`
+/// - **Runtime**: Child boundary and overlap validation in `add_child()`
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::{frame_new, label_new, Alignment};
+/// use escp_layout::Page;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut frame = frame_new!(20, 3).title("Total").title_alignment(Alignment::Center);
+/// frame.add_child(label_new!(18).add_text("  1,234.50")?, (0, 0))?;
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.render(&frame)?;
+/// assert_eq!(
+///     page_builder.build().to_plain_text(),
+///     "+----- Total ------+\n|  1,234.50        |\n+------------------+\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct Frame<const WIDTH: u16, const HEIGHT: u16> {
+    /// Children widgets with positions relative to the inner area
+    children: Vec<WidgetNode>,
+
+    /// Glyphs of the border
+    border: BorderStyle,
+
+    /// Title printed in the top border, if any
+    title: Option<String>,
+
+    /// Placement of the title
+    title_alignment: Alignment,
+
+    /// Style of the title text
+    title_style: StyleFlags,
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Frame<WIDTH, HEIGHT> {
+    /// Width of the area inside the border
+    pub const INNER_WIDTH: u16 = WIDTH - 2;
+
+    /// Height of the area inside the border
+    pub const INNER_HEIGHT: u16 = HEIGHT - 2;
+
+    /// Create a new Frame widget with const generic dimensions.
+    ///
+    /// The border uses [`BorderStyle::Ascii`] glyphs until changed with
+    /// [`border`](Self::border).
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if WIDTH or HEIGHT is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::Frame;
+    ///
+    /// let frame = Frame::<40, 10>::new();
+    /// assert_eq!(Frame::<40, 10>::INNER_WIDTH, 38);
+    /// ```
+    pub fn new() -> Self {
+        debug_assert!(
+            WIDTH >= 2 && HEIGHT >= 2,
+            "Frame dimensions must be at least 2"
+        );
+
+        Self {
+            children: Vec::new(),
+            border: BorderStyle::Ascii,
+            title: None,
+            title_alignment: Alignment::Left,
+            title_style: StyleFlags::NONE,
+        }
+    }
+
+    /// Set the glyphs of the border (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::frame_new;
+    /// use escp_layout::BorderStyle;
+    ///
+    /// let frame = frame_new!(40, 10).border(BorderStyle::Double);
+    /// ```
+    pub fn border(mut self, border: BorderStyle) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Set the title printed in the top border (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::frame_new;
+    ///
+    /// let frame = frame_new!(40, 10).title("Ship To");
+    /// ```
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the placement of the title (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::{frame_new, Alignment};
+    ///
+    /// let frame = frame_new!(40, 10)
+    ///     .title("Ship To")
+    ///     .title_alignment(Alignment::Right);
+    /// ```
+    pub fn title_alignment(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Set the style of the title text (builder pattern).
+    ///
+    /// Double-width titles take two columns per character. Double height is
+    /// dropped from the style: it would cover the first inner row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::frame_new;
+    /// use escp_layout::StyleFlags;
+    ///
+    /// let frame = frame_new!(40, 10)
+    ///     .title("Ship To")
+    ///     .title_style(StyleFlags::BOLD);
+    /// ```
+    pub fn title_style(mut self, style: StyleFlags) -> Self {
+        self.title_style = fit_height(style, 1);
+        self
+    }
+
+    /// Add a child widget at a position relative to the inside of the
+    /// border (composition phase).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Rect::add_child`](super::Rect::add_child), with the inner
+    /// area of (WIDTH − 2) × (HEIGHT − 2) as the parent bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::{frame_new, label_new};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut frame = frame_new!(40, 10);
+    /// frame.add_child(label_new!(38).add_text("Inside")?, (0, 0))?;
+    ///
+    /// // The inner area is only 38 columns wide
+    /// assert!(frame.add_child(label_new!(39), (0, 1)).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_child<W: Widget + 'static>(
+        &mut self,
+        widget: W,
+        position: (u16, u16),
+    ) -> Result<(), RenderError> {
+        let inner = (WIDTH.saturating_sub(2), HEIGHT.saturating_sub(2));
+        let siblings = self
+            .children
+            .iter()
+            .map(|child| (child.position, (child.width, child.height)));
+        check_child(inner, siblings, position, (W::WIDTH, W::HEIGHT))?;
+
+        self.children.push(WidgetNode::new(widget, position));
+        Ok(())
+    }
+
+    /// Returns the top border, split into the border before the title,
+    /// the title (with its padding spaces) and the border after it
+    fn top_border(&self) -> (String, Option<String>, String) {
+        let horizontal = |count: u16| {
+            std::iter::repeat_n(self.border.horizontal(), count as usize).collect::<String>()
+        };
+        let inner = WIDTH - 2;
+
+        // One border glyph and one space on each side of the title
+        let room = inner.saturating_sub(4);
+        let title = self
+            .title
+            .as_deref()
+            .map(|title| visible_text(title, room, self.title_style))
+            .filter(|title| !title.is_empty());
+        let Some(title) = title else {
+            return (horizontal(inner), None, String::new());
+        };
+
+        let title_width = title.chars().count() as u16 * self.title_style.glyph_width() + 2;
+        let before = match self.title_alignment {
+            Alignment::Left => 1,
+            Alignment::Center => (inner - title_width) / 2,
+            Alignment::Right => inner - title_width - 1,
+        };
+        (
+            horizontal(before),
+            Some(title.to_string()),
+            horizontal(inner - title_width - before),
+        )
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Frame<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Widget for Frame<WIDTH, HEIGHT> {
+    const WIDTH: u16 = WIDTH;
+    const HEIGHT: u16 = HEIGHT;
+
+    fn render_to(
+        &self,
+        context: &mut RenderContext,
+        position: (u16, u16),
+    ) -> Result<(), RenderError> {
+        if WIDTH < 2 || HEIGHT < 2 {
+            return Ok(());
+        }
+        let (x, y) = position;
+        let right = x + WIDTH - 1;
+        let bottom = y + HEIGHT - 1;
+        let none = StyleFlags::NONE;
+
+        // Top border with the title
+        let (before, title, after) = self.top_border();
+        let mut top = String::new();
+        top.push(self.border.top_left());
+        top.push_str(&before);
+        context.write_styled(&top, (x, y), none)?;
+        let mut column = x + 1 + before.chars().count() as u16;
+        if let Some(title) = title {
+            context.write_styled(" ", (column, y), none)?;
+            context.write_styled(&title, (column + 1, y), self.title_style)?;
+            column += 1 + title.chars().count() as u16 * self.title_style.glyph_width();
+            context.write_styled(" ", (column, y), none)?;
+            column += 1;
+        }
+        let mut rest = after;
+        rest.push(self.border.top_right());
+        context.write_styled(&rest, (column, y), none)?;
+
+        // Sides
+        let vertical = self.border.vertical().to_string();
+        for row in y + 1..bottom {
+            context.write_styled(&vertical, (x, row), none)?;
+            context.write_styled(&vertical, (right, row), none)?;
+        }
+
+        // Bottom border
+        let mut bottom_line = String::new();
+        bottom_line.push(self.border.bottom_left());
+        bottom_line.extend(std::iter::repeat_n(
+            self.border.horizontal(),
+            (WIDTH - 2) as usize,
+        ));
+        bottom_line.push(self.border.bottom_right());
+        context.write_styled(&bottom_line, (x, bottom), none)?;
+
+        // Children, relative to the inside of the border
+        for child in &self.children {
+            let child_pos = (x + 1 + child.position.0, y + 1 + child.position.1);
+            child.widget.render_to_dyn(context, child_pos)?;
+        }
+        Ok(())
+    }
+}
+
+/// Ergonomic macro for creating Frame widgets.
+///
+/// Expands `frame_new!(W, H)` to `Frame::<W, H>::new()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::frame_new;
+///
+/// let frame = frame_new!(40, 10).title("Invoice");
+/// ```
+#[macro_export]
+macro_rules! frame_new {
+    ($w:expr, $h:expr) => {
+        $crate::widget::Frame::<$w, $h>::new()
+    };
+}
+
+pub use frame_new;
@@ -5,13 +5,14 @@ use crate::cell::StyleFlags;
 
 /// Leaf widget for rendering styled text content with compile-time dimensions.
 ///
-/// Labels are single-line only (HEIGHT must always be 1).
+/// Labels are single-line only (HEIGHT must be 1, or 2 to reserve the lower
+/// half of double-height text).
 ///
 /// # Validation
 ///
 /// Per Constitution Principle VI validation hierarchy:
 /// - **Compile-time**: Const generic dimensions (WIDTH, HEIGHT)
-/// - **Debug-time**: `debug_assert!(HEIGHT == 1)` in `new()`
+/// - **Debug-time**: `debug_assert!(HEIGHT == 1 || HEIGHT == 2)` in `new()`
 /// - **Runtime**: Text content validation in `add_text()`
 ///
 /// # Examples
@@ -38,7 +39,7 @@ pub struct Label<const WIDTH: u16, const HEIGHT: u16> {
     /// Text content to render (None until add_text() called)
     text: Option<String>,
 
-    /// Text style (bold, underline, etc.)
+    /// Text style (bold, underline, italic, etc.)
     style: StyleFlags,
 }
 
@@ -47,9 +48,9 @@ impl<const WIDTH: u16, const HEIGHT: u16> Label<WIDTH, HEIGHT> {
     ///
     /// # Panics
     ///
-    /// Panics in debug builds if HEIGHT ≠ 1 (Label must be single-line).
-    /// In release builds with HEIGHT ≠ 1, behavior is undefined per
-    /// Constitution Principle IX.
+    /// Panics in debug builds if HEIGHT is not 1 or 2 (Label must be
+    /// single-line). In release builds with other heights, behavior is
+    /// undefined per Constitution Principle IX.
     ///
     /// # Examples
     ///
@@ -64,7 +65,7 @@ impl<const WIDTH: u16, const HEIGHT: u16> Label<WIDTH, HEIGHT> {
     /// # }
     /// ```
     pub fn new() -> Self {
-        debug_assert!(HEIGHT == 1, "Label HEIGHT must be 1");
+        debug_assert!(HEIGHT == 1 || HEIGHT == 2, "Label HEIGHT must be 1 or 2");
 
         Self {
             text: None,
@@ -77,9 +78,12 @@ impl<const WIDTH: u16, const HEIGHT: u16> Label<WIDTH, HEIGHT> {
     /// # Errors
     ///
     /// Returns `RenderError::TextExceedsWidth` if:
-    /// - Text length exceeds WIDTH
+    /// - Text length in characters exceeds WIDTH
     /// - Text contains newline characters (`\n`, `\r\n`)
     ///
+    /// Returns `RenderError::TextExceedsHeight` if the label is already
+    /// double height but only one row high.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -92,22 +96,8 @@ impl<const WIDTH: u16, const HEIGHT: u16> Label<WIDTH, HEIGHT> {
     /// ```
     pub fn add_text(mut self, text: impl Into<String>) -> Result<Self, RenderError> {
         let text = text.into();
-
-        // Validate text length
-        if text.len() > WIDTH as usize {
-            return Err(RenderError::TextExceedsWidth {
-                text_length: text.len() as u16,
-                widget_width: WIDTH,
-            });
-        }
-
-        // Validate single-line constraint (no newlines)
-        if text.contains('\n') || text.contains("\r\n") {
-            return Err(RenderError::TextExceedsWidth {
-                text_length: text.len() as u16,
-                widget_width: WIDTH,
-            });
-        }
+        check_text(&text, WIDTH)?;
+        check_height(self.style, HEIGHT)?;
 
         self.text = Some(text);
         Ok(self)
@@ -150,6 +140,156 @@ impl<const WIDTH: u16, const HEIGHT: u16> Label<WIDTH, HEIGHT> {
         self.style = self.style.with_underline();
         self
     }
+
+    /// Apply italic styling (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::label_new;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = label_new!(20)
+    ///     .add_text("Italic")?
+    ///     .italic();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn italic(mut self) -> Self {
+        self.style = self.style.with_italic();
+        self
+    }
+
+    /// Apply double-strike styling (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::label_new;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = label_new!(20)
+    ///     .add_text("Double Strike")?
+    ///     .double_strike();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn double_strike(mut self) -> Self {
+        self.style = self.style.with_double_strike();
+        self
+    }
+
+    /// Apply superscript styling (builder pattern).
+    ///
+    /// Replaces subscript if it was applied before.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::label_new;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = label_new!(4)
+    ///     .add_text("TM")?
+    ///     .superscript();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn superscript(mut self) -> Self {
+        self.style = self.style.with_superscript();
+        self
+    }
+
+    /// Apply subscript styling (builder pattern).
+    ///
+    /// Replaces superscript if it was applied before.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::label_new;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = label_new!(4)
+    ///     .add_text("2")?
+    ///     .subscript();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscript(mut self) -> Self {
+        self.style = self.style.with_subscript();
+        self
+    }
+
+    /// Apply double-width styling (builder pattern).
+    ///
+    /// Each character occupies two columns; text beyond WIDTH / 2 characters
+    /// is truncated when rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::label_new;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = label_new!(20)
+    ///     .add_text("HEADING")?
+    ///     .double_width();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn double_width(mut self) -> Self {
+        self.style = self.style.with_double_width();
+        self
+    }
+
+    /// Apply double-height styling (builder pattern).
+    ///
+    /// Each character also covers the second row of the label, so the label
+    /// must be two rows high (`Label::<W, 2>`). Rendering a double-height
+    /// single-row label fails with `RenderError::TextExceedsHeight`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::Label;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = Label::<20, 2>::new()
+    ///     .add_text("HEADING")?
+    ///     .double_height();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn double_height(mut self) -> Self {
+        self.style = self.style.with_double_height();
+        self
+    }
+
+    /// Apply strikethrough styling (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::label_new;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let label = label_new!(20)
+    ///     .add_text("Void")?
+    ///     .strikethrough();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn strikethrough(mut self) -> Self {
+        self.style = self.style.with_strikethrough();
+        self
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Label<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<const WIDTH: u16, const HEIGHT: u16> Widget for Label<WIDTH, HEIGHT> {
@@ -162,15 +302,72 @@ impl<const WIDTH: u16, const HEIGHT: u16> Widget for Label<WIDTH, HEIGHT> {
         position: (u16, u16),
     ) -> Result<(), RenderError> {
         // Render text with style at given position
-        // Text was validated at construction time (add_text checks text.len() <= WIDTH)
+        // Text was validated at construction time (add_text checks its length <= WIDTH)
         if let Some(ref text) = self.text {
-            context.write_styled(text, position, self.style)?;
+            check_height(self.style, HEIGHT)?;
+            context.write_styled(visible_text(text, WIDTH, self.style), position, self.style)?;
         }
         // If no text, render nothing (empty label)
         Ok(())
     }
 }
 
+/// Validates label text against the label width.
+///
+/// # Errors
+///
+/// Returns `RenderError::TextExceedsWidth` if the text has more than
+/// `width` characters or contains newline characters.
+pub(crate) fn check_text(text: &str, width: u16) -> Result<(), RenderError> {
+    // Validate text length in characters and single-line constraint (no newlines)
+    let length = text.chars().count();
+    if length > width as usize || text.contains('\n') || text.contains("\r\n") {
+        return Err(RenderError::TextExceedsWidth {
+            text_length: length.min(u16::MAX as usize) as u16,
+            widget_width: width,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that glyphs in `style` fit in `height` rows.
+///
+/// # Errors
+///
+/// Returns `RenderError::TextExceedsHeight` for double-height glyphs in a
+/// single row.
+pub(crate) fn check_height(style: StyleFlags, height: u16) -> Result<(), RenderError> {
+    if style.glyph_height() > height {
+        return Err(RenderError::TextExceedsHeight {
+            glyph_height: style.glyph_height(),
+            widget_height: height,
+        });
+    }
+    Ok(())
+}
+
+/// Returns `style` without double height if its glyphs are taller than
+/// `height` rows.
+pub(crate) fn fit_height(style: StyleFlags, height: u16) -> StyleFlags {
+    if style.glyph_height() > height {
+        style.set(StyleFlags::DOUBLE_HEIGHT, false)
+    } else {
+        style
+    }
+}
+
+/// Returns the part of validated label text that fits in `width` columns.
+///
+/// Double-width glyphs take two columns, so only `width / 2` of them fit.
+pub(crate) fn visible_text(text: &str, width: u16, style: StyleFlags) -> &str {
+    let visible = (width / style.glyph_width()) as usize;
+    let end = text
+        .char_indices()
+        .nth(visible)
+        .map_or(text.len(), |(i, _)| i);
+    &text[..end]
+}
+
 /// Ergonomic macro for creating Label widgets.
 ///
 /// Expands `label_new!(W)` to `Label::<W, 1>::new()` (HEIGHT=1 automatic).
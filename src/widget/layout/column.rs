@@ -75,6 +75,12 @@ impl<const WIDTH: u16, const HEIGHT: u16> Column<WIDTH, HEIGHT> {
     }
 }
 
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Column<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Ergonomic macro for creating Column layouts.
 #[macro_export]
 macro_rules! column_new {
@@ -75,6 +75,12 @@ impl<const WIDTH: u16, const HEIGHT: u16> Row<WIDTH, HEIGHT> {
     }
 }
 
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Row<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Ergonomic macro for creating Row layouts.
 #[macro_export]
 macro_rules! row_new {
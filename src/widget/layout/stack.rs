@@ -52,6 +52,12 @@ impl<const WIDTH: u16, const HEIGHT: u16> Stack<WIDTH, HEIGHT> {
     }
 }
 
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Stack<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Ergonomic macro for creating Stack layouts.
 #[macro_export]
 macro_rules! stack_new {
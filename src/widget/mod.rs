@@ -5,6 +5,8 @@
 //! - Automatic coordinate calculation through parent-child relationships
 //! - Type-safe boundary enforcement with three-tier validation
 //! - Zero runtime dependencies
+//! - Runtime widget trees loaded from data via [`WidgetDescription`]
+//! - Data-bound templates with `{{placeholder}}` labels via [`Template`]
 //!
 //! # Quick Start
 //!
@@ -29,8 +31,13 @@
 
 mod rect;
 mod context;
+mod description;
+mod frame;
 mod label;
 pub mod layout;
+mod paragraph;
+mod template;
+mod text_block;
 mod tree;
 
 // Re-export core types
@@ -47,11 +54,25 @@ mod error;
 
 // Re-export widgets
 pub use rect::Rect;
+pub use frame::{Alignment, Frame};
 pub use label::Label;
+pub use paragraph::Paragraph;
+pub use text_block::TextBlock;
+
+// Re-export runtime widget tree descriptions
+pub use description::{
+    AreaDescription, ChildDescription, Layout, LayoutError, TextStyle, WidgetDescription,
+};
+
+// Re-export templates
+pub use template::{Template, TemplateData, TemplateError, TemplateValue};
 
 // Re-export macros
 pub use rect::rect_new;
+pub use frame::frame_new;
 pub use label::label_new;
+pub use paragraph::paragraph_new;
+pub use text_block::text_block_new;
 
 // Re-export layout components (will be added in Phase 5)
 pub use layout::{column_area, column_new, row_area, row_new, stack_new, Column, Row, Stack};
@@ -0,0 +1,317 @@
+//! Paragraph widget for rendering word-wrapped text.
+
+use super::{RenderContext, RenderError, Widget};
+use crate::cell::StyleFlags;
+
+/// Character with the style it is printed in
+type Glyph = (char, StyleFlags);
+
+/// Leaf widget for rendering word-wrapped text with compile-time dimensions.
+///
+/// Text is made of spans: [`add_text`](Self::add_text) appends text in the
+/// paragraph style (set with [`bold`](Self::bold) and friends) and
+/// [`add_span`](Self::add_span) appends text in a style of its own.
+///
+/// # Wrapping
+///
+/// - Lines break at whitespace; each run of whitespace between two words
+///   on the same line prints as one space
+/// - Words wider than the paragraph are split at its width, without
+///   hyphens
+/// - `\n` starts a new line
+/// - Lines beyond HEIGHT are dropped; [`line_count`](Self::line_count)
+///   reports how many lines the whole text needs
+///
+/// Double-width glyphs take two columns and lines containing double-height
+/// glyphs take two rows.
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::{paragraph_new, rect_new};
+/// use escp_layout::Page;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let terms = paragraph_new!(20, 3)
+///     .add_text("Payment is due within thirty days of the invoice date.");
+/// assert_eq!(terms.line_count(), 3);
+///
+/// let mut root = rect_new!(40, 10);
+/// root.add_child(terms, (0, 0))?;
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.render(&root)?;
+/// assert_eq!(
+///     page_builder.build().to_plain_text(),
+///     "Payment is due\nwithin thirty days\nof the invoice date.\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paragraph<const WIDTH: u16, const HEIGHT: u16> {
+    /// Text spans with their own style, or None for the paragraph style
+    spans: Vec<(String, Option<StyleFlags>)>,
+
+    /// Style of spans without their own style
+    style: StyleFlags,
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Paragraph<WIDTH, HEIGHT> {
+    /// Create a new empty Paragraph with specified const generic dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if WIDTH or HEIGHT is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::Paragraph;
+    ///
+    /// let paragraph = Paragraph::<40, 5>::new().add_text("Terms and conditions");
+    /// ```
+    pub fn new() -> Self {
+        debug_assert!(WIDTH > 0, "Paragraph WIDTH must be greater than 0");
+        debug_assert!(HEIGHT > 0, "Paragraph HEIGHT must be greater than 0");
+
+        Self {
+            spans: Vec::new(),
+            style: StyleFlags::NONE,
+        }
+    }
+
+    /// Append text in the paragraph style (builder pattern).
+    ///
+    /// Never fails: lines that do not fit are dropped when rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::paragraph_new;
+    ///
+    /// let paragraph = paragraph_new!(40, 5)
+    ///     .add_text("Goods remain our property ")
+    ///     .add_text("until paid in full.");
+    /// ```
+    pub fn add_text(mut self, text: impl Into<String>) -> Self {
+        self.spans.push((text.into(), None));
+        self
+    }
+
+    /// Append text in its own style (builder pattern).
+    ///
+    /// The span keeps `style` regardless of the paragraph style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::paragraph_new;
+    /// use escp_layout::StyleFlags;
+    ///
+    /// let paragraph = paragraph_new!(40, 5)
+    ///     .add_text("Payment is ")
+    ///     .add_span("due on receipt", StyleFlags::BOLD)
+    ///     .add_text(".");
+    /// ```
+    pub fn add_span(mut self, text: impl Into<String>, style: StyleFlags) -> Self {
+        self.spans.push((text.into(), Some(style)));
+        self
+    }
+
+    /// Returns the number of lines the whole text wraps to, including
+    /// lines beyond HEIGHT.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::paragraph_new;
+    ///
+    /// let paragraph = paragraph_new!(10, 1).add_text("one two three four");
+    /// assert_eq!(paragraph.line_count(), 2);
+    /// ```
+    pub fn line_count(&self) -> usize {
+        self.lines().len()
+    }
+
+    /// Apply bold styling to the paragraph style (builder pattern).
+    pub fn bold(mut self) -> Self {
+        self.style = self.style.with_bold();
+        self
+    }
+
+    /// Apply underline styling to the paragraph style (builder pattern).
+    pub fn underline(mut self) -> Self {
+        self.style = self.style.with_underline();
+        self
+    }
+
+    /// Apply italic styling to the paragraph style (builder pattern).
+    pub fn italic(mut self) -> Self {
+        self.style = self.style.with_italic();
+        self
+    }
+
+    /// Apply double-strike styling to the paragraph style (builder pattern).
+    pub fn double_strike(mut self) -> Self {
+        self.style = self.style.with_double_strike();
+        self
+    }
+
+    /// Apply strikethrough styling to the paragraph style (builder pattern).
+    pub fn strikethrough(mut self) -> Self {
+        self.style = self.style.with_strikethrough();
+        self
+    }
+
+    /// Apply double-width styling to the paragraph style (builder pattern).
+    pub fn double_width(mut self) -> Self {
+        self.style = self.style.with_double_width();
+        self
+    }
+
+    /// Apply double-height styling to the paragraph style (builder pattern).
+    pub fn double_height(mut self) -> Self {
+        self.style = self.style.with_double_height();
+        self
+    }
+
+    /// Returns the wrapped lines of the whole text
+    fn lines(&self) -> Vec<Vec<Glyph>> {
+        let glyphs: Vec<Glyph> = self
+            .spans
+            .iter()
+            .flat_map(|(text, style)| {
+                let style = style.unwrap_or(self.style);
+                text.chars().map(move |ch| (ch, style))
+            })
+            .collect();
+        wrap(&glyphs, WIDTH)
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Paragraph<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Widget for Paragraph<WIDTH, HEIGHT> {
+    const WIDTH: u16 = WIDTH;
+    const HEIGHT: u16 = HEIGHT;
+
+    fn render_to(
+        &self,
+        context: &mut RenderContext,
+        position: (u16, u16),
+    ) -> Result<(), RenderError> {
+        let mut row = 0;
+        for line in self.lines() {
+            let line_height = line
+                .iter()
+                .map(|(_, style)| style.glyph_height())
+                .max()
+                .unwrap_or(1);
+            if HEIGHT - row < line_height {
+                break;
+            }
+
+            let mut column = 0;
+            for run in line.chunk_by(|(_, a), (_, b)| a == b) {
+                let style = run[0].1;
+                // Only a double-width glyph in a 1-column paragraph overflows
+                let fit = (WIDTH.saturating_sub(column) / style.glyph_width()) as usize;
+                let text: String = run.iter().take(fit).map(|(ch, _)| ch).collect();
+                if text.is_empty() {
+                    break;
+                }
+                context.write_styled(&text, (position.0 + column, position.1 + row), style)?;
+                column += width_of(run);
+            }
+
+            row += line_height;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of columns glyphs occupy
+fn width_of(glyphs: &[Glyph]) -> u16 {
+    glyphs
+        .iter()
+        .map(|(_, style)| style.glyph_width())
+        .fold(0, u16::saturating_add)
+}
+
+/// Wraps glyphs into lines of at most `width` columns.
+///
+/// Returns no lines for empty text.
+fn wrap(glyphs: &[Glyph], width: u16) -> Vec<Vec<Glyph>> {
+    let mut lines = Vec::new();
+    if glyphs.is_empty() {
+        return lines;
+    }
+
+    for hard_line in glyphs.split(|(ch, _)| *ch == '\n') {
+        let mut line: Vec<Glyph> = Vec::new();
+        let mut next = 0;
+        while let Some(skip) = hard_line[next..]
+            .iter()
+            .position(|(ch, _)| !ch.is_whitespace())
+        {
+            // Words on the same line are separated by one space in the
+            // style of the first whitespace character between them
+            let gap_style = hard_line[next].1;
+            let start = next + skip;
+            let end = hard_line[start..]
+                .iter()
+                .position(|(ch, _)| ch.is_whitespace())
+                .map_or(hard_line.len(), |length| start + length);
+            let word = &hard_line[start..end];
+            next = end;
+
+            if !line.is_empty() {
+                let space = (' ', gap_style);
+                if width_of(&line) + width_of(&[space]) + width_of(word) <= width {
+                    line.push(space);
+                    line.extend_from_slice(word);
+                    continue;
+                }
+                lines.push(std::mem::take(&mut line));
+            }
+
+            // Split words wider than the line, at least one glyph per line
+            let mut rest = word;
+            while width_of(rest) > width {
+                let mut fit = 1;
+                while fit < rest.len() && width_of(&rest[..fit + 1]) <= width {
+                    fit += 1;
+                }
+                lines.push(rest[..fit].to_vec());
+                rest = &rest[fit..];
+            }
+            line.extend_from_slice(rest);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Ergonomic macro for creating Paragraph widgets.
+///
+/// Expands `paragraph_new!(W, H)` to `Paragraph::<W, H>::new()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::paragraph_new;
+///
+/// let paragraph = paragraph_new!(40, 5).add_text("Terms and conditions");
+/// ```
+#[macro_export]
+macro_rules! paragraph_new {
+    ($w:expr, $h:expr) => {
+        $crate::widget::Paragraph::<$w, $h>::new()
+    };
+}
+
+pub use paragraph_new;
@@ -93,78 +93,98 @@ impl<const WIDTH: u16, const HEIGHT: u16> Rect<WIDTH, HEIGHT> {
         widget: W,
         position: (u16, u16),
     ) -> Result<(), RenderError> {
-        let child_width = W::WIDTH;
-        let child_height = W::HEIGHT;
-
-        // Validate child fits within parent bounds (with checked arithmetic)
-        let child_right =
-            position
-                .0
-                .checked_add(child_width)
-                .ok_or(RenderError::IntegerOverflow {
-                    operation: format!(
-                        "child position.x ({}) + width ({})",
-                        position.0, child_width
-                    ),
-                })?;
-        let child_bottom =
-            position
+        let siblings = self
+            .children
+            .iter()
+            .map(|child| (child.position, (child.width, child.height)));
+        check_child((WIDTH, HEIGHT), siblings, position, (W::WIDTH, W::HEIGHT))?;
+
+        // Add child to tree
+        self.children.push(WidgetNode::new(widget, position));
+
+        Ok(())
+    }
+}
+
+/// Validates a child of size `child` at `position` inside a parent of size
+/// `parent` that already holds `siblings` (position, size).
+///
+/// Shared by [`Rect::add_child`] and widget trees built at runtime.
+pub(crate) fn check_child(
+    parent: (u16, u16),
+    siblings: impl IntoIterator<Item = ((u16, u16), (u16, u16))>,
+    position: (u16, u16),
+    child: (u16, u16),
+) -> Result<(), RenderError> {
+    let (child_width, child_height) = child;
+
+    // Validate child fits within parent bounds (with checked arithmetic)
+    let child_right = position
+        .0
+        .checked_add(child_width)
+        .ok_or(RenderError::IntegerOverflow {
+            operation: format!(
+                "child position.x ({}) + width ({})",
+                position.0, child_width
+            ),
+        })?;
+    let child_bottom =
+        position
+            .1
+            .checked_add(child_height)
+            .ok_or(RenderError::IntegerOverflow {
+                operation: format!(
+                    "child position.y ({}) + height ({})",
+                    position.1, child_height
+                ),
+            })?;
+
+    if child_right > parent.0 || child_bottom > parent.1 {
+        return Err(RenderError::ChildExceedsParent {
+            parent_width: parent.0,
+            parent_height: parent.1,
+            child_width,
+            child_height,
+            position,
+        });
+    }
+
+    // Check for overlaps with existing children using AABB collision detection
+    // Per FR-005A: touching edges (shared boundary) does NOT count as overlap
+    for (existing, (width, height)) in siblings {
+        let existing_right = existing
+            .0
+            .checked_add(width)
+            .ok_or(RenderError::IntegerOverflow {
+                operation: "existing child bounds calculation".to_string(),
+            })?;
+        let existing_bottom =
+            existing
                 .1
-                .checked_add(child_height)
+                .checked_add(height)
                 .ok_or(RenderError::IntegerOverflow {
-                    operation: format!(
-                        "child position.y ({}) + height ({})",
-                        position.1, child_height
-                    ),
+                    operation: "existing child bounds calculation".to_string(),
                 })?;
 
-        if child_right > WIDTH || child_bottom > HEIGHT {
-            return Err(RenderError::ChildExceedsParent {
-                parent_width: WIDTH,
-                parent_height: HEIGHT,
-                child_width,
-                child_height,
-                position,
-            });
-        }
+        // AABB intersection check with strict inequality (touching edges allowed)
+        let overlaps = child_right > existing.0
+            && position.0 < existing_right
+            && child_bottom > existing.1
+            && position.1 < existing_bottom;
 
-        // Check for overlaps with existing children using AABB collision detection
-        // Per FR-005A: touching edges (shared boundary) does NOT count as overlap
-        for existing in &self.children {
-            let existing_right = existing.position.0.checked_add(existing.width).ok_or(
-                RenderError::IntegerOverflow {
-                    operation: "existing child bounds calculation".to_string(),
-                },
-            )?;
-            let existing_bottom = existing.position.1.checked_add(existing.height).ok_or(
-                RenderError::IntegerOverflow {
-                    operation: "existing child bounds calculation".to_string(),
-                },
-            )?;
-
-            // AABB intersection check with strict inequality (touching edges allowed)
-            let overlaps = child_right > existing.position.0
-                && position.0 < existing_right
-                && child_bottom > existing.position.1
-                && position.1 < existing_bottom;
-
-            if overlaps {
-                return Err(RenderError::OverlappingChildren {
-                    child1_bounds: (
-                        existing.position.0,
-                        existing.position.1,
-                        existing.width,
-                        existing.height,
-                    ),
-                    child2_bounds: (position.0, position.1, child_width, child_height),
-                });
-            }
+        if overlaps {
+            return Err(RenderError::OverlappingChildren {
+                child1_bounds: (existing.0, existing.1, width, height),
+                child2_bounds: (position.0, position.1, child_width, child_height),
+            });
         }
+    }
+    Ok(())
+}
 
-        // Add child to tree
-        self.children.push(WidgetNode::new(widget, position));
-
-        Ok(())
+impl<const WIDTH: u16, const HEIGHT: u16> Default for Rect<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -0,0 +1,812 @@
+//! Templates binding label placeholders to runtime data.
+//!
+//! Label texts in a [`WidgetDescription`] may contain placeholders such as
+//! `{{customer.name}}` or `{{total:>12,.2}}`. A [`Template`] validates the
+//! layout once and renders it with any [`TemplateData`] into a [`Page`].
+//!
+//! # Placeholder syntax
+//!
+//! `{{path}}` or `{{path:spec}}`, where `path` is a dot-separated key path
+//! (list elements are addressed by index, e.g. `items.0.name`) and `spec`
+//! is `[[fill]align][width][,][.precision]`:
+//!
+//! - `align`: `<` (left), `>` (right) or `^` (center); text defaults to
+//!   left and numbers to right alignment
+//! - `fill`: padding character, a space by default
+//! - `width`: minimum width in characters
+//! - `,`: group the integer digits of numbers in thousands
+//! - `.precision`: decimals for numbers, maximum characters for text
+//!
+//! Text that reads as a number is formatted as a number when the spec has
+//! grouping or a precision: `"1234.5"` with `,.2` prints `1,234.50`, and
+//! `"00123"` with `.3` prints `123.000` rather than `001`.
+//!
+//! Width and precision are limited to 65535, the widest possible label.
+
+use super::description::{Layout, LayoutError, WidgetDescription};
+use super::RenderError;
+use crate::geometry::PageGeometry;
+use crate::page::Page;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Value bound to a placeholder.
+///
+/// With the `serde` feature, values deserialize from any self-describing
+/// format, so a JSON or TOML record can be used directly as
+/// [`TemplateData`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateValue {
+    /// Missing value; renders as an empty string
+    Null,
+    /// Boolean, rendered as `true` or `false`
+    Bool(bool),
+    /// Integer, kept exact and formatted with the placeholder's precision
+    /// and grouping
+    Integer(i128),
+    /// Number, formatted with the placeholder's precision and grouping
+    Number(f64),
+    /// Text
+    Text(String),
+    /// List whose elements are addressed by index
+    List(Vec<TemplateValue>),
+    /// Map whose entries are addressed by key
+    Map(BTreeMap<String, TemplateValue>),
+}
+
+impl TemplateValue {
+    /// Returns the value at a dot-separated path below this value
+    fn get(&self, path: &str) -> Option<&TemplateValue> {
+        path.split('.').try_fold(self, |value, key| match value {
+            TemplateValue::Map(entries) => entries.get(key),
+            TemplateValue::List(items) => items.get(key.parse::<usize>().ok()?),
+            _ => None,
+        })
+    }
+}
+
+impl From<&str> for TemplateValue {
+    fn from(text: &str) -> Self {
+        TemplateValue::Text(text.to_string())
+    }
+}
+
+impl From<String> for TemplateValue {
+    fn from(text: String) -> Self {
+        TemplateValue::Text(text)
+    }
+}
+
+impl From<f64> for TemplateValue {
+    fn from(number: f64) -> Self {
+        TemplateValue::Number(number)
+    }
+}
+
+impl From<i64> for TemplateValue {
+    fn from(number: i64) -> Self {
+        TemplateValue::Integer(number.into())
+    }
+}
+
+impl From<bool> for TemplateValue {
+    fn from(value: bool) -> Self {
+        TemplateValue::Bool(value)
+    }
+}
+
+/// Source of placeholder values.
+///
+/// Implemented for [`TemplateValue`] and for string-keyed `HashMap`s and
+/// `BTreeMap`s. Maps first look up the whole path as a key (so flat maps
+/// can use keys like `"customer.name"`), then the first path segment.
+pub trait TemplateData {
+    /// Returns the value at a dot-separated path, or None if there is none
+    fn lookup(&self, path: &str) -> Option<TemplateValue>;
+}
+
+impl TemplateData for TemplateValue {
+    fn lookup(&self, path: &str) -> Option<TemplateValue> {
+        self.get(path).cloned()
+    }
+}
+
+/// Looks `path` up in a string-keyed map
+fn lookup_in_map<'a, V>(get: impl Fn(&str) -> Option<&'a V>, path: &str) -> Option<TemplateValue>
+where
+    V: Clone + Into<TemplateValue> + 'a,
+{
+    if let Some(value) = get(path) {
+        return Some(value.clone().into());
+    }
+    let (key, rest) = path.split_once('.')?;
+    get(key)?.clone().into().get(rest).cloned()
+}
+
+impl<V: Clone + Into<TemplateValue>> TemplateData for HashMap<String, V> {
+    fn lookup(&self, path: &str) -> Option<TemplateValue> {
+        lookup_in_map(|key| self.get(key), path)
+    }
+}
+
+impl<V: Clone + Into<TemplateValue>> TemplateData for BTreeMap<String, V> {
+    fn lookup(&self, path: &str) -> Option<TemplateValue> {
+        lookup_in_map(|key| self.get(key), path)
+    }
+}
+
+/// Layout whose label texts contain placeholders.
+///
+/// The layout is validated once when the template is created, with
+/// placeholders left out. When rendering, substituted values may make a
+/// label's text longer than the label: like every label, it is truncated
+/// at the label width (half the width for double-width text). Newlines in
+/// values are printed as spaces.
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::{ChildDescription, Template, WidgetDescription};
+/// use std::collections::HashMap;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let label = |position, width, text: &str| ChildDescription {
+///     position,
+///     widget: WidgetDescription::Label {
+///         width,
+///         text: text.to_string(),
+///         style: Vec::new(),
+///     },
+/// };
+/// let template = Template::new(WidgetDescription::Rect {
+///     width: 40,
+///     height: 2,
+///     children: vec![
+///         label((0, 0), 30, "Customer: {{customer.name}}"),
+///         label((0, 1), 20, "Total: {{total:>10,.2}}"),
+///     ],
+/// })?;
+///
+/// let mut record = HashMap::new();
+/// record.insert("customer.name".to_string(), "ACME Corp".to_string());
+/// record.insert("total".to_string(), "1234.5".to_string());
+///
+/// let page = template.render(&record)?;
+/// assert_eq!(
+///     page.to_plain_text(),
+///     "Customer: ACME Corp\nTotal:   1,234.50\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    /// Layout with every label text cleared
+    layout: Layout,
+    /// Parsed text of every label, in layout order
+    labels: Vec<Vec<Segment>>,
+    geometry: PageGeometry,
+}
+
+impl Template {
+    /// Parses the placeholders of `description` and validates its layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::Syntax`] for a malformed placeholder and
+    /// [`TemplateError::Layout`] if the layout is invalid.
+    pub fn new(mut description: WidgetDescription) -> Result<Self, TemplateError> {
+        let mut labels = Vec::new();
+        let mut syntax_error = None;
+        description.visit_labels("root", &mut |path, text| {
+            match parse(text) {
+                Ok(segments) => labels.push(segments),
+                Err(message) => {
+                    syntax_error.get_or_insert(TemplateError::Syntax {
+                        widget: path.to_string(),
+                        message,
+                    });
+                }
+            }
+            text.clear();
+        });
+        if let Some(error) = syntax_error {
+            return Err(error);
+        }
+
+        Ok(Template {
+            layout: description.build().map_err(TemplateError::Layout)?,
+            labels,
+            geometry: PageGeometry::default(),
+        })
+    }
+
+    /// Returns the template rendering onto pages of the given geometry
+    /// (160×51 by default)
+    pub fn with_geometry(self, geometry: PageGeometry) -> Self {
+        Template { geometry, ..self }
+    }
+
+    /// Renders the template with one record of data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::MissingValue`] if a placeholder has no
+    /// value, [`TemplateError::NotScalar`] if its value is a list or map,
+    /// and [`TemplateError::Render`] if the layout does not fit the page.
+    pub fn render(&self, data: &impl TemplateData) -> Result<Page, TemplateError> {
+        let mut layout = self.layout.clone();
+        for (text, segments) in layout.label_texts_mut().into_iter().zip(&self.labels) {
+            for segment in segments {
+                match segment {
+                    Segment::Literal(literal) => text.push_str(literal),
+                    Segment::Placeholder(placeholder) => {
+                        placeholder.write(data, text)?;
+                    }
+                }
+            }
+        }
+
+        let mut page_builder = Page::builder_with_geometry(self.geometry);
+        page_builder
+            .render_layout(&layout)
+            .map_err(TemplateError::Render)?;
+        Ok(page_builder.build())
+    }
+}
+
+/// Part of a label text
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Placeholder(Placeholder),
+}
+
+/// Placeholder with its format spec
+#[derive(Clone, Debug, PartialEq)]
+struct Placeholder {
+    path: String,
+    fill: char,
+    align: Option<Align>,
+    width: usize,
+    grouping: bool,
+    precision: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+impl Align {
+    fn from_char(ch: char) -> Option<Align> {
+        match ch {
+            '<' => Some(Align::Left),
+            '>' => Some(Align::Right),
+            '^' => Some(Align::Center),
+            _ => None,
+        }
+    }
+}
+
+/// Splits a label text into literals and placeholders
+fn parse(text: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            segments.push(Segment::Literal(rest[..start].to_string()));
+        }
+        let inner = &rest[start + 2..];
+        let end = inner
+            .find("}}")
+            .ok_or_else(|| format!("unclosed placeholder in {:?}", text))?;
+        segments.push(Segment::Placeholder(parse_placeholder(&inner[..end])?));
+        rest = &inner[end + 2..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Literal(rest.to_string()));
+    }
+    Ok(segments)
+}
+
+/// Parses `path` or `path:spec`
+fn parse_placeholder(inner: &str) -> Result<Placeholder, String> {
+    let (path, spec) = inner.split_once(':').unwrap_or((inner, ""));
+    let path = path.trim();
+    if path.is_empty() || path.split('.').any(str::is_empty) || path.contains(char::is_whitespace) {
+        return Err(format!("invalid placeholder path {:?}", path));
+    }
+    let invalid_spec = || format!("invalid format spec {:?} for {}", spec, path);
+
+    let mut placeholder = Placeholder {
+        path: path.to_string(),
+        fill: ' ',
+        align: None,
+        width: 0,
+        grouping: false,
+        precision: None,
+    };
+    let mut chars = spec.chars().peekable();
+
+    // [[fill]align]
+    let mut lookahead = spec.chars();
+    match (lookahead.next(), lookahead.next()) {
+        (Some(fill), Some(align)) if Align::from_char(align).is_some() => {
+            placeholder.fill = fill;
+            placeholder.align = Align::from_char(align);
+            chars.nth(1);
+        }
+        (Some(align), _) if Align::from_char(align).is_some() => {
+            placeholder.align = Align::from_char(align);
+            chars.next();
+        }
+        _ => {}
+    }
+
+    // [width]
+    let mut width = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        width.push(digit);
+    }
+    if !width.is_empty() {
+        placeholder.width = parse_count(&width).ok_or_else(invalid_spec)?;
+    }
+
+    // [,]
+    placeholder.grouping = chars.next_if_eq(&',').is_some();
+
+    // [.precision]
+    if chars.next_if_eq(&'.').is_some() {
+        let mut precision = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            precision.push(digit);
+        }
+        placeholder.precision = Some(parse_count(&precision).ok_or_else(invalid_spec)?);
+    }
+
+    if chars.next().is_some() {
+        return Err(invalid_spec());
+    }
+    Ok(placeholder)
+}
+
+/// Parses a width or precision, which can be at most the widest label
+fn parse_count(digits: &str) -> Option<usize> {
+    digits
+        .parse()
+        .ok()
+        .filter(|&count| count <= u16::MAX as usize)
+}
+
+impl Placeholder {
+    /// Appends the formatted value of this placeholder to `output`
+    fn write(&self, data: &impl TemplateData, output: &mut String) -> Result<(), TemplateError> {
+        let value = data
+            .lookup(&self.path)
+            .ok_or_else(|| TemplateError::MissingValue {
+                placeholder: self.path.clone(),
+            })?;
+
+        let (text, default_align) = match value {
+            TemplateValue::Null => (String::new(), Align::Left),
+            TemplateValue::Bool(value) => (value.to_string(), Align::Left),
+            TemplateValue::Integer(number) => (self.format_integer(number), Align::Right),
+            TemplateValue::Number(number) => (self.format_number(number), Align::Right),
+            TemplateValue::Text(text) => match self.numeric_text(&text) {
+                Some(number) => (number, Align::Right),
+                None => match self.precision {
+                    Some(max) => (text.chars().take(max).collect(), Align::Left),
+                    None => (text, Align::Left),
+                },
+            },
+            TemplateValue::List(_) | TemplateValue::Map(_) => {
+                return Err(TemplateError::NotScalar {
+                    placeholder: self.path.clone(),
+                })
+            }
+        };
+        // Labels are single-line
+        let text = text.replace("\r\n", " ").replace(['\n', '\r'], " ");
+
+        let padding = self.width.saturating_sub(text.chars().count());
+        let (before, after) = match self.align.unwrap_or(default_align) {
+            Align::Left => (0, padding),
+            Align::Right => (padding, 0),
+            Align::Center => (padding / 2, padding - padding / 2),
+        };
+        output.extend(std::iter::repeat_n(self.fill, before));
+        output.push_str(&text);
+        output.extend(std::iter::repeat_n(self.fill, after));
+        Ok(())
+    }
+
+    /// Formats `text` as a number if the spec asks for number formatting
+    /// (grouping or precision) and the text is a number
+    fn numeric_text(&self, text: &str) -> Option<String> {
+        if !self.grouping && self.precision.is_none() {
+            return None;
+        }
+        let text = text.trim();
+        if let Ok(number) = text.parse::<i128>() {
+            return Some(self.format_integer(number));
+        }
+        let number = text.parse::<f64>().ok().filter(|n| n.is_finite())?;
+        Some(self.format_number(number))
+    }
+
+    /// Formats an integer with the placeholder's precision and grouping
+    fn format_integer(&self, number: i128) -> String {
+        let mut text = number.to_string();
+        if let Some(precision) = self.precision.filter(|&precision| precision > 0) {
+            text.push('.');
+            text.extend(std::iter::repeat_n('0', precision));
+        }
+        self.group(text)
+    }
+
+    /// Formats a number with the placeholder's precision and grouping
+    fn format_number(&self, number: f64) -> String {
+        let text = match self.precision {
+            Some(precision) => format!("{:.*}", precision, number),
+            None => number.to_string(),
+        };
+        self.group(text)
+    }
+
+    /// Groups the integer digits of a formatted number if the spec asks
+    /// for grouping
+    fn group(&self, text: String) -> String {
+        if !self.grouping {
+            return text;
+        }
+
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(dot) => unsigned.split_at(dot),
+            None => (unsigned, ""),
+        };
+        let mut grouped = String::from(sign);
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        grouped.push_str(fraction);
+        grouped
+    }
+}
+
+/// Error creating or rendering a [`Template`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A label text contains a malformed placeholder.
+    Syntax {
+        /// Path of the label within the description
+        widget: String,
+        /// What is wrong with the placeholder
+        message: String,
+    },
+
+    /// The layout is invalid (see [`WidgetDescription::build`]).
+    Layout(LayoutError),
+
+    /// The data has no value for a placeholder.
+    MissingValue {
+        /// Path of the placeholder
+        placeholder: String,
+    },
+
+    /// The value of a placeholder is a list or map.
+    NotScalar {
+        /// Path of the placeholder
+        placeholder: String,
+    },
+
+    /// Rendering onto the page failed.
+    Render(RenderError),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Syntax { widget, message } => write!(f, "{}: {}", widget, message),
+            TemplateError::Layout(error) => error.fmt(f),
+            TemplateError::MissingValue { placeholder } => {
+                write!(f, "No value for placeholder {{{{{}}}}}", placeholder)
+            }
+            TemplateError::NotScalar { placeholder } => write!(
+                f,
+                "Value for placeholder {{{{{}}}}} is a list or map",
+                placeholder
+            ),
+            TemplateError::Render(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TemplateError::Layout(error) => Some(error),
+            TemplateError::Render(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde_dep::Deserialize<'de> for TemplateValue {
+    fn deserialize<D: serde_dep::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde_dep::de::{MapAccess, SeqAccess, Visitor};
+
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = TemplateValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a template value")
+            }
+
+            fn visit_unit<E>(self) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Null)
+            }
+
+            fn visit_none<E>(self) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Null)
+            }
+
+            fn visit_some<D: serde_dep::Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<TemplateValue, D::Error> {
+                serde_dep::Deserialize::deserialize(deserializer)
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Bool(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Integer(value.into()))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Integer(value.into()))
+            }
+
+            fn visit_i128<E>(self, value: i128) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Integer(value))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Number(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Text(value.to_string()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<TemplateValue, E> {
+                Ok(TemplateValue::Text(value))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<TemplateValue, A::Error> {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(TemplateValue::List(items))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TemplateValue, A::Error> {
+                let mut entries = BTreeMap::new();
+                while let Some((key, value)) = map.next_entry::<String, TemplateValue>()? {
+                    entries.insert(key, value);
+                }
+                Ok(TemplateValue::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{ChildDescription, TextStyle};
+
+    fn placeholder(spec: &str) -> Placeholder {
+        parse_placeholder(spec).unwrap()
+    }
+
+    fn format(spec: &str, value: impl Into<TemplateValue>) -> String {
+        let mut data = BTreeMap::new();
+        data.insert("v".to_string(), value.into());
+        let mut output = String::new();
+        placeholder(&format!("v{}", spec))
+            .write(&data, &mut output)
+            .unwrap();
+        output
+    }
+
+    fn label_template(width: u16, text: &str, style: Vec<TextStyle>) -> Template {
+        Template::new(WidgetDescription::Rect {
+            width: 40,
+            height: 1,
+            children: vec![ChildDescription {
+                position: (0, 0),
+                widget: WidgetDescription::Label {
+                    width,
+                    text: text.to_string(),
+                    style,
+                },
+            }],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_segments() {
+        assert_eq!(
+            parse("Total: {{ total :>8.2}} EUR").unwrap(),
+            [
+                Segment::Literal("Total: ".to_string()),
+                Segment::Placeholder(Placeholder {
+                    path: "total".to_string(),
+                    fill: ' ',
+                    align: Some(Align::Right),
+                    width: 8,
+                    grouping: false,
+                    precision: Some(2),
+                }),
+                Segment::Literal(" EUR".to_string()),
+            ]
+        );
+        assert_eq!(parse("").unwrap(), []);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("{{name").is_err());
+        assert!(parse("{{}}").is_err());
+        assert!(parse("{{a..b}}").is_err());
+        assert!(parse("{{first name}}").is_err());
+        assert!(parse("{{n:>x}}").is_err());
+        assert!(parse("{{n:.}}").is_err());
+        assert!(parse("{{n:99999999999}}").is_err());
+        assert!(parse("{{n:.99999999999}}").is_err());
+        assert!(parse("{{n:65536}}").is_err());
+        assert!(parse("{{n:65535.65535}}").is_ok());
+    }
+
+    #[test]
+    fn test_format_text() {
+        assert_eq!(format("", "abc"), "abc");
+        assert_eq!(format(":6", "abc"), "abc   ");
+        assert_eq!(format(":>6", "abc"), "   abc");
+        assert_eq!(format(":*^7", "abc"), "**abc**");
+        assert_eq!(format(":.2", "abc"), "ab");
+        assert_eq!(format("", "two\nlines"), "two lines");
+        assert_eq!(format(":5", true), "true ");
+        assert_eq!(format(":3", TemplateValue::Null), "   ");
+    }
+
+    #[test]
+    fn test_format_numbers() {
+        assert_eq!(format("", 42i64), "42");
+        assert_eq!(format("", 2.5), "2.5");
+        assert_eq!(format(":6", 42i64), "    42");
+        assert_eq!(format(":<6", 42i64), "42    ");
+        assert_eq!(format(":0>6.2", 4.256), "004.26");
+        assert_eq!(format(":,", 1234567i64), "1,234,567");
+        assert_eq!(format(":,.2", -1234.5), "-1,234.50");
+        assert_eq!(format(":,.2", "1234.5"), "1,234.50");
+        assert_eq!(format(":,", 999i64), "999");
+        assert_eq!(format(":.2", 42i64), "42.00");
+        assert_eq!(format(":,.1", -1234i64), "-1,234.0");
+    }
+
+    #[test]
+    fn test_format_large_integers_exactly() {
+        assert_eq!(format("", 9007199254740993i64), "9007199254740993");
+        assert_eq!(format(":,", i64::MAX), "9,223,372,036,854,775,807");
+        assert_eq!(
+            format(":,.2", "9007199254740993"),
+            "9,007,199,254,740,993.00"
+        );
+        assert_eq!(format(":.3", "00123"), "123.000");
+    }
+
+    #[test]
+    fn test_lookup_paths() {
+        let mut customer = BTreeMap::new();
+        customer.insert("name".to_string(), TemplateValue::from("ACME"));
+        let mut record = BTreeMap::new();
+        record.insert("customer".to_string(), TemplateValue::Map(customer));
+        record.insert(
+            "items".to_string(),
+            TemplateValue::List(vec![TemplateValue::from("first")]),
+        );
+        let record = TemplateValue::Map(record);
+
+        assert_eq!(record.lookup("customer.name"), Some("ACME".into()));
+        assert_eq!(record.lookup("items.0"), Some("first".into()));
+        assert_eq!(record.lookup("items.1"), None);
+        assert_eq!(record.lookup("customer.name.first"), None);
+
+        let mut flat = HashMap::new();
+        flat.insert("customer.name".to_string(), "Flat".to_string());
+        assert_eq!(flat.lookup("customer.name"), Some("Flat".into()));
+    }
+
+    #[test]
+    fn test_values_truncate_at_label_width() {
+        let template = label_template(8, "Name: {{name}}", Vec::new());
+        let mut data = HashMap::new();
+        data.insert("name".to_string(), "Bartholomew".to_string());
+        assert_eq!(
+            template.render(&data).unwrap().to_plain_text(),
+            "Name: Ba\n"
+        );
+
+        let template = label_template(8, "{{name}}", vec![TextStyle::DoubleWidth]);
+        assert_eq!(template.render(&data).unwrap().to_plain_text(), "B a r t\n");
+    }
+
+    #[test]
+    fn test_render_errors() {
+        let template = label_template(20, "{{missing}}", Vec::new());
+        let data: HashMap<String, String> = HashMap::new();
+        assert_eq!(
+            template.render(&data).unwrap_err(),
+            TemplateError::MissingValue {
+                placeholder: "missing".to_string()
+            }
+        );
+
+        let template = label_template(20, "{{items}}", Vec::new());
+        let mut data = HashMap::new();
+        data.insert("items".to_string(), TemplateValue::List(Vec::new()));
+        assert!(matches!(
+            template.render(&data),
+            Err(TemplateError::NotScalar { .. })
+        ));
+    }
+
+    #[test]
+    fn test_new_errors() {
+        let error = Template::new(WidgetDescription::Label {
+            width: 10,
+            text: "{{oops".to_string(),
+            style: Vec::new(),
+        })
+        .unwrap_err();
+        assert!(matches!(error, TemplateError::Syntax { ref widget, .. } if widget == "root"));
+
+        // Placeholder text longer than the label is fine; the layout is not
+        let error = Template::new(WidgetDescription::Rect {
+            width: 10,
+            height: 1,
+            children: vec![ChildDescription {
+                position: (5, 0),
+                widget: WidgetDescription::Label {
+                    width: 8,
+                    text: "{{a.very.long.placeholder.path}}".to_string(),
+                    style: Vec::new(),
+                },
+            }],
+        })
+        .unwrap_err();
+        assert!(matches!(error, TemplateError::Layout(_)));
+    }
+}
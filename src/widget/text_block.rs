@@ -0,0 +1,225 @@
+//! TextBlock widget for rendering preformatted multi-line text.
+
+use super::label::visible_text;
+use super::{RenderContext, RenderError, Widget};
+use crate::cell::StyleFlags;
+
+/// Default distance between tab stops, in characters
+const DEFAULT_TAB_WIDTH: u16 = 8;
+
+/// Leaf widget for rendering preformatted multi-line text with compile-time
+/// dimensions.
+///
+/// Text keeps its whitespace and is never wrapped: each line of the text
+/// (separated by `\n` or `\r\n`) is one row of the block. Tabs expand to
+/// the next tab stop (every 8 characters by default). Lines wider than
+/// WIDTH and lines beyond HEIGHT are silently truncated.
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::{rect_new, text_block_new};
+/// use escp_layout::Page;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut root = rect_new!(40, 10);
+/// let block = text_block_new!(12, 3).add_text("Qty\tItem\n  2\tWidgets\n  1\tGadget");
+/// root.add_child(block, (0, 0))?;
+///
+/// let mut page_builder = Page::builder();
+/// page_builder.render(&root)?;
+/// let page = page_builder.build();
+///
+/// let text = page.to_plain_text();
+/// assert!(text.starts_with("Qty     Item\n  2     Widg\n  1     Gadg\n"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct TextBlock<const WIDTH: u16, const HEIGHT: u16> {
+    /// Text content to render (empty until add_text() called)
+    text: String,
+
+    /// Distance between tab stops, in characters
+    tab_width: u16,
+
+    /// Text style (bold, underline, italic, etc.)
+    style: StyleFlags,
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> TextBlock<WIDTH, HEIGHT> {
+    /// Create a new empty TextBlock with specified const generic dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if WIDTH or HEIGHT is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::TextBlock;
+    ///
+    /// let block = TextBlock::<40, 5>::new().add_text("Line 1\nLine 2");
+    /// ```
+    pub fn new() -> Self {
+        debug_assert!(WIDTH > 0, "TextBlock WIDTH must be greater than 0");
+        debug_assert!(HEIGHT > 0, "TextBlock HEIGHT must be greater than 0");
+
+        Self {
+            text: String::new(),
+            tab_width: DEFAULT_TAB_WIDTH,
+            style: StyleFlags::NONE,
+        }
+    }
+
+    /// Set the text content of the block (builder pattern).
+    ///
+    /// Never fails: text that does not fit is truncated when rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::text_block_new;
+    ///
+    /// let block = text_block_new!(20, 3).add_text(" /\\_/\\\n( o.o )\n > ^ <");
+    /// ```
+    pub fn add_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Set the distance between tab stops in characters (builder pattern).
+    ///
+    /// A width of 0 is treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use escp_layout::widget::text_block_new;
+    ///
+    /// let block = text_block_new!(20, 2)
+    ///     .add_text("a\tb\nab\tc")
+    ///     .tab_width(4);
+    /// ```
+    pub fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Apply bold styling (builder pattern).
+    pub fn bold(mut self) -> Self {
+        self.style = self.style.with_bold();
+        self
+    }
+
+    /// Apply underline styling (builder pattern).
+    pub fn underline(mut self) -> Self {
+        self.style = self.style.with_underline();
+        self
+    }
+
+    /// Apply italic styling (builder pattern).
+    pub fn italic(mut self) -> Self {
+        self.style = self.style.with_italic();
+        self
+    }
+
+    /// Apply double-strike styling (builder pattern).
+    pub fn double_strike(mut self) -> Self {
+        self.style = self.style.with_double_strike();
+        self
+    }
+
+    /// Apply strikethrough styling (builder pattern).
+    pub fn strikethrough(mut self) -> Self {
+        self.style = self.style.with_strikethrough();
+        self
+    }
+
+    /// Apply double-width styling (builder pattern).
+    ///
+    /// Each character occupies two columns, so only WIDTH / 2 characters of
+    /// each line are shown.
+    pub fn double_width(mut self) -> Self {
+        self.style = self.style.with_double_width();
+        self
+    }
+
+    /// Apply double-height styling (builder pattern).
+    ///
+    /// Each line occupies two rows, so only HEIGHT / 2 lines are shown.
+    pub fn double_height(mut self) -> Self {
+        self.style = self.style.with_double_height();
+        self
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Default for TextBlock<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Widget for TextBlock<WIDTH, HEIGHT> {
+    const WIDTH: u16 = WIDTH;
+    const HEIGHT: u16 = HEIGHT;
+
+    fn render_to(
+        &self,
+        context: &mut RenderContext,
+        position: (u16, u16),
+    ) -> Result<(), RenderError> {
+        let line_height = self.style.glyph_height();
+        let rows = (0..HEIGHT).step_by(line_height as usize);
+        for (row, line) in rows.zip(self.text.split('\n')) {
+            // Double-height lines need their second row inside the block
+            if HEIGHT - row < line_height {
+                break;
+            }
+            let line = expand_tabs(line.strip_suffix('\r').unwrap_or(line), self.tab_width);
+            let visible = visible_text(&line, WIDTH, self.style);
+            if !visible.is_empty() {
+                context.write_styled(visible, (position.0, position.1 + row), self.style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replaces tabs with spaces up to the next multiple of `tab_width`
+/// characters.
+fn expand_tabs(line: &str, tab_width: u16) -> String {
+    let tab_width = tab_width as usize;
+    let mut expanded = String::with_capacity(line.len());
+    let mut column = 0;
+    for ch in line.chars() {
+        if ch == '\t' {
+            let spaces = tab_width - column % tab_width;
+            expanded.extend(std::iter::repeat_n(' ', spaces));
+            column += spaces;
+        } else {
+            expanded.push(ch);
+            column += 1;
+        }
+    }
+    expanded
+}
+
+/// Ergonomic macro for creating TextBlock widgets.
+///
+/// Expands `text_block_new!(W, H)` to `TextBlock::<W, H>::new()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use escp_layout::widget::text_block_new;
+///
+/// let block = text_block_new!(40, 5).add_text("Line 1\nLine 2");
+/// ```
+#[macro_export]
+macro_rules! text_block_new {
+    ($w:expr, $h:expr) => {
+        $crate::widget::TextBlock::<$w, $h>::new()
+    };
+}
+
+pub use text_block_new;
@@ -0,0 +1,234 @@
+//! End-to-end tests of the escp-layout command-line tool.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+const LAYOUT: &str = r#"{
+    "type": "rect",
+    "width": 40,
+    "height": 2,
+    "children": [
+        { "position": [0, 0], "widget": { "type": "label", "width": 30, "text": "INVOICE {{number}}", "style": ["bold"] } },
+        { "position": [0, 1], "widget": { "type": "label", "width": 30, "text": "Total: {{total:>8.2}}" } }
+    ]
+}"#;
+
+const RECORDS: &str = r#"[
+    { "number": 1, "total": 12.5 },
+    { "number": 2, "total": 7 }
+]"#;
+
+/// Temporary directory removed when dropped
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("escp-cli-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    fn file(&self, name: &str, contents: &[u8]) -> String {
+        let path = self.0.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    fn path(&self, name: &str) -> String {
+        self.0.join(name).to_str().unwrap().to_string()
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_escp-layout"))
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_render_template_and_decode() {
+    let dir = TempDir::new("render");
+    let layout = dir.file("invoice.json", LAYOUT.as_bytes());
+    let records = dir.file("records.json", RECORDS.as_bytes());
+    let prn = dir.path("invoice.prn");
+
+    let output = run(&["render", &layout, "--data", &records, "-o", &prn]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(std::fs::read(&prn).unwrap().starts_with(b"\x1b@"));
+
+    let output = run(&["decode", &prn]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        stdout(&output),
+        "INVOICE 1\nTotal:    12.50\n--- page 2 ---\nINVOICE 2\nTotal:     7.00\n"
+    );
+}
+
+#[test]
+fn test_preview() {
+    let dir = TempDir::new("preview");
+    let layout = dir.file("invoice.json", LAYOUT.as_bytes());
+    let records = dir.file("records.json", RECORDS.as_bytes());
+
+    let output = run(&["preview", &layout, "--data", &records, "--html"]);
+    assert!(output.status.success(), "{:?}", output);
+    let html = stdout(&output);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<b>INVOICE 1</b>"));
+
+    // Without data, placeholders print as written
+    let output = run(&["preview", &layout]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(stdout(&output).starts_with("INVOICE {{number}}\n"));
+}
+
+#[test]
+fn test_diff_exit_status() {
+    let dir = TempDir::new("diff");
+    let layout = dir.file("invoice.json", LAYOUT.as_bytes());
+    let first = dir.file("first.json", br#"{ "number": 1, "total": 12.5 }"#);
+    let second = dir.file("second.json", br#"{ "number": 1, "total": 13.5 }"#);
+    let a = dir.path("a.prn");
+    let b = dir.path("b.prn");
+    assert!(run(&["render", &layout, "-d", &first, "-o", &a])
+        .status
+        .success());
+    assert!(run(&["render", &layout, "-d", &second, "-o", &b])
+        .status
+        .success());
+
+    let output = run(&["diff", &a, &a]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+
+    let output = run(&["diff", &a, &b]);
+    assert_eq!(output.status.code(), Some(1));
+    let text = stdout(&output);
+    assert!(text.starts_with("page 1:\n"), "{}", text);
+    assert!(text.contains("Total:    12.50"));
+    assert!(text.contains("Total:    13.50"));
+}
+
+#[test]
+fn test_send_to_file_and_socket() {
+    let dir = TempDir::new("send");
+    let prn = dir.file("job.prn", b"\x1b@Hello\x0c");
+
+    let device = dir.file("device", b"");
+    let output = run(&["send", &prn, &device]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(std::fs::read(&device).unwrap(), b"\x1b@Hello\x0c");
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let receiver = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut received = Vec::new();
+        stream.read_to_end(&mut received).unwrap();
+        received
+    });
+    let output = run(&["send", &prn, &address]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(receiver.join().unwrap(), b"\x1b@Hello\x0c");
+}
+
+#[test]
+fn test_errors() {
+    let output = run(&["frobnicate"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown command"));
+
+    let output = run(&["decode"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run(&["decode", "/nonexistent/file.prn"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("/nonexistent/file.prn"));
+
+    let dir = TempDir::new("errors");
+    let layout = dir.file("invoice.json", LAYOUT.as_bytes());
+    let record = dir.file("record.json", br#"{ "number": 1 }"#);
+    let output = run(&[
+        "render",
+        &layout,
+        "--data",
+        &record,
+        "-o",
+        &dir.path("x.prn"),
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("total"));
+}
+
+#[test]
+fn test_send_rejects_options_for_other_destinations() {
+    let dir = TempDir::new("send-options");
+    let prn = dir.file("job.prn", b"\x1b@Hello\x0c");
+    let device = dir.file("device", b"");
+
+    let output = run(&["send", &prn, &device, "--timeout", "5"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--timeout"));
+
+    let output = run(&["send", &prn, &device, "--job-name", "invoice"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--job-name"));
+
+    let output = run(&["send", &prn, "127.0.0.1:9", "--job-name", "invoice"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--job-name"));
+
+    let output = run(&["send", &prn, "lpr:office", "--timeout", "5"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--timeout"));
+
+    assert_eq!(std::fs::read(&device).unwrap(), b"");
+}
+
+#[test]
+fn test_send_rejects_out_of_range_timeouts() {
+    let dir = TempDir::new("send-timeout");
+    let prn = dir.file("job.prn", b"\x1b@Hello\x0c");
+
+    for timeout in ["1e30", "1e-30", "0", "-1", "NaN", "inf"] {
+        let output = run(&["send", &prn, "127.0.0.1:9", "--timeout", timeout]);
+        assert_eq!(output.status.code(), Some(2), "{}: {:?}", timeout, output);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("invalid timeout"));
+    }
+}
+
+#[test]
+fn test_help() {
+    let output = run(&["help"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Usage: escp-layout"));
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_escp-layout"))
+        .args(["decode", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"\x1b@Piped\x0c")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(stdout(&output).starts_with("Piped\n"));
+}
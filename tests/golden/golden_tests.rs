@@ -1,6 +1,6 @@
 //! Golden master tests for ESC/P output validation
 
-use escp_layout::{Document, Page, StyleFlags};
+use escp_layout::{Decoder, Document, Page, RenderOptions, StyleFlags};
 use std::fs;
 use std::path::PathBuf;
 
@@ -12,6 +12,10 @@ fn get_golden_path(name: &str) -> PathBuf {
 }
 
 fn generate_invoice() -> Vec<u8> {
+    invoice_document().render()
+}
+
+fn invoice_document() -> Document {
     let mut page_builder = Page::builder();
 
     // Header
@@ -63,9 +67,59 @@ fn generate_invoice() -> Vec<u8> {
     let page = page_builder.build();
     let mut doc_builder = Document::builder();
     doc_builder.add_page(page);
-    let document = doc_builder.build();
+    doc_builder.build()
+}
+
+fn multi_page_document() -> Document {
+    let mut doc_builder = Document::builder();
+    for i in 1..=3 {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, &format!("Page {}", i), StyleFlags::BOLD);
+        doc_builder.add_page(page_builder.build());
+    }
+    doc_builder.build()
+}
+
+fn compact() -> RenderOptions {
+    RenderOptions::new().with_compact(true)
+}
+
+/// Compares output against a golden file, creating it on first run
+fn assert_golden(name: &str, current_output: &[u8]) {
+    let golden_path = get_golden_path(name);
 
-    document.render()
+    if !golden_path.exists() {
+        fs::write(&golden_path, current_output).expect("Failed to write golden master");
+        return;
+    }
+
+    let golden_output = fs::read(&golden_path).expect("Failed to read golden master");
+    if current_output != golden_output {
+        panic!(
+            "{} golden master mismatch\n{}",
+            name,
+            describe_mismatch(&golden_output, current_output)
+        );
+    }
+}
+
+/// Describes how ESC/P output differs from the golden bytes, cell by cell
+fn describe_mismatch(golden_output: &[u8], current_output: &[u8]) -> String {
+    if current_output.starts_with(b"%PDF") {
+        return format!(
+            "golden: {} bytes, current: {} bytes",
+            golden_output.len(),
+            current_output.len()
+        );
+    }
+    let golden = Decoder::new().decode(golden_output).into_document();
+    let current = Decoder::new().decode(current_output).into_document();
+    let diff = golden.diff(&current);
+    if diff.is_empty() {
+        "same cells, different command bytes".to_string()
+    } else {
+        diff.to_string()
+    }
 }
 
 #[test]
@@ -150,9 +204,10 @@ fn test_hello_world_golden() {
 
     let golden_output = fs::read(&golden_path).expect("Failed to read golden master");
 
-    assert_eq!(
-        current_output, golden_output,
-        "Hello World golden master mismatch"
+    assert!(
+        current_output == golden_output,
+        "Hello World golden master mismatch\n{}",
+        describe_mismatch(&golden_output, &current_output)
     );
 }
 
@@ -180,9 +235,10 @@ fn test_multi_page_golden() {
 
     let golden_output = fs::read(&golden_path).expect("Failed to read golden master");
 
-    assert_eq!(
-        current_output, golden_output,
-        "Multi-page golden master mismatch"
+    assert!(
+        current_output == golden_output,
+        "Multi-page golden master mismatch\n{}",
+        describe_mismatch(&golden_output, &current_output)
     );
 
     // Verify form-feed count
@@ -273,6 +329,62 @@ fn test_styled_invoice_golden() {
     assert_eq!(golden_hash, current_hash);
 }
 
+#[test]
+fn test_invoice_compact_golden() {
+    let document = invoice_document();
+    let compact_output = document.render_with(compact());
+    assert_golden("invoice_compact.bin", &compact_output);
+
+    // Same initialization, far fewer bytes
+    assert!(compact_output.len() * 4 < document.render().len());
+    assert!(compact_output.starts_with(&document.render()[..6]));
+}
+
+#[test]
+fn test_multi_page_compact_golden() {
+    let compact_output = multi_page_document().render_with(compact());
+    assert_golden("multi_page_compact.bin", &compact_output);
+
+    // Blank rows below the heading are left to the form feed
+    let ff_count = compact_output.iter().filter(|&&b| b == 0x0C).count();
+    assert_eq!(ff_count, 3);
+    assert!(compact_output.ends_with(b"\x1bEPage 3\r\n\x1bF\x0C"));
+}
+
+#[test]
+fn test_hello_world_compact_golden() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "Hello, World!", StyleFlags::NONE);
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page_builder.build());
+    let compact_output = doc_builder.build().render_with(compact());
+
+    assert_golden("hello_world_compact.bin", &compact_output);
+    assert_eq!(compact_output, b"\x1b@\x0f\x1bC\x33Hello, World!\r\n\x0C");
+}
+
+#[test]
+fn test_invoice_pdf_golden() {
+    let pdf = invoice_document().to_pdf();
+    assert_golden("invoice.pdf", &pdf);
+
+    // No timestamps: the same document always gives the same bytes
+    assert_eq!(pdf, invoice_document().to_pdf());
+    assert!(pdf.starts_with(b"%PDF-1.4\n"));
+    assert!(pdf.ends_with(b"%%EOF\n"));
+}
+
+#[test]
+fn test_multi_page_pdf_golden() {
+    let pdf = multi_page_document().to_pdf();
+    assert_golden("multi_page.pdf", &pdf);
+
+    // One PDF page per document page
+    let page_count = pdf.windows(11).filter(|w| w == b"/Type /Page").count()
+        - pdf.windows(12).filter(|w| w == b"/Type /Pages").count();
+    assert_eq!(page_count, 3);
+}
+
 // Simple SHA-256 hash computation (using only std)
 fn sha256_hash(data: &[u8]) -> String {
     // For simplicity, use a basic hash (sum of bytes) in tests
@@ -282,10 +394,10 @@ fn sha256_hash(data: &[u8]) -> String {
 }
 
 #[test]
+#[ignore]
 fn test_regenerate_all_golden_masters() {
     // This test can be run manually to regenerate all golden masters
     // Run with: cargo test test_regenerate_all_golden_masters -- --ignored
-    #[ignore]
     fn regenerate() {
         let invoice_path = get_golden_path("invoice.bin");
         let hello_path = get_golden_path("hello_world.bin");
@@ -323,6 +435,28 @@ fn test_regenerate_all_golden_masters() {
             "✓ Regenerated multi_page.bin ({} bytes)",
             multi_output.len()
         );
+
+        // Regenerate compact variants
+        let invoice_compact = invoice_document().render_with(compact());
+        fs::write(get_golden_path("invoice_compact.bin"), &invoice_compact).unwrap();
+        let multi_compact = multi_page_document().render_with(compact());
+        fs::write(get_golden_path("multi_page_compact.bin"), &multi_compact).unwrap();
+        let mut hello_compact_builder = Document::builder();
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, "Hello, World!", StyleFlags::NONE);
+        hello_compact_builder.add_page(page_builder.build());
+        let hello_compact = hello_compact_builder.build().render_with(compact());
+        fs::write(get_golden_path("hello_world_compact.bin"), &hello_compact).unwrap();
+        println!("✓ Regenerated compact golden masters");
+
+        // Regenerate PDF exports
+        fs::write(get_golden_path("invoice.pdf"), invoice_document().to_pdf()).unwrap();
+        fs::write(
+            get_golden_path("multi_page.pdf"),
+            multi_page_document().to_pdf(),
+        )
+        .unwrap();
+        println!("✓ Regenerated PDF golden masters");
     }
 
     regenerate();
@@ -0,0 +1,153 @@
+//! Integration tests for decoding rendered ESC/P back into documents
+
+use escp_layout::{
+    BorderStyle, CharacterTable, Decoder, Document, HorizontalSkip, LineSpacing, Page,
+    PageGeometry, Pitch, RenderOptions, StyleFlags,
+};
+
+fn invoice_page(geometry: PageGeometry) -> Page {
+    let mut builder = Page::builder_with_geometry(geometry);
+    builder.write_str(0, 0, "INVOICE #12345", StyleFlags::BOLD.with_double_width());
+    builder.write_str(0, 2, "Customer: ACME Corp", StyleFlags::NONE);
+    builder.write_str(0, 4, "Item", StyleFlags::UNDERLINE);
+    builder.write_str(40, 4, "Amount", StyleFlags::UNDERLINE);
+    builder.write_str(0, 5, "Widget", StyleFlags::NONE);
+    builder.write_str(40, 5, "$1,250.00", StyleFlags::NONE);
+    builder.write_str(30, 8, "TOTAL", StyleFlags::DOUBLE_HEIGHT.with_bold());
+    builder.write_str(40, 8, "$1,250.00", StyleFlags::BOLD);
+    builder.build()
+}
+
+#[test]
+fn test_round_trip_multi_page_document() {
+    let mut builder = Document::builder();
+    builder
+        .add_page(invoice_page(PageGeometry::default()))
+        .add_page(invoice_page(PageGeometry::new(160, 20)))
+        .add_page(Page::builder().build());
+    let document = builder.build();
+
+    let decoded = Decoder::new().decode(&document.render());
+    assert!(decoded.unknown_sequences().is_empty());
+    assert_eq!(decoded.into_document(), document);
+}
+
+#[test]
+fn test_round_trip_pitches_and_spacings() {
+    let pitches = [
+        Pitch::Cpi10,
+        Pitch::Cpi12,
+        Pitch::Cpi15,
+        Pitch::Condensed17,
+        Pitch::Condensed20,
+    ];
+    let spacings = [
+        LineSpacing::SixthInch,
+        LineSpacing::EighthInch,
+        LineSpacing::SevenSeventySecondInch,
+        LineSpacing::Units180(24),
+        LineSpacing::Units360(50),
+    ];
+
+    for pitch in pitches {
+        let build = |line_spacing| {
+            let mut builder = Document::builder();
+            builder
+                .pitch(pitch)
+                .line_spacing(line_spacing)
+                .add_page(invoice_page(PageGeometry::new(80, 60)));
+            builder.build()
+        };
+        for line_spacing in spacings {
+            let document = build(line_spacing);
+            // 7/72 inch is sent as ESC + 35
+            let expected = match line_spacing {
+                LineSpacing::SevenSeventySecondInch => build(LineSpacing::Units360(35)),
+                _ => document.clone(),
+            };
+
+            let decoded = Decoder::new().decode(&document.render());
+            assert!(decoded.unknown_sequences().is_empty());
+            assert_eq!(
+                decoded.document(),
+                &expected,
+                "{:?} {:?}",
+                pitch,
+                line_spacing
+            );
+        }
+    }
+}
+
+#[test]
+fn test_round_trip_box_drawing() {
+    let mut page_builder = Page::builder_with_geometry(PageGeometry::new(40, 10));
+    page_builder.draw_box(0, 0, 20, 5, BorderStyle::Double, StyleFlags::NONE);
+    page_builder.write_str(2, 2, "Boxed", StyleFlags::NONE);
+
+    let mut builder = Document::builder();
+    builder
+        .character_table(CharacterTable::Pc437)
+        .add_page(page_builder.build());
+    let document = builder.build();
+
+    let decoded = Decoder::new().decode(&document.render());
+    assert!(decoded.unknown_sequences().is_empty());
+    assert_eq!(decoded.document(), &document);
+}
+
+#[test]
+fn test_round_trip_render_options() {
+    let mut builder = Document::builder();
+    builder
+        .add_page(invoice_page(PageGeometry::default()))
+        .add_page(invoice_page(PageGeometry::default()));
+    let document = builder.build();
+    let decoder = Decoder::new().with_columns(160);
+
+    for mode in [
+        HorizontalSkip::Absolute,
+        HorizontalSkip::Relative,
+        HorizontalSkip::Auto,
+    ] {
+        for compact in [false, true] {
+            let options = RenderOptions::new()
+                .with_compact(compact)
+                .with_horizontal_skip(mode);
+            let decoded = decoder.decode(&document.render_with(options));
+            assert!(decoded.unknown_sequences().is_empty());
+            assert_eq!(decoded.document(), &document, "{:?} {}", mode, compact);
+        }
+    }
+}
+
+#[test]
+fn test_round_trip_streamed_page() {
+    let page = invoice_page(PageGeometry::new(60, 12));
+    let mut bytes = Vec::new();
+    page.render_to(&mut bytes).unwrap();
+
+    let decoded = Decoder::new().decode(&bytes);
+    assert_eq!(decoded.document().pages(), [page]);
+}
+
+#[test]
+fn test_decode_reports_unknown_sequences() {
+    // ESC l 5 (left margin), ESC k 1 (typeface), then text
+    let decoded = Decoder::new().decode(b"\x1b@\x1bl\x05\x1bk\x01Text\x0c");
+
+    let unknown: Vec<String> = decoded
+        .unknown_sequences()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        unknown,
+        [
+            "unknown sequence at byte 2: 1B 6C 05",
+            "unknown sequence at byte 5: 1B 6B 01",
+        ]
+    );
+    let page = &decoded.document().pages()[0];
+    assert_eq!(page.get_cell(0, 0).unwrap().character(), 'T');
+}
@@ -0,0 +1,77 @@
+//! Integration tests for comparing pages and documents
+
+use escp_layout::{Decoder, Document, Page, PageGeometry, RenderOptions, StyleFlags};
+
+fn receipt(total: &str, total_style: StyleFlags) -> Page {
+    let mut builder = Page::builder_with_geometry(PageGeometry::new(40, 12));
+    builder.write_str(0, 0, "RECEIPT", StyleFlags::BOLD.with_double_width());
+    builder.write_str(0, 2, "Coffee", StyleFlags::NONE);
+    builder.write_str(30, 2, "3.50", StyleFlags::NONE);
+    builder.write_str(0, 4, "TOTAL", StyleFlags::BOLD);
+    builder.write_str(30, 4, total, total_style);
+    builder.build()
+}
+
+fn document(pages: &[Page]) -> Document {
+    let mut builder = Document::builder();
+    for page in pages {
+        builder.add_page(page.clone());
+    }
+    builder.build()
+}
+
+#[test]
+fn test_decoded_output_has_no_differences() {
+    let document = document(&[
+        receipt("3.50", StyleFlags::BOLD),
+        receipt("7.00", StyleFlags::UNDERLINE),
+    ]);
+    let compact = document.render_with(RenderOptions::new().with_compact(true));
+    let decoded = Decoder::new()
+        .with_columns(40)
+        .with_rows(12)
+        .decode(&compact)
+        .into_document();
+
+    let diff = document.diff(&decoded);
+    assert!(diff.is_empty(), "{}", diff);
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn test_page_diff_reports_text_and_style() {
+    let before = receipt("3.50", StyleFlags::BOLD);
+    let after = receipt("3.60", StyleFlags::NONE);
+    let diff = before.diff(&after);
+
+    assert_eq!(diff.changed_rows(), [4]);
+    assert_eq!(diff.changed_columns(), [30, 31, 32, 33]);
+    assert_eq!(diff.text_changes().count(), 1);
+    assert_eq!(diff.style_changes().count(), 4);
+    assert_eq!(
+        diff.side_by_side(),
+        "   4 | TOTAL                         3.50 | TOTAL                         3.60\n\
+         \x20    |                               ^^^^ |                               ^^^^\n"
+    );
+}
+
+#[test]
+fn test_document_diff_reports_pages() {
+    let first = receipt("3.50", StyleFlags::BOLD);
+    let second = receipt("9.99", StyleFlags::BOLD);
+    let before = document(&[first.clone(), second.clone(), second.clone()]);
+    let after = document(&[first, receipt("9.90", StyleFlags::BOLD)]);
+
+    let diff = before.diff(&after);
+    assert!(!diff.settings_changed());
+    assert!(diff.page_diffs()[0].is_empty());
+    assert_eq!(diff.page_diffs()[1].changed_columns(), [33]);
+    assert_eq!(diff.removed_pages(), 2..3);
+    assert_eq!(
+        diff.to_string(),
+        "page 2:\n\
+         \x20  4 | TOTAL                         9.99 | TOTAL                         9.90\n\
+         \x20    |                                  ^ |                                  ^\n\
+         page 3: removed\n"
+    );
+}
@@ -1,6 +1,7 @@
 //! Integration tests for multi-page document rendering (User Story 3)
 
-use escp_layout::{Document, Page, StyleFlags};
+use escp_layout::{Document, Page, RenderOptions, StyleFlags};
+use std::io::{self, Write};
 
 #[test]
 fn test_multi_page_document_with_different_content() {
@@ -115,9 +116,9 @@ fn test_identical_pages_produce_identical_sequences() {
 
     // Split by form-feed (0x0C)
     let mut page_sequences = Vec::new();
-    let mut start = 3; // Skip initialization bytes (ESC @ SI)
+    let mut start = 6; // Skip initialization bytes (ESC @ SI ESC C n)
 
-    for (i, &byte) in bytes.iter().enumerate().skip(3) {
+    for (i, &byte) in bytes.iter().enumerate().skip(6) {
         if byte == 0x0C {
             page_sequences.push(&bytes[start..i]);
             start = i + 1;
@@ -259,3 +260,79 @@ fn test_determinism_across_multi_page_renders() {
     assert_eq!(render1, render2);
     assert_eq!(render2, render3);
 }
+
+#[test]
+fn test_streaming_render_matches_render() {
+    let mut doc_builder = Document::builder();
+    for i in 0..5 {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, &format!("Statement page {}", i + 1), StyleFlags::BOLD);
+        page_builder.write_str(0, 50, "continued", StyleFlags::NONE);
+        doc_builder.add_page(page_builder.build());
+    }
+    let document = doc_builder.build();
+
+    let mut streamed = Vec::new();
+    document.render_to(&mut streamed).unwrap();
+    assert_eq!(streamed, document.render());
+
+    let options = RenderOptions::new().with_compact(true);
+    let mut streamed = Vec::new();
+    document.render_to_with(&mut streamed, options).unwrap();
+    assert_eq!(streamed, document.render_with(options));
+}
+
+#[test]
+fn test_streaming_render_propagates_write_errors() {
+    /// Writer accepting a fixed number of bytes before failing
+    struct FailingWriter(usize);
+
+    impl Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.0 < buf.len() {
+                return Err(io::Error::new(io::ErrorKind::BrokenPipe, "printer offline"));
+            }
+            self.0 -= buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(Page::builder().build());
+    doc_builder.add_page(Page::builder().build());
+    let document = doc_builder.build();
+
+    let error = document.render_to(FailingWriter(100)).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+
+    let error = document.pages()[0]
+        .render_to(FailingWriter(10))
+        .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn test_text_preview_of_multi_page_document() {
+    let mut doc_builder = Document::builder();
+    for page_num in 1..=3 {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, &format!("Page {}", page_num), StyleFlags::BOLD);
+        page_builder.write_str(10, 50, "Footer", StyleFlags::UNDERLINE);
+        doc_builder.add_page(page_builder.build());
+    }
+    let document = doc_builder.build();
+
+    let text = document.to_plain_text();
+    assert_eq!(text.matches("--- page ").count(), 2);
+    assert!(text.starts_with("Page 1\n\n"));
+    assert!(text.contains("          Footer\n--- page 2 ---\nPage 2\n"));
+    assert!(text.ends_with("          Footer\n"));
+
+    let ansi = document.to_ansi();
+    assert!(ansi.starts_with("\x1b[0;1mPage 1\x1b[0m\n"));
+    assert_eq!(ansi.matches("\x1b[0;4mFooter\x1b[0m").count(), 3);
+}
@@ -0,0 +1,155 @@
+//! Integration tests for horizontal skip positioning
+
+use escp_layout::{Document, HorizontalSkip, Page, Pitch, RenderOptions, StyleFlags};
+
+/// Printed glyph: (vertical, horizontal position in 1/360 inch, byte)
+type Glyph = (u32, u32, u8);
+
+/// Default 1/6-inch line spacing in 1/360-inch units
+const LINE: u32 = 60;
+
+/// Replays the subset of ESC/P the renderer emits and records where each
+/// non-blank glyph lands.
+fn printed_glyphs(bytes: &[u8], char_width: u32) -> Vec<Glyph> {
+    let mut glyphs = Vec::new();
+    let (mut vertical, mut position) = (0u32, 0u32);
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            0x1B => {
+                i += match bytes[i + 1] {
+                    // ESC $ nL nH - absolute position
+                    b'$' => {
+                        position = u16::from_le_bytes([bytes[i + 2], bytes[i + 3]]) as u32;
+                        4
+                    }
+                    // ESC \ nL nH - relative position
+                    b'\\' => {
+                        position += i16::from_le_bytes([bytes[i + 2], bytes[i + 3]]) as u32;
+                        4
+                    }
+                    // ESC J n - feed n/180 inch
+                    b'J' => {
+                        vertical += bytes[i + 2] as u32 * 2;
+                        3
+                    }
+                    // ESC ( U 1 0 n
+                    b'(' => 6,
+                    // ESC C n, ESC - n
+                    b'C' | b'-' => 3,
+                    // ESC @, ESC E, ESC F
+                    _ => 2,
+                };
+                continue;
+            }
+            0x0D => position = 0,
+            0x0A => vertical += LINE,
+            0x0C => vertical = (vertical / 100_000 + 1) * 100_000,
+            0x0F => {}
+            byte => {
+                if byte != b' ' {
+                    glyphs.push((vertical, position, byte));
+                }
+                position += char_width;
+            }
+        }
+        i += 1;
+    }
+    glyphs
+}
+
+fn sparse_form() -> Document {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(2, 0, "Name:", StyleFlags::BOLD);
+    page_builder.write_str(40, 0, "Jane Doe", StyleFlags::NONE);
+    page_builder.write_str(120, 0, "Date: 2025-11-18", StyleFlags::NONE);
+    page_builder.write_str(10, 5, "Account", StyleFlags::UNDERLINE);
+    page_builder.write_str(150, 5, "42", StyleFlags::BOLD);
+    page_builder.write_str(0, 50, "x", StyleFlags::NONE);
+    page_builder.write_str(159, 50, "y", StyleFlags::NONE);
+
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page_builder.build());
+    doc_builder.build()
+}
+
+#[test]
+fn test_absolute_skip_lands_on_same_columns() {
+    let document = sparse_form();
+    let width = Pitch::Condensed17.char_width();
+
+    let padded = document.render();
+    let skipped =
+        document.render_with(RenderOptions::new().with_horizontal_skip(HorizontalSkip::Absolute));
+
+    assert!(skipped.len() < padded.len());
+    assert!(skipped.windows(2).any(|w| w == b"\x1b$"));
+    assert_eq!(
+        printed_glyphs(&skipped, width),
+        printed_glyphs(&padded, width)
+    );
+}
+
+#[test]
+fn test_relative_skip_lands_on_same_columns() {
+    let document = sparse_form();
+    let width = Pitch::Condensed17.char_width();
+
+    let padded = document.render();
+    let skipped =
+        document.render_with(RenderOptions::new().with_horizontal_skip(HorizontalSkip::Relative));
+
+    assert!(skipped.windows(2).any(|w| w == b"\x1b\\"));
+    assert_eq!(
+        printed_glyphs(&skipped, width),
+        printed_glyphs(&padded, width)
+    );
+}
+
+#[test]
+fn test_skip_combined_with_compact() {
+    let document = sparse_form();
+    let width = Pitch::Condensed17.char_width();
+
+    let padded = document.render();
+    let options = RenderOptions::new()
+        .with_compact(true)
+        .with_horizontal_skip(HorizontalSkip::Absolute);
+    let optimized = document.render_with(options);
+
+    // Blank rows collapse to feeds, so compare columns row by row in order
+    let columns = |glyphs: Vec<Glyph>| -> Vec<(u32, u8)> {
+        glyphs
+            .into_iter()
+            .map(|(_, pos, byte)| (pos, byte))
+            .collect()
+    };
+    assert_eq!(
+        columns(printed_glyphs(&optimized, width)),
+        columns(printed_glyphs(&padded, width))
+    );
+    assert!(optimized.len() * 10 < padded.len());
+}
+
+#[test]
+fn test_skip_threshold_controls_replacement() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "a", StyleFlags::NONE);
+    page_builder.write_str(8, 0, "b", StyleFlags::NONE);
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page_builder.build());
+    let document = doc_builder.build();
+
+    let options = RenderOptions::new()
+        .with_compact(true)
+        .with_horizontal_skip(HorizontalSkip::Relative);
+
+    // 7 blanks cost more than ESC \
+    let bytes = document.render_with(options);
+    assert!(bytes.windows(2).any(|w| w == b"\x1b\\"));
+
+    // A threshold above the run length keeps the spaces
+    let bytes = document.render_with(options.with_skip_threshold(8));
+    assert!(!bytes.windows(2).any(|w| w == b"\x1b\\"));
+    assert!(bytes.windows(9).any(|w| w == b"a       b"));
+}
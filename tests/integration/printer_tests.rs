@@ -0,0 +1,129 @@
+//! Integration tests for printing rendered ESC/P on the virtual printer
+
+use escp_layout::{
+    Document, HorizontalSkip, LineSpacing, Page, PageGeometry, PageImage, Pitch, RenderOptions,
+    StyleFlags, VirtualPrinter,
+};
+
+/// One pixel per 1/360 inch, so pixel coordinates are paper units
+const PRINTER: VirtualPrinter = VirtualPrinter::new().with_dpi(360);
+
+fn document(pitch: Pitch, line_spacing: LineSpacing, page: Page) -> Document {
+    let mut builder = Document::builder();
+    builder
+        .pitch(pitch)
+        .line_spacing(line_spacing)
+        .add_page(page);
+    builder.build()
+}
+
+/// Returns the bounding box (x0, y0, x1, y1) of the ink inside a region
+fn ink_bounds(image: &PageImage, x0: u32, y0: u32, x1: u32, y1: u32) -> Option<[u32; 4]> {
+    let mut bounds: Option<[u32; 4]> = None;
+    for y in y0..y1 {
+        for x in x0..x1 {
+            if image.ink(x, y) > 0 {
+                let [bx0, by0, bx1, by1] = bounds.unwrap_or([x, y, x + 1, y + 1]);
+                bounds = Some([bx0.min(x), by0.min(y), bx1.max(x + 1), by1.max(y + 1)]);
+            }
+        }
+    }
+    bounds
+}
+
+#[test]
+fn test_cells_land_on_physical_grid() {
+    let pitches = [
+        Pitch::Cpi10,
+        Pitch::Cpi12,
+        Pitch::Cpi15,
+        Pitch::Condensed17,
+        Pitch::Condensed20,
+    ];
+    for pitch in pitches {
+        for line_spacing in [LineSpacing::SixthInch, LineSpacing::EighthInch] {
+            let mut builder = Page::builder_with_geometry(PageGeometry::new(80, 20));
+            builder.write_str(37, 11, "H", StyleFlags::NONE);
+            let printout = PRINTER.print(&document(pitch, line_spacing, builder.build()).render());
+
+            let page = &printout.pages()[0];
+            let (width, spacing) = (pitch.char_width(), line_spacing.units());
+            let bounds = ink_bounds(page, 0, 0, page.width(), page.height());
+            // 'H' fills the first five of the cell's six dot columns and the
+            // seven 1/60-inch dot rows from the top of its line
+            assert_eq!(
+                bounds,
+                Some([
+                    37 * width,
+                    11 * spacing,
+                    37 * width + width * 5 / 6,
+                    11 * spacing + 42
+                ]),
+                "{pitch:?} {line_spacing:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_horizontal_skip_matches_spaces() {
+    let mut builder = Page::builder_with_geometry(PageGeometry::new(160, 4));
+    builder.write_str(0, 1, "Left", StyleFlags::NONE);
+    builder.write_str(120, 1, "Right", StyleFlags::BOLD);
+    let document = document(Pitch::Condensed17, LineSpacing::SixthInch, builder.build());
+
+    let spaces =
+        PRINTER.print(&document.render_with(RenderOptions::new().without_horizontal_skip()));
+    for skip in [
+        HorizontalSkip::Absolute,
+        HorizontalSkip::Relative,
+        HorizontalSkip::Auto,
+    ] {
+        let options = RenderOptions::new().with_horizontal_skip(skip);
+        let skipped = PRINTER.print(&document.render_with(options));
+        assert_eq!(skipped, spaces, "{skip:?}");
+    }
+}
+
+#[test]
+fn test_one_image_per_page() {
+    let mut builder = Document::builder();
+    for _ in 0..3 {
+        let mut page = Page::builder_with_geometry(PageGeometry::new(40, 12));
+        page.write_str(0, 0, "Page", StyleFlags::NONE);
+        builder.add_page(page.build());
+    }
+    let printout = PRINTER.print(&builder.build().render());
+
+    assert_eq!(printout.pages().len(), 3);
+    for page in printout.pages() {
+        // 12 lines of 1/6 inch on 13.6-inch paper
+        assert_eq!((page.width(), page.height()), (4896, 720));
+        assert!(!page.is_blank());
+    }
+    assert!(printout.unknown_sequences().is_empty());
+}
+
+#[test]
+fn test_image_encodings() {
+    let mut builder = Page::builder_with_geometry(PageGeometry::new(10, 2));
+    builder.write_str(0, 0, "X", StyleFlags::NONE);
+    let document = document(Pitch::Cpi10, LineSpacing::SixthInch, builder.build());
+    let printout = VirtualPrinter::new()
+        .with_dpi(60)
+        .with_paper_width(360)
+        .print(&document.render());
+    let page = &printout.pages()[0];
+
+    assert_eq!((page.width(), page.height()), (60, 20));
+    let pbm = page.to_pbm();
+    assert!(pbm.starts_with(b"P4\n60 20\n"));
+    assert_eq!(pbm.len(), b"P4\n60 20\n".len() + 8 * 20);
+
+    let pgm = page.to_pgm();
+    assert!(pgm.starts_with(b"P5\n60 20\n255\n"));
+    assert_eq!(pgm.len(), b"P5\n60 20\n255\n".len() + 60 * 20);
+    // Top-left dot of 'X' is inked, the paper right of the glyph is white
+    assert!(pgm[b"P5\n60 20\n255\n".len()] < 255);
+    assert_eq!(pgm[b"P5\n60 20\n255\n".len() + 30], 255);
+}
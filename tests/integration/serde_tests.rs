@@ -0,0 +1,65 @@
+//! Integration tests for the `serde` feature
+
+use escp_layout::{Document, Page, PageGeometry, Pitch, StyleFlags};
+use proptest::prelude::*;
+
+/// Builds a style from eight random bits, one per style setter
+fn style(bits: u16) -> StyleFlags {
+    let setters: [fn(StyleFlags) -> StyleFlags; 8] = [
+        StyleFlags::with_bold,
+        StyleFlags::with_underline,
+        StyleFlags::with_italic,
+        StyleFlags::with_double_strike,
+        StyleFlags::with_superscript,
+        StyleFlags::with_strikethrough,
+        StyleFlags::with_double_width,
+        StyleFlags::with_double_height,
+    ];
+    setters
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| bits & (1 << bit) != 0)
+        .fold(StyleFlags::NONE, |style, (_, set)| set(style))
+}
+
+proptest! {
+    #[test]
+    fn test_page_round_trip(
+        writes in prop::collection::vec(
+            (0u16..24, 0u16..8, "[ -~éü€]{0,12}", 0u16..256),
+            0..16,
+        ),
+    ) {
+        // Overlapping writes leave orphaned halves of double-size glyphs
+        let mut builder = Page::builder_with_geometry(PageGeometry::new(24, 8));
+        for (x, y, text, bits) in &writes {
+            builder.write_str(*x, *y, text, style(*bits));
+        }
+        let page = builder.build();
+
+        let json = serde_json::to_string(&page).unwrap();
+        let decoded: Page = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(decoded, page);
+    }
+}
+
+#[test]
+fn test_document_round_trip_renders_identically() {
+    let mut doc_builder = Document::builder();
+    doc_builder.pitch(Pitch::Cpi10);
+    for i in 1..=3 {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, &format!("Page {}", i), StyleFlags::BOLD);
+        page_builder.write_str(0, 2, "Total", StyleFlags::DOUBLE_WIDTH);
+        doc_builder.add_page(page_builder.build());
+    }
+    let document = doc_builder.build();
+
+    let json = serde_json::to_string(&document).unwrap();
+    let decoded: Document = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, document);
+    assert_eq!(decoded.render(), document.render());
+
+    // Far smaller than one object per cell
+    assert!(json.len() < 1000, "{} bytes", json.len());
+}
@@ -1,6 +1,6 @@
 //! Integration tests for single-page rendering (User Story 1)
 
-use escp_layout::{Document, Page, StyleFlags};
+use escp_layout::{BorderStyle, CharacterTable, Document, InternationalCharset, Page, StyleFlags};
 
 #[test]
 fn test_single_page_invoice_rendering() {
@@ -104,3 +104,118 @@ fn test_deterministic_rendering() {
         assert_eq!(first_render, render, "All renders must be identical");
     }
 }
+
+#[test]
+fn test_last_row_is_printed() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 50, "Final row", StyleFlags::BOLD);
+
+    let page = page_builder.build();
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page);
+    let document = doc_builder.build();
+    let bytes = document.render();
+
+    // Page length covers all 51 rows
+    assert_eq!(&bytes[3..6], &[0x1B, 0x43, 51]);
+
+    // Every row is terminated by CR LF
+    let line_count = bytes.windows(2).filter(|w| *w == [0x0D, 0x0A]).count();
+    assert_eq!(line_count, 51);
+
+    // Row 50 content appears after the 50th line break, before the form-feed
+    let fiftieth_break = bytes
+        .windows(2)
+        .enumerate()
+        .filter(|(_, w)| *w == [0x0D, 0x0A])
+        .nth(49)
+        .map(|(i, _)| i)
+        .unwrap();
+    let last_row = String::from_utf8_lossy(&bytes[fiftieth_break + 2..]);
+    assert!(last_row.starts_with("\x1bEFinal row"));
+}
+
+#[test]
+fn test_accented_names_print_with_character_table() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "José Müller £12 €5", StyleFlags::NONE);
+    let page = page_builder.build();
+
+    let mut doc_builder = Document::builder();
+    doc_builder.character_table(CharacterTable::Pc858);
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    // é=0x82, ü=0x81, £=0x9C, €=0xD5 in PC858
+    let expected: &[u8] = b"Jos\x82 M\x81ller \x9C12 \xD55";
+    assert!(bytes.windows(expected.len()).any(|w| w == expected));
+    assert!(!bytes.contains(&b'?'));
+}
+
+#[test]
+fn test_international_charset_replaces_ascii_positions() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "£10 #1", StyleFlags::NONE);
+    let page = page_builder.build();
+
+    let mut doc_builder = Document::builder();
+    doc_builder.international_charset(InternationalCharset::UnitedKingdom);
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    // ESC R 3 selects the UK set
+    assert!(bytes.windows(3).any(|w| w == [0x1B, 0x52, 0x03]));
+    // '£' prints at 0x23; '#' is no longer printable and falls back to '?'
+    assert!(bytes.windows(6).any(|w| w == b"#10 ?1"));
+}
+
+#[test]
+fn test_unmappable_characters_use_fallback() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "Łódź", StyleFlags::NONE);
+    let page = page_builder.build();
+
+    let mut doc_builder = Document::builder();
+    doc_builder.character_table(CharacterTable::Pc437);
+    doc_builder.fallback_char('_');
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    // Only 'ó' (0xA2) exists in PC437
+    assert!(bytes.windows(4).any(|w| w == b"_\xA2d_"));
+}
+
+#[test]
+fn test_framed_box_prints_native_lines() {
+    let mut page_builder = Page::builder();
+    page_builder.draw_box(0, 0, 4, 3, BorderStyle::Single, StyleFlags::NONE);
+    page_builder.draw_box(10, 0, 4, 3, BorderStyle::Double, StyleFlags::NONE);
+    let page = page_builder.build();
+
+    let mut doc_builder = Document::builder();
+    doc_builder.character_table(CharacterTable::Pc437);
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    // ┌──┐ and ╔══╗ in PC437
+    assert!(bytes.windows(4).any(|w| w == [0xDA, 0xC4, 0xC4, 0xBF]));
+    assert!(bytes.windows(4).any(|w| w == [0xC9, 0xCD, 0xCD, 0xBB]));
+}
+
+#[test]
+fn test_framed_box_falls_back_to_ascii() {
+    let mut page_builder = Page::builder();
+    page_builder.draw_box(0, 0, 4, 3, BorderStyle::Single, StyleFlags::NONE);
+    page_builder.draw_box(10, 0, 4, 3, BorderStyle::Double, StyleFlags::NONE);
+    let page = page_builder.build();
+
+    // The default ASCII table has no box-drawing characters
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+    let text = String::from_utf8_lossy(&bytes);
+
+    assert!(text.contains("+--+      +==+"));
+    assert!(text.contains("|  |      |  |"));
+    assert!(!text.contains('?'));
+}
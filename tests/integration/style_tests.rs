@@ -303,3 +303,77 @@ fn test_empty_cells_break_style_runs() {
         "Empty cells should break style optimization"
     );
 }
+
+#[test]
+fn test_extended_style_esc_codes() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "Italic", StyleFlags::ITALIC);
+    page_builder.write_str(0, 1, "Double", StyleFlags::DOUBLE_STRIKE);
+    page_builder.write_str(0, 2, "Super", StyleFlags::SUPERSCRIPT);
+    page_builder.write_str(0, 3, "Sub", StyleFlags::SUBSCRIPT);
+    page_builder.write_str(0, 4, "Struck", StyleFlags::STRIKETHROUGH);
+
+    let page = page_builder.build();
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    let contains = |seq: &[u8]| bytes.windows(seq.len()).any(|w| w == seq);
+
+    // ESC 4 / ESC 5 - italic on/off
+    assert!(contains(b"\x1b4Italic\x1b5"));
+    // ESC G / ESC H - double-strike on/off
+    assert!(contains(b"\x1bGDouble\x1bH"));
+    // ESC S 0 / ESC T - superscript on/off
+    assert!(contains(b"\x1bS\x00Super\x1bT"));
+    // ESC S 1 / ESC T - subscript on/off
+    assert!(contains(b"\x1bS\x01Sub\x1bT"));
+    // ESC ( - ... 1 / ... 0 - strikethrough on/off
+    assert!(contains(
+        b"\x1b(-\x03\x00\x01\x02\x01Struck\x1b(-\x03\x00\x01\x02\x00"
+    ));
+}
+
+#[test]
+fn test_extended_styles_minimal_transitions() {
+    let mut page_builder = Page::builder();
+    let style = StyleFlags::ITALIC.with_strikethrough();
+    page_builder.write_str(0, 0, "ABCDEFGHIJ", style);
+
+    let page = page_builder.build();
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    // One on/off pair per style for a uniformly styled run
+    let italic_on = bytes.windows(2).filter(|w| *w == [0x1B, 0x34]).count();
+    let strike_on = bytes
+        .windows(8)
+        .filter(|w| *w == [0x1B, 0x28, 0x2D, 0x03, 0x00, 0x01, 0x02, 0x01])
+        .count();
+    assert_eq!(italic_on, 1);
+    assert_eq!(strike_on, 1);
+}
+
+#[test]
+fn test_double_width_heading_keeps_columns_aligned() {
+    let mut page_builder = Page::builder();
+    page_builder.write_str(0, 0, "TITLE", StyleFlags::DOUBLE_WIDTH);
+    page_builder.write_str(10, 0, "|", StyleFlags::NONE);
+    page_builder.write_str(10, 1, "|", StyleFlags::NONE);
+
+    let page = page_builder.build();
+    let mut doc_builder = Document::builder();
+    doc_builder.add_page(page);
+    let bytes = doc_builder.build().render();
+
+    // ESC W 1, five glyphs emitted once, ESC W 0, then the marker at column 10
+    // with no padding: the glyphs already advanced the head ten columns
+    assert!(bytes.windows(12).any(|w| w == b"\x1bW\x01TITLE\x1bW\x00|"));
+
+    // The unstyled row below places its marker after ten spaces
+    let padded = [b" ".repeat(10), b"|".to_vec()].concat();
+    assert!(bytes
+        .windows(13)
+        .any(|w| w == [b"\r\n".as_slice(), &padded].concat()));
+}
@@ -0,0 +1,52 @@
+//! Integration tests for sending documents through printer transports
+
+use escp_layout::transport::{DeviceTransport, TcpTransport, Transport};
+use escp_layout::{Decoder, Document, Page, StyleFlags};
+use std::io::Read;
+use std::net::TcpListener;
+
+fn invoice(pages: usize) -> Document {
+    let mut builder = Document::builder();
+    for number in 1..=pages {
+        let mut page_builder = Page::builder();
+        page_builder.write_str(0, 0, "INVOICE", StyleFlags::BOLD);
+        page_builder.write_str(0, 2, &format!("Page {}", number), StyleFlags::NONE);
+        builder.add_page(page_builder.build());
+    }
+    builder.build()
+}
+
+#[test]
+fn test_tcp_transport_delivers_document() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let printer = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut job = Vec::new();
+        stream.read_to_end(&mut job).unwrap();
+        job
+    });
+
+    let document = invoice(3);
+    TcpTransport::new(address).send_document(&document).unwrap();
+
+    let job = printer.join().unwrap();
+    assert_eq!(job, document.render());
+    assert_eq!(Decoder::new().decode(&job).document().page_count(), 3);
+}
+
+#[test]
+fn test_transports_as_trait_objects() {
+    let path = std::env::temp_dir().join(format!("escp-transport-{}.prn", std::process::id()));
+    std::fs::write(&path, b"").unwrap();
+
+    let document = invoice(1);
+    let mut transports: Vec<Box<dyn Transport>> = vec![Box::new(DeviceTransport::new(&path))];
+    for transport in &mut transports {
+        transport.send_document(&document).unwrap();
+    }
+
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, document.render());
+}
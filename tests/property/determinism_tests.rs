@@ -132,3 +132,31 @@ proptest! {
         prop_assert!(!bytes.is_empty());
     }
 }
+
+proptest! {
+    #[test]
+    fn test_deterministic_previews(
+        text in arbitrary_text(),
+        x in 0u16..160u16,
+        y in 0u16..51u16,
+        bold in prop::bool::ANY,
+    ) {
+        let mut page_builder = Page::builder();
+        let style = if bold { StyleFlags::BOLD } else { StyleFlags::NONE };
+        page_builder.write_str(x, y, &text, style);
+
+        let mut doc_builder = Document::builder();
+        doc_builder.add_page(page_builder.build());
+        let document = doc_builder.build();
+
+        let html = document.to_html();
+        let svg = document.to_svg();
+        prop_assert_eq!(&html, &document.to_html(), "HTML previews must be identical");
+        prop_assert_eq!(&svg, &document.to_svg(), "SVG previews must be identical");
+
+        // Text is escaped, so every '<' opens one of the preview's own tags
+        let body = &html[html.find("<body>").unwrap()..];
+        prop_assert_eq!(body.matches('<').count(), body.matches('>').count());
+        prop_assert_eq!(svg[0].matches('<').count(), svg[0].matches('>').count());
+    }
+}
@@ -1,6 +1,6 @@
 //! Property-based tests to ensure no panics on arbitrary input (User Story 2)
 
-use escp_layout::{Document, Page, StyleFlags};
+use escp_layout::{Decoder, Document, Page, StyleFlags};
 use proptest::prelude::*;
 
 // Strategy to generate arbitrary text
@@ -29,7 +29,7 @@ proptest! {
 
         // Rendering should also never panic
         let bytes = document.render();
-        prop_assert!(!!!bytes.is_empty(), "Should produce output");
+        prop_assert!(!bytes.is_empty(), "Should produce output");
     }
 }
 
@@ -61,7 +61,7 @@ proptest! {
         let document = doc_builder.build();
 
         let bytes = document.render();
-        prop_assert!(!!!bytes.is_empty());
+        prop_assert!(!bytes.is_empty());
     }
 }
 
@@ -83,7 +83,7 @@ proptest! {
         let document = doc_builder.build();
         let bytes = document.render();
 
-        prop_assert!(!!!bytes.is_empty());
+        prop_assert!(!bytes.is_empty());
         prop_assert_eq!(document.page_count(), page_count);
 
         // Verify form-feed count (should equal page_count)
@@ -153,3 +153,12 @@ fn test_extreme_text_lengths() {
     let bytes = document.render();
     assert!(!bytes.is_empty());
 }
+
+proptest! {
+    #[test]
+    fn test_decode_never_panics(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        // Arbitrary byte streams decode without panicking
+        let decoded = Decoder::new().decode(&bytes);
+        prop_assert!(decoded.document().page_count() <= bytes.len() + 1);
+    }
+}
@@ -0,0 +1,135 @@
+//! Tests for loading widget tree descriptions with serde.
+
+use escp_layout::widget::{RenderError, WidgetDescription};
+use escp_layout::{Page, StyleFlags};
+
+const INVOICE_JSON: &str = r#"{
+    "type": "column",
+    "width": 60,
+    "height": 6,
+    "areas": [
+        {
+            "size": 2,
+            "children": [
+                {
+                    "position": [0, 0],
+                    "widget": { "type": "label", "width": 20, "text": "INVOICE", "style": ["bold", "underline"] }
+                }
+            ]
+        },
+        {
+            "size": 4,
+            "children": [
+                {
+                    "position": [0, 0],
+                    "widget": {
+                        "type": "row",
+                        "width": 60,
+                        "height": 1,
+                        "areas": [
+                            { "size": 30, "children": [{ "position": [0, 0], "widget": { "type": "label", "width": 30, "text": "Widget" } }] },
+                            { "size": 30, "children": [{ "position": [20, 0], "widget": { "type": "label", "width": 10, "text": "$10.00" } }] }
+                        ]
+                    }
+                }
+            ]
+        }
+    ]
+}"#;
+
+const INVOICE_TOML: &str = r#"
+type = "column"
+width = 60
+height = 6
+
+[[areas]]
+size = 2
+
+[[areas.children]]
+position = [0, 0]
+widget = { type = "label", width = 20, text = "INVOICE", style = ["bold", "underline"] }
+
+[[areas]]
+size = 4
+
+[[areas.children]]
+position = [0, 0]
+
+[areas.children.widget]
+type = "row"
+width = 60
+height = 1
+areas = [
+    { size = 30, children = [{ position = [0, 0], widget = { type = "label", width = 30, text = "Widget" } }] },
+    { size = 30, children = [{ position = [20, 0], widget = { type = "label", width = 10, text = "$10.00" } }] },
+]
+"#;
+
+fn render(description: &WidgetDescription) -> Page {
+    let layout = description.build().unwrap();
+    let mut page_builder = Page::builder();
+    page_builder.render_layout(&layout).unwrap();
+    page_builder.build()
+}
+
+#[test]
+fn test_load_from_json() {
+    let description: WidgetDescription = serde_json::from_str(INVOICE_JSON).unwrap();
+    let page = render(&description);
+
+    assert_eq!(
+        page.to_plain_text(),
+        "INVOICE\n\nWidget                                            $10.00\n"
+    );
+    assert_eq!(
+        page.get_cell(0, 0).unwrap().style(),
+        StyleFlags::BOLD.with_underline()
+    );
+}
+
+#[test]
+fn test_json_and_toml_agree() {
+    let from_json: WidgetDescription = serde_json::from_str(INVOICE_JSON).unwrap();
+    let from_toml: WidgetDescription = toml::from_str(INVOICE_TOML).unwrap();
+    assert_eq!(from_json, from_toml);
+
+    // Serializing gives back an equivalent description
+    let json = serde_json::to_string(&from_json).unwrap();
+    let reloaded: WidgetDescription = serde_json::from_str(&json).unwrap();
+    assert_eq!(reloaded, from_json);
+}
+
+#[test]
+fn test_validation_error_path() {
+    let json = r#"{
+        "type": "rect",
+        "width": 20,
+        "height": 3,
+        "children": [
+            { "position": [0, 0], "widget": { "type": "label", "width": 10, "text": "a" } },
+            { "position": [0, 1], "widget": { "type": "rect", "width": 20, "height": 2, "children": [
+                { "position": [12, 1], "widget": { "type": "label", "width": 10 } }
+            ] } }
+        ]
+    }"#;
+    let description: WidgetDescription = serde_json::from_str(json).unwrap();
+    let error = description.build().unwrap_err();
+
+    assert_eq!(error.path(), "root.children[1].children[0]");
+    assert_eq!(
+        error.error(),
+        &RenderError::ChildExceedsParent {
+            parent_width: 20,
+            parent_height: 2,
+            child_width: 10,
+            child_height: 1,
+            position: (12, 1),
+        }
+    );
+}
+
+#[test]
+fn test_unknown_widget_type_is_rejected() {
+    let json = r#"{ "type": "button", "width": 10 }"#;
+    assert!(serde_json::from_str::<WidgetDescription>(json).is_err());
+}
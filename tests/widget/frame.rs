@@ -0,0 +1,153 @@
+//! Tests for the Frame widget.
+
+use escp_layout::widget::{frame_new, label_new, rect_new, Alignment, Frame, Rect, RenderError};
+use escp_layout::{BorderStyle, Page, StyleFlags};
+
+fn render<const W: u16, const H: u16>(frame: Frame<W, H>) -> Page {
+    let mut root: Rect<40, 12> = rect_new!(40, 12);
+    root.add_child(frame, (0, 0)).unwrap();
+    let mut page_builder = Page::builder();
+    page_builder.render(&root).unwrap();
+    page_builder.build()
+}
+
+#[test]
+fn test_draws_border_without_title() {
+    let frame = frame_new!(6, 3);
+    assert_eq!(render(frame).to_plain_text(), "+----+\n|    |\n+----+\n");
+}
+
+#[test]
+fn test_inner_dimensions() {
+    assert_eq!(Frame::<40, 10>::INNER_WIDTH, 38);
+    assert_eq!(Frame::<40, 10>::INNER_HEIGHT, 8);
+    assert_eq!(Frame::<2, 2>::INNER_WIDTH, 0);
+}
+
+#[test]
+fn test_title_alignments() {
+    let titled = |alignment| {
+        let frame = frame_new!(16, 2).title("Ship").title_alignment(alignment);
+        render(frame).to_plain_text()
+    };
+    assert_eq!(
+        titled(Alignment::Left),
+        "+- Ship -------+\n+--------------+\n"
+    );
+    assert_eq!(
+        titled(Alignment::Center),
+        "+---- Ship ----+\n+--------------+\n"
+    );
+    assert_eq!(
+        titled(Alignment::Right),
+        "+------- Ship -+\n+--------------+\n"
+    );
+}
+
+#[test]
+fn test_truncates_long_titles() {
+    let frame = frame_new!(10, 2).title("Shipping address");
+    assert_eq!(render(frame).to_plain_text(), "+- Ship -+\n+--------+\n");
+
+    // No room for a title at all
+    let frame = frame_new!(6, 2).title("Ship");
+    assert_eq!(render(frame).to_plain_text(), "+----+\n+----+\n");
+}
+
+#[test]
+fn test_styled_title() {
+    let frame = frame_new!(12, 2).title("Ab").title_style(StyleFlags::BOLD);
+    let page = render(frame);
+
+    assert_eq!(page.to_plain_text(), "+- Ab -----+\n+----------+\n");
+    let style = |x| page.get_cell(x, 0).unwrap().style();
+    assert_eq!(style(2), StyleFlags::NONE);
+    assert_eq!(style(3), StyleFlags::BOLD);
+    assert_eq!(style(4), StyleFlags::BOLD);
+    assert_eq!(style(5), StyleFlags::NONE);
+}
+
+#[test]
+fn test_double_width_title() {
+    let frame = frame_new!(12, 2)
+        .title("Ab")
+        .title_style(StyleFlags::DOUBLE_WIDTH)
+        .title_alignment(Alignment::Right);
+    let page = render(frame);
+
+    assert_eq!(page.get_cell(5, 0).unwrap().character(), 'A');
+    assert_eq!(page.get_cell(7, 0).unwrap().character(), 'b');
+    assert_eq!(page.get_cell(9, 0).unwrap().character(), ' ');
+    assert_eq!(page.get_cell(10, 0).unwrap().character(), '-');
+    assert_eq!(page.get_cell(11, 0).unwrap().character(), '+');
+}
+
+#[test]
+fn test_double_height_title_stays_in_border() {
+    let mut frame = frame_new!(20, 4)
+        .title("Total")
+        .title_style(StyleFlags::DOUBLE_HEIGHT.with_bold());
+    frame
+        .add_child(label_new!(5).add_text("value").unwrap(), (0, 0))
+        .unwrap();
+    let page = render(frame);
+
+    assert_eq!(
+        page.to_plain_text().lines().take(2).collect::<Vec<_>>(),
+        ["+- Total ----------+", "|value             |"]
+    );
+    assert_eq!(page.get_cell(3, 0).unwrap().style(), StyleFlags::BOLD);
+}
+
+#[test]
+fn test_children_are_relative_to_inner_area() {
+    let mut frame = frame_new!(12, 4);
+    frame
+        .add_child(label_new!(5).add_text("one").unwrap(), (0, 0))
+        .unwrap();
+    frame
+        .add_child(label_new!(5).add_text("two").unwrap(), (5, 1))
+        .unwrap();
+    assert_eq!(
+        render(frame).to_plain_text(),
+        "+----------+\n|one       |\n|     two  |\n+----------+\n"
+    );
+}
+
+#[test]
+fn test_children_must_fit_inner_area() {
+    let mut frame = frame_new!(12, 4);
+    assert!(matches!(
+        frame.add_child(label_new!(11), (0, 0)),
+        Err(RenderError::ChildExceedsParent { .. })
+    ));
+    assert!(matches!(
+        frame.add_child(label_new!(10), (0, 2)),
+        Err(RenderError::ChildExceedsParent { .. })
+    ));
+    frame.add_child(label_new!(10), (0, 1)).unwrap();
+    assert!(matches!(
+        frame.add_child(label_new!(4), (2, 1)),
+        Err(RenderError::OverlappingChildren { .. })
+    ));
+}
+
+#[test]
+fn test_nested_frames() {
+    let mut inner = frame_new!(8, 3).title("x");
+    inner
+        .add_child(label_new!(4).add_text("ok").unwrap(), (0, 0))
+        .unwrap();
+    let mut outer = frame_new!(12, 5).border(BorderStyle::Double);
+    outer.add_child(inner, (1, 0)).unwrap();
+
+    let page = render(outer);
+    let row = |y: u16| -> String {
+        (0..12)
+            .map(|x| page.get_cell(x, y).unwrap().character())
+            .collect()
+    };
+    assert_eq!(row(1), "║ +- x --+ ║");
+    assert_eq!(row(2), "║ |ok    | ║");
+    assert_eq!(row(0).chars().next(), Some('╔'));
+}
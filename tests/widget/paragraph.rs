@@ -0,0 +1,92 @@
+//! Tests for the Paragraph widget.
+
+use escp_layout::widget::{paragraph_new, rect_new, Paragraph, Rect};
+use escp_layout::{Page, StyleFlags};
+
+fn render<const W: u16, const H: u16>(paragraph: Paragraph<W, H>) -> Page {
+    let mut root: Rect<40, 12> = rect_new!(40, 12);
+    root.add_child(paragraph, (0, 0)).unwrap();
+    let mut page_builder = Page::builder();
+    page_builder.render(&root).unwrap();
+    page_builder.build()
+}
+
+#[test]
+fn test_wraps_on_whitespace() {
+    let paragraph = paragraph_new!(12, 5).add_text("The quick  brown fox\tjumps over the lazy dog");
+    assert_eq!(paragraph.line_count(), 4);
+    assert_eq!(
+        render(paragraph).to_plain_text(),
+        "The quick\nbrown fox\njumps over\nthe lazy dog\n"
+    );
+}
+
+#[test]
+fn test_splits_long_words() {
+    let paragraph = paragraph_new!(5, 5).add_text("a abcdefghijkl b");
+    assert_eq!(paragraph.line_count(), 4);
+    assert_eq!(render(paragraph).to_plain_text(), "a\nabcde\nfghij\nkl b\n");
+}
+
+#[test]
+fn test_drops_lines_beyond_height() {
+    let paragraph = paragraph_new!(10, 2).add_text("one two three four five six");
+    assert_eq!(paragraph.line_count(), 3);
+    assert_eq!(render(paragraph).to_plain_text(), "one two\nthree four\n");
+}
+
+#[test]
+fn test_hard_line_breaks() {
+    let paragraph = paragraph_new!(20, 5).add_text("Terms:\n\n  1. Pay on time\r\n");
+    assert_eq!(paragraph.line_count(), 4);
+    assert_eq!(
+        render(paragraph).to_plain_text(),
+        "Terms:\n\n1. Pay on time\n"
+    );
+}
+
+#[test]
+fn test_paragraph_and_span_styles() {
+    let paragraph = paragraph_new!(20, 3)
+        .add_text("Pay ")
+        .add_span("now", StyleFlags::BOLD)
+        .add_text(" please")
+        .italic();
+    let page = render(paragraph);
+
+    assert_eq!(page.to_plain_text(), "Pay now please\n");
+    let style = |x| page.get_cell(x, 0).unwrap().style();
+    assert_eq!(style(0), StyleFlags::ITALIC);
+    assert_eq!(style(3), StyleFlags::ITALIC);
+    assert_eq!(style(4), StyleFlags::BOLD);
+    assert_eq!(style(7), StyleFlags::ITALIC);
+}
+
+#[test]
+fn test_spans_join_into_words() {
+    // No whitespace between spans: one word in two styles
+    let paragraph = paragraph_new!(6, 3)
+        .add_span("ab", StyleFlags::UNDERLINE)
+        .add_text("cd ef");
+    assert_eq!(render(paragraph).to_plain_text(), "abcd\nef\n");
+}
+
+#[test]
+fn test_double_size_glyphs() {
+    let paragraph = paragraph_new!(8, 5)
+        .add_span("BIG", StyleFlags::DOUBLE_WIDTH.with_double_height())
+        .add_text(" text wraps here");
+    assert_eq!(paragraph.line_count(), 4);
+
+    // The double-height first line takes rows 0 and 1
+    let page = render(paragraph);
+    assert_eq!(page.to_plain_text(), "B I G\n\ntext\nwraps\nhere\n");
+}
+
+#[test]
+fn test_empty_paragraph() {
+    let paragraph = paragraph_new!(10, 2);
+    assert_eq!(paragraph.line_count(), 0);
+    assert_eq!(render(paragraph).to_plain_text(), "");
+    assert_eq!(paragraph_new!(10, 2).add_text("   ").line_count(), 1);
+}
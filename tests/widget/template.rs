@@ -0,0 +1,109 @@
+//! Tests for rendering templates with serde-loaded records.
+
+use escp_layout::widget::{
+    Template, TemplateData, TemplateError, TemplateValue, WidgetDescription,
+};
+use escp_layout::StyleFlags;
+
+const INVOICE_TEMPLATE: &str = r#"{
+    "type": "rect",
+    "width": 40,
+    "height": 4,
+    "children": [
+        { "position": [0, 0], "widget": { "type": "label", "width": 40, "text": "INVOICE {{number:0>6}}", "style": ["bold"] } },
+        { "position": [0, 1], "widget": { "type": "label", "width": 20, "text": "{{customer.name}}" } },
+        { "position": [0, 2], "widget": { "type": "label", "width": 25, "text": "{{items.0.name:<15}}{{items.0.price:>10,.2}}" } },
+        { "position": [0, 3], "widget": { "type": "label", "width": 25, "text": "{{label:<15}}{{total:>10,.2}}" } }
+    ]
+}"#;
+
+fn invoice_template() -> Template {
+    let description: WidgetDescription = serde_json::from_str(INVOICE_TEMPLATE).unwrap();
+    Template::new(description).unwrap()
+}
+
+#[test]
+fn test_render_json_record() {
+    let record: TemplateValue = serde_json::from_str(
+        r#"{
+            "number": 42,
+            "customer": { "name": "Bartholomew Industries Ltd" },
+            "items": [{ "name": "Widget", "price": 1250 }],
+            "label": "Total",
+            "total": 1250.5
+        }"#,
+    )
+    .unwrap();
+
+    let page = invoice_template().render(&record).unwrap();
+    assert_eq!(
+        page.to_plain_text(),
+        "INVOICE 000042\n\
+         Bartholomew Industri\n\
+         Widget           1,250.00\n\
+         Total            1,250.50\n"
+    );
+    assert_eq!(page.get_cell(0, 0).unwrap().style(), StyleFlags::BOLD);
+}
+
+#[test]
+fn test_render_keeps_large_integers_exact() {
+    let record: TemplateValue = serde_json::from_str(
+        r#"{
+            "number": 9007199254740993,
+            "customer": { "name": "ACME" },
+            "items": [{ "name": "Widget", "price": 18446744073709551615 }],
+            "label": "Total",
+            "total": -9007199254740993
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        record.lookup("items.0.price"),
+        Some(TemplateValue::Integer(u64::MAX.into()))
+    );
+    let page = invoice_template().render(&record).unwrap();
+    assert!(page
+        .to_plain_text()
+        .starts_with("INVOICE 9007199254740993\n"));
+}
+
+#[test]
+fn test_render_toml_record() {
+    let record: TemplateValue = toml::from_str(
+        r#"
+        number = 7
+        label = "Due"
+        total = 99.999
+
+        [customer]
+        name = "ACME"
+
+        [[items]]
+        name = "Gadget"
+        price = 0.5
+        "#,
+    )
+    .unwrap();
+
+    let page = invoice_template().render(&record).unwrap();
+    assert_eq!(
+        page.to_plain_text(),
+        "INVOICE 000007\n\
+         ACME\n\
+         Gadget               0.50\n\
+         Due                100.00\n"
+    );
+}
+
+#[test]
+fn test_render_reports_missing_values() {
+    let record: TemplateValue = serde_json::from_str(r#"{ "number": 1 }"#).unwrap();
+    assert_eq!(
+        invoice_template().render(&record).unwrap_err(),
+        TemplateError::MissingValue {
+            placeholder: "customer.name".to_string()
+        }
+    );
+}
@@ -0,0 +1,110 @@
+//! Tests for the TextBlock widget.
+
+use escp_layout::widget::{column_area, column_new, rect_new, text_block_new, RenderError};
+use escp_layout::{Page, StyleFlags};
+
+fn render_text(build: impl FnOnce(&mut escp_layout::widget::Rect<40, 10>)) -> String {
+    let mut root = rect_new!(40, 10);
+    build(&mut root);
+    let mut page_builder = Page::builder();
+    page_builder.render(&root).unwrap();
+    let page = page_builder.build();
+    page.to_plain_text()
+}
+
+#[test]
+fn test_preserves_whitespace_and_lines() {
+    let art = " /\\_/\\\n( o.o )\n\n  > ^ <";
+    let text = render_text(|root| {
+        root.add_child(text_block_new!(10, 5).add_text(art), (2, 1))
+            .unwrap();
+    });
+    assert_eq!(text, "\n   /\\_/\\\n  ( o.o )\n\n    > ^ <\n");
+}
+
+#[test]
+fn test_truncates_at_bounds() {
+    let text = render_text(|root| {
+        let block = text_block_new!(5, 2).add_text("abcdefgh\r\n12345678\r\nhidden");
+        root.add_child(block, (0, 0)).unwrap();
+        root.add_child(text_block_new!(5, 1).add_text("right"), (5, 0))
+            .unwrap();
+    });
+    assert_eq!(text, "abcderight\n12345\n");
+}
+
+#[test]
+fn test_expands_tabs() {
+    let text = render_text(|root| {
+        let block = text_block_new!(20, 3).add_text("a\tb\nabcd\tc\n\t\tx");
+        root.add_child(block.tab_width(4), (0, 0)).unwrap();
+        root.add_child(text_block_new!(20, 1).add_text("x\ty"), (0, 5))
+            .unwrap();
+    });
+    assert_eq!(text, "a   b\nabcd    c\n        x\n\n\nx       y\n");
+}
+
+#[test]
+fn test_styles() {
+    let mut root = rect_new!(40, 10);
+    let block = text_block_new!(6, 5)
+        .add_text("WIDE TEXT\nsecond\nthird")
+        .bold()
+        .double_width()
+        .double_height();
+    root.add_child(block, (0, 0)).unwrap();
+
+    let mut page_builder = Page::builder();
+    page_builder.render(&root).unwrap();
+    let page = page_builder.build();
+
+    // Three characters per line, two rows per line, two lines in five rows
+    assert_eq!(page.to_plain_text(), "W I D\n\ns e c\n");
+    let style = page.get_cell(0, 0).unwrap().style();
+    assert_eq!(
+        style,
+        StyleFlags::BOLD.with_double_width().with_double_height()
+    );
+    // The third line would need rows 4 and 5
+    assert_eq!(page.get_cell(0, 4).unwrap().character(), ' ');
+}
+
+#[test]
+fn test_inside_layouts() {
+    let mut root = rect_new!(40, 10);
+    let mut column = column_new!(40, 10);
+    let (mut header, header_pos) = column_area!(column, 2).unwrap();
+    let (mut body, body_pos) = column_area!(column, 8).unwrap();
+
+    header
+        .add_child(text_block_new!(40, 2).add_text("HEADER\n======"), (0, 0))
+        .unwrap();
+    body.add_child(text_block_new!(40, 8).add_text("body"), (0, 0))
+        .unwrap();
+    root.add_child(header, header_pos).unwrap();
+    root.add_child(body, body_pos).unwrap();
+
+    let mut page_builder = Page::builder();
+    page_builder.render(&root).unwrap();
+    assert_eq!(
+        page_builder.build().to_plain_text(),
+        "HEADER\n======\nbody\n"
+    );
+}
+
+#[test]
+fn test_must_fit_parent() {
+    let mut root = rect_new!(10, 3);
+    let error = root
+        .add_child(text_block_new!(8, 4).add_text("tall"), (0, 0))
+        .unwrap_err();
+    assert!(matches!(error, RenderError::ChildExceedsParent { .. }));
+}
+
+#[test]
+fn test_empty_block_renders_nothing() {
+    let text = render_text(|root| {
+        root.add_child(text_block_new!(10, 3), (0, 0)).unwrap();
+    });
+    assert_eq!(text, "");
+}
@@ -1,6 +1,6 @@
 //! Basic integration test for widget composability system.
 
-use escp_layout::widget::{rect_new, label_new};
+use escp_layout::widget::{label_new, rect_new, Label, RenderError};
 use escp_layout::Page;
 
 #[test]
@@ -101,3 +101,126 @@ fn test_touching_edges_allowed() {
     let result = parent.add_child(label2, (20, 0));
     assert!(result.is_ok(), "Touching edges should be allowed");
 }
+
+#[test]
+fn test_label_extended_styles() {
+    let mut root = rect_new!(80, 30);
+
+    let label = label_new!(20)
+        .add_text("Styled")
+        .expect("Text should fit")
+        .italic()
+        .double_strike()
+        .superscript()
+        .strikethrough();
+    root.add_child(label, (0, 0)).expect("Child should fit");
+
+    let subscript = label_new!(20)
+        .add_text("Sub")
+        .expect("Text should fit")
+        .superscript()
+        .subscript();
+    root.add_child(subscript, (0, 1)).expect("Child should fit");
+
+    let mut page_builder = Page::builder();
+    page_builder
+        .render(&root)
+        .expect("Rendering should succeed");
+    let page = page_builder.build();
+
+    let style = page.get_cell(0, 0).unwrap().style();
+    assert!(style.italic());
+    assert!(style.double_strike());
+    assert!(style.superscript());
+    assert!(style.strikethrough());
+    assert!(!style.bold());
+
+    let style = page.get_cell(0, 1).unwrap().style();
+    assert!(style.subscript());
+    assert!(!style.superscript());
+}
+
+#[test]
+fn test_label_double_width_truncated_to_widget_width() {
+    let mut root = rect_new!(20, 2);
+
+    let heading = label_new!(6)
+        .add_text("HEADER")
+        .expect("Text should fit")
+        .double_width();
+    root.add_child(heading, (0, 0)).expect("Child should fit");
+
+    let neighbour = label_new!(4).add_text("next").expect("Text should fit");
+    root.add_child(neighbour, (6, 0)).expect("Child should fit");
+
+    let mut page_builder = Page::builder();
+    page_builder
+        .render(&root)
+        .expect("Rendering should succeed");
+    let page = page_builder.build();
+
+    // Three double-width glyphs fill the 6 columns
+    assert_eq!(page.get_cell(0, 0).unwrap().character(), 'H');
+    assert!(page.get_cell(1, 0).unwrap().is_covered());
+    assert_eq!(page.get_cell(4, 0).unwrap().character(), 'A');
+    assert!(page.get_cell(5, 0).unwrap().is_covered());
+
+    // The neighbouring label is untouched
+    assert_eq!(page.get_cell(6, 0).unwrap().character(), 'n');
+}
+
+#[test]
+fn test_label_double_height_needs_two_rows() {
+    // A single-row label cannot hold the lower half of the glyphs
+    let short = label_new!(4)
+        .add_text("BIG")
+        .expect("Text should fit")
+        .double_height();
+    let mut root = rect_new!(20, 2);
+    root.add_child(short, (0, 0)).expect("Child should fit");
+    let mut page_builder = Page::builder();
+    assert_eq!(
+        page_builder.render(&root),
+        Err(RenderError::TextExceedsHeight {
+            glyph_height: 2,
+            widget_height: 1,
+        })
+    );
+    assert!(matches!(
+        label_new!(4).double_height().add_text("BIG"),
+        Err(RenderError::TextExceedsHeight { .. })
+    ));
+
+    // A two-row label reserves it
+    let tall = Label::<4, 2>::new()
+        .add_text("BIG")
+        .expect("Text should fit")
+        .double_height();
+    let mut root = rect_new!(20, 2);
+    root.add_child(tall, (10, 0)).expect("Child should fit");
+    let mut page_builder = Page::builder();
+    page_builder
+        .render(&root)
+        .expect("Rendering should succeed");
+    let page = page_builder.build();
+
+    assert!(page.get_cell(10, 0).unwrap().style().double_height());
+    assert!(page.get_cell(10, 1).unwrap().is_covered());
+}
+
+#[test]
+fn test_label_text_length_counts_characters() {
+    // Six characters, seven bytes
+    assert!(label_new!(6).add_text("Müller").is_ok());
+
+    match label_new!(5).add_text("Müller") {
+        Err(RenderError::TextExceedsWidth {
+            text_length,
+            widget_width,
+        }) => {
+            assert_eq!(text_length, 6);
+            assert_eq!(widget_width, 5);
+        }
+        _ => panic!("Expected TextExceedsWidth"),
+    }
+}